@@ -21,6 +21,7 @@ use crate::input::testing_time::TestingTimeReport;
 use crate::output::Output;
 use crate::output::format::Format;
 use crate::output::json::Json;
+use crate::statistics::noise::Noise;
 use crate::suite_kind::SuiteKind;
 
 use self::run_failures::RunFailures;
@@ -144,6 +145,7 @@ impl Benchmark {
                 .tests
                 .entry(name)
                 .or_insert_with(|| Test::new(TestMetadata::new(selector, vec![])));
+            existing_test.gas_noise = Noise::Deterministic;
 
             for (mode, run) in test.runs.into_iter() {
                 let mode_key = if mode.starts_with(&toolchain) {
@@ -12,6 +12,8 @@ use std::collections::BTreeMap;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::statistics::noise::Noise;
+
 use self::metadata::Metadata;
 use self::run::Run;
 
@@ -32,6 +34,10 @@ pub struct Test {
     /// The number of non-zero gas values across all runs.
     #[serde(skip)]
     pub non_zero_gas_values: usize,
+    /// How noisy the gas is: deterministic on the tester's fixed inputs,
+    /// noisy on the projects' fuzzed tests.
+    #[serde(skip)]
+    pub gas_noise: Noise,
 }
 
 impl Test {
@@ -43,6 +49,7 @@ impl Test {
             metadata,
             runs: Default::default(),
             non_zero_gas_values: 0,
+            gas_noise: Noise::default(),
         }
     }
 
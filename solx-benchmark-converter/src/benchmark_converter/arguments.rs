@@ -21,7 +21,7 @@ pub struct Arguments {
     /// Input files. A single directory argument expands to every JSON file underneath it.
     pub input_paths: Vec<PathBuf>,
 
-    /// Benchmark output format: `json` or `xlsx`.
+    /// Benchmark output format: `json`, `xlsx`, or `html`.
     #[arg(long = "output-format", alias = "benchmark-format", default_value_t = OutputFormat::Xlsx)]
     pub output_format: OutputFormat,
 
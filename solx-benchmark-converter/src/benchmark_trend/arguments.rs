@@ -0,0 +1,84 @@
+//!
+//! The benchmark trend arguments.
+//!
+
+use std::path::PathBuf;
+
+use clap::Args;
+use clap::Parser;
+use clap::Subcommand;
+
+use solx_benchmark_converter::Trend;
+
+///
+/// Keeps the benchmarks of `main` commits in a historical store and reports
+/// the slow drifts across them.
+///
+#[derive(Debug, Parser)]
+#[command(about, long_about = None, arg_required_else_help = true)]
+pub struct Arguments {
+    /// The subcommand to run.
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+///
+/// The benchmark trend subcommands.
+///
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Appends a converted benchmark JSON to the store.
+    Insert(Insert),
+    /// Renders the trend over the latest commits of the store.
+    Report(Report),
+}
+
+///
+/// The `insert` subcommand arguments.
+///
+#[derive(Debug, Args)]
+pub struct Insert {
+    /// The store directory, created on first use.
+    #[arg(long)]
+    pub store: PathBuf,
+
+    /// The benchmark JSON produced by `solx-benchmark-converter`.
+    #[arg(long)]
+    pub benchmark: PathBuf,
+
+    /// The hash of the benchmarked commit.
+    #[arg(long)]
+    pub commit: String,
+
+    /// The commit date in UTC ISO 8601, e.g. from
+    /// `TZ=UTC git show -s --date=iso-strict-local --format=%cd`.
+    #[arg(long)]
+    pub date: String,
+}
+
+///
+/// The `report` subcommand arguments.
+///
+#[derive(Debug, Args)]
+pub struct Report {
+    /// The store directory.
+    #[arg(long)]
+    pub store: PathBuf,
+
+    /// The number of latest commits the trend covers.
+    #[arg(long, default_value_t = Trend::DEFAULT_WINDOW)]
+    pub window: usize,
+
+    /// The growth over the window, in percent, that flags a series no single
+    /// commit grew by as much.
+    #[arg(long, default_value_t = Trend::DEFAULT_THRESHOLD_PERCENT)]
+    pub threshold: f64,
+
+    /// Output Markdown file.
+    #[arg(long)]
+    pub markdown_path: Option<PathBuf>,
+
+    /// Output XLSX file.
+    #[arg(long)]
+    pub xlsx_path: Option<PathBuf>,
+}
@@ -0,0 +1,62 @@
+//!
+//! The benchmark trend binary.
+//!
+//! Appends each converted `main` benchmark to a historical store, and renders
+//! per-test gas and size time series over the latest commits, flagging the
+//! slow drifts no single PR comparison would catch.
+//!
+
+pub mod arguments;
+
+use clap::Parser;
+
+use solx_benchmark_converter::Benchmark;
+use solx_benchmark_converter::History;
+use solx_benchmark_converter::HistoryEntry;
+use solx_benchmark_converter::Trend;
+
+use self::arguments::Arguments;
+use self::arguments::Command;
+
+///
+/// The application entry point.
+///
+fn main() -> anyhow::Result<()> {
+    let arguments = Arguments::try_parse()?;
+
+    match arguments.command {
+        Command::Insert(insert) => {
+            let benchmark = Benchmark::try_from(insert.benchmark.as_path())?;
+            let entry = HistoryEntry::new(insert.commit, insert.date, benchmark)?;
+            let path = History::new(insert.store).insert(&entry)?;
+            println!("Recorded commit {} in {path:?}", entry.short_commit());
+        }
+        Command::Report(report) => {
+            if report.markdown_path.is_none() && report.xlsx_path.is_none() {
+                anyhow::bail!("No output path was provided; nothing to report.");
+            }
+            if report.window < 2 {
+                anyhow::bail!("The trend window must cover at least 2 commits.");
+            }
+
+            let entries = History::new(report.store.clone()).load()?;
+            if entries.is_empty() {
+                anyhow::bail!("History store {:?} has no entries.", report.store);
+            }
+            let trend = Trend::new(entries.as_slice(), report.window, report.threshold);
+
+            if let Some(path) = report.markdown_path {
+                std::fs::write(path.as_path(), trend.markdown())
+                    .map_err(|error| anyhow::anyhow!("Trend file {path:?} writing: {error}"))?;
+            }
+            if let Some(path) = report.xlsx_path {
+                trend
+                    .xlsx()?
+                    .save(path.as_path())
+                    .map_err(|error| anyhow::anyhow!("Trend file {path:?} writing: {error}"))?;
+            }
+        }
+    }
+
+    Ok(())
+}
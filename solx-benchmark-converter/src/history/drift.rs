@@ -0,0 +1,73 @@
+//!
+//! A slow regression no single commit is responsible for.
+//!
+
+use crate::history::metric::Metric;
+use crate::history::series::Series;
+
+///
+/// A series that grew by at least the threshold over the window, counting
+/// only the commits that grew it by less than the threshold each. A step
+/// regression is the PR comparison's to catch, so it is left out of the
+/// aggregate rather than hiding the small ones adding up around it.
+///
+#[derive(Debug, Clone)]
+pub struct Drift {
+    /// The metric measured.
+    pub metric: Metric,
+    /// The test name.
+    pub test: String,
+    /// The mode the test ran in.
+    pub mode: String,
+    /// The first measurement in the window.
+    pub first: u64,
+    /// The last measurement in the window.
+    pub last: u64,
+    /// The compounded relative change of the commits below the threshold, in
+    /// percent.
+    pub change_percent: f64,
+    /// The largest relative growth between consecutive measurements below
+    /// the threshold, in percent.
+    pub largest_step_percent: f64,
+    /// The number of step regressions left out of the change.
+    pub step_regressions: usize,
+    /// The series' one-line chart.
+    pub sparkline: String,
+}
+
+impl Drift {
+    ///
+    /// The drift of the series, if it has one. Measurements shrinking over
+    /// the window are improvements and never drift.
+    ///
+    pub fn detect(series: &Series, threshold_percent: f64) -> Option<Self> {
+        let values: Vec<u64> = series.values().collect();
+        let (first, last) = (*values.first()?, *values.last()?);
+        if values.len() < 2 {
+            return None;
+        }
+        let (steps, step_regressions): (Vec<f64>, Vec<f64>) = values
+            .windows(2)
+            .filter_map(|pair| crate::utils::relative_percent(pair[1], pair[0]))
+            .partition(|step| *step < threshold_percent);
+        let change_percent = (steps
+            .iter()
+            .fold(1.0, |growth, step| growth * (1.0 + step / 100.0))
+            - 1.0)
+            * 100.0;
+        if change_percent < threshold_percent {
+            return None;
+        }
+        Some(Self {
+            metric: series.metric,
+            test: series.test.clone(),
+            mode: series.mode.clone(),
+            first,
+            last,
+            change_percent,
+            largest_step_percent: steps.into_iter().fold(0.0, f64::max),
+            step_regressions: step_regressions.len(),
+            sparkline: series.sparkline(),
+        })
+    }
+}
@@ -0,0 +1,94 @@
+//!
+//! One commit's benchmark in the historical store.
+//!
+
+use std::path::Path;
+
+use crate::benchmark::Benchmark;
+
+///
+/// One commit's benchmark in the historical store.
+///
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct Entry {
+    /// The full commit hash.
+    pub commit: String,
+    /// The commit date, in UTC ISO 8601, e.g. `2026-10-18T09:30:00Z`. Entries
+    /// are ordered by it, so it must be the same time zone across the store.
+    pub date: String,
+    /// The converted benchmark of the commit.
+    pub benchmark: Benchmark,
+}
+
+impl Entry {
+    /// The length of the commit hash abbreviation in file names and reports.
+    pub const SHORT_COMMIT_LENGTH: usize = 7;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the commit is not a hexadecimal hash, or the date
+    /// does not start with a `YYYY-MM-DD` day.
+    ///
+    pub fn new(commit: String, date: String, benchmark: Benchmark) -> anyhow::Result<Self> {
+        if commit.is_empty()
+            || !commit
+                .chars()
+                .all(|character| character.is_ascii_hexdigit())
+        {
+            anyhow::bail!("Commit {commit:?} is not a hexadecimal hash");
+        }
+        let is_day = date.len() >= 10
+            && date.bytes().take(10).enumerate().all(|(index, byte)| {
+                if index == 4 || index == 7 {
+                    byte == b'-'
+                } else {
+                    byte.is_ascii_digit()
+                }
+            });
+        if !is_day {
+            anyhow::bail!("Date {date:?} is not in ISO 8601, e.g. `2026-10-18T09:30:00Z`");
+        }
+        Ok(Self {
+            commit: commit.to_ascii_lowercase(),
+            date,
+            benchmark,
+        })
+    }
+
+    ///
+    /// The abbreviated commit hash.
+    ///
+    pub fn short_commit(&self) -> &str {
+        &self.commit[..self.commit.len().min(Self::SHORT_COMMIT_LENGTH)]
+    }
+
+    ///
+    /// The `YYYY-MM-DD` day of the commit.
+    ///
+    pub fn day(&self) -> &str {
+        &self.date[..10]
+    }
+
+    ///
+    /// The entry's file name without extension: the day and the commit, so
+    /// a listing of the store reads chronologically.
+    ///
+    pub fn file_stem(&self) -> String {
+        format!("{}-{}", self.day(), self.commit)
+    }
+}
+
+impl TryFrom<&Path> for Entry {
+    type Error = anyhow::Error;
+
+    fn try_from(path: &Path) -> Result<Self, Self::Error> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|error| anyhow::anyhow!("History entry {path:?} reading: {error}"))?;
+        let entry: Self = serde_json::from_str(text.as_str())
+            .map_err(|error| anyhow::anyhow!("History entry {path:?} parsing: {error}"))?;
+        Ok(entry)
+    }
+}
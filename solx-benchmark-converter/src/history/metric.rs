@@ -0,0 +1,49 @@
+//!
+//! A measurement tracked across commits.
+//!
+
+use crate::benchmark::test::run::Run;
+
+///
+/// A measurement tracked across commits. Times are left out: they depend on
+/// the CI machine more than on the commit.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Metric {
+    /// Gas used.
+    Gas,
+    /// Deploy bytecode size.
+    DeploySize,
+    /// Runtime bytecode size.
+    RuntimeSize,
+}
+
+impl Metric {
+    /// Every metric, in report order.
+    pub const ALL: [Self; 3] = [Self::Gas, Self::DeploySize, Self::RuntimeSize];
+
+    /// The metric's name in the report tables and sheet tabs.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Gas => "Gas",
+            Self::DeploySize => "Deploy Size",
+            Self::RuntimeSize => "Runtime Size",
+        }
+    }
+
+    /// The run's average of this metric, or `None` if it was not measured.
+    pub fn value(self, run: &Run) -> Option<u64> {
+        let (samples, average) = match self {
+            Self::Gas => (run.gas.as_slice(), run.average_gas()),
+            Self::DeploySize => (run.size.as_slice(), run.average_size()),
+            Self::RuntimeSize => (run.runtime_size.as_slice(), run.average_runtime_size()),
+        };
+        (!samples.is_empty()).then_some(average)
+    }
+}
+
+impl std::fmt::Display for Metric {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.label())
+    }
+}
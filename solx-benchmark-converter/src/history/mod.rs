@@ -0,0 +1,111 @@
+//!
+//! The historical benchmark store.
+//!
+//! Every converted benchmark of a `main` commit is kept as one dated JSON file
+//! in a directory, never rewritten, so the directory can be cached or synced
+//! between CI runs as-is. The trend report reads the whole directory back and
+//! looks for slow drifts the one-PR-against-`main` comparison cannot see.
+//!
+
+pub mod drift;
+pub mod entry;
+pub mod metric;
+pub mod series;
+pub mod trend;
+
+use std::path::PathBuf;
+
+use self::entry::Entry;
+
+///
+/// The historical benchmark store: a directory of dated JSON files, one per
+/// commit.
+///
+pub struct History {
+    /// The store directory.
+    pub directory: PathBuf,
+}
+
+impl History {
+    /// The extension of the entry files.
+    const ENTRY_EXTENSION: &'static str = "json";
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(directory: PathBuf) -> Self {
+        Self { directory }
+    }
+
+    ///
+    /// Appends an entry to the store, creating the directory on first use.
+    /// Returns the path of the written file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the commit is already recorded, or if creating the
+    /// directory or writing the file fails.
+    ///
+    pub fn insert(&self, entry: &Entry) -> anyhow::Result<PathBuf> {
+        std::fs::create_dir_all(self.directory.as_path()).map_err(|error| {
+            anyhow::anyhow!("History directory {:?} creation: {error}", self.directory)
+        })?;
+
+        let path = self
+            .directory
+            .join(format!("{}.{}", entry.file_stem(), Self::ENTRY_EXTENSION));
+        let file = std::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(path.as_path())
+            .map_err(|error| match error.kind() {
+                std::io::ErrorKind::AlreadyExists => anyhow::anyhow!(
+                    "Commit {} of {} is already recorded in {path:?}",
+                    entry.commit,
+                    entry.date
+                ),
+                _ => anyhow::anyhow!("History entry {path:?} creating: {error}"),
+            })?;
+        serde_json::to_writer(file, entry)
+            .map_err(|error| anyhow::anyhow!("History entry {path:?} writing: {error}"))?;
+        Ok(path)
+    }
+
+    ///
+    /// Reads every entry of the store, oldest first. A missing directory is
+    /// an empty history.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory or any entry cannot be read or parsed.
+    ///
+    pub fn load(&self) -> anyhow::Result<Vec<Entry>> {
+        if !self.directory.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut entries = Vec::new();
+        let directory = std::fs::read_dir(self.directory.as_path()).map_err(|error| {
+            anyhow::anyhow!("History directory {:?} reading: {error}", self.directory)
+        })?;
+        for file in directory {
+            let path = file
+                .map_err(|error| {
+                    anyhow::anyhow!("History directory {:?} reading: {error}", self.directory)
+                })?
+                .path();
+            if path.extension().and_then(|extension| extension.to_str())
+                != Some(Self::ENTRY_EXTENSION)
+            {
+                continue;
+            }
+            entries.push(Entry::try_from(path.as_path())?);
+        }
+        entries.sort_by(|left, right| {
+            left.date
+                .cmp(&right.date)
+                .then_with(|| left.commit.cmp(&right.commit))
+        });
+        Ok(entries)
+    }
+}
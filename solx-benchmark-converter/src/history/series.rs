@@ -0,0 +1,87 @@
+//!
+//! One test's measurement across commits.
+//!
+
+use std::collections::BTreeMap;
+
+use crate::history::entry::Entry;
+use crate::history::metric::Metric;
+
+///
+/// One test's measurement in one mode across commits.
+///
+#[derive(Debug, Clone)]
+pub struct Series {
+    /// The metric measured.
+    pub metric: Metric,
+    /// The test name.
+    pub test: String,
+    /// The mode the test ran in.
+    pub mode: String,
+    /// The measurement per entry, `None` where the entry did not measure it.
+    pub points: Vec<Option<u64>>,
+}
+
+impl Series {
+    /// The sparkline glyphs, lowest to highest.
+    const SPARKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+    ///
+    /// Collects every series measured in the entries, ordered by metric, test,
+    /// and mode. Each series has one point per entry.
+    ///
+    pub fn collect(entries: &[Entry]) -> Vec<Self> {
+        let mut series: BTreeMap<(Metric, &str, &str), Vec<Option<u64>>> = BTreeMap::new();
+        for (index, entry) in entries.iter().enumerate() {
+            for (test_name, test) in entry.benchmark.tests.iter() {
+                for (mode, run) in test.runs.iter() {
+                    for metric in Metric::ALL {
+                        let Some(value) = metric.value(run) else {
+                            continue;
+                        };
+                        series
+                            .entry((metric, test_name.as_str(), mode.as_str()))
+                            .or_insert_with(|| vec![None; entries.len()])[index] = Some(value);
+                    }
+                }
+            }
+        }
+        series
+            .into_iter()
+            .map(|((metric, test, mode), points)| Self {
+                metric,
+                test: test.to_owned(),
+                mode: mode.to_owned(),
+                points,
+            })
+            .collect()
+    }
+
+    ///
+    /// The measured points, skipping the entries that did not measure them.
+    ///
+    pub fn values(&self) -> impl Iterator<Item = u64> + '_ {
+        self.points.iter().flatten().copied()
+    }
+
+    ///
+    /// The series as a one-line chart, scaled between its minimum and
+    /// maximum, with a space for each unmeasured entry.
+    ///
+    pub fn sparkline(&self) -> String {
+        let (Some(min), Some(max)) = (self.values().min(), self.values().max()) else {
+            return String::new();
+        };
+        self.points
+            .iter()
+            .map(|point| match point {
+                None => ' ',
+                Some(_) if max == min => Self::SPARKS[0],
+                Some(value) => {
+                    let scale = (Self::SPARKS.len() - 1) as u64;
+                    Self::SPARKS[((value - min) * scale / (max - min)) as usize]
+                }
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,78 @@
+//!
+//! The trend report over the latest commits of the historical store.
+//!
+
+pub mod trend_template;
+pub mod xlsx;
+
+use rust_xlsxwriter::Workbook;
+
+use crate::history::drift::Drift;
+use crate::history::entry::Entry;
+use crate::history::series::Series;
+
+use self::trend_template::TrendTemplate;
+use self::xlsx::TrendXlsx;
+
+///
+/// The trend report over the latest commits of the historical store.
+///
+pub struct Trend {
+    /// The abbreviated commit hashes and their days, oldest first.
+    pub commits: Vec<(String, String)>,
+    /// Every series measured in the window.
+    pub series: Vec<Series>,
+    /// The drifting series, the largest change first.
+    pub drifts: Vec<Drift>,
+    /// The growth over the window a drift reaches, in percent.
+    pub threshold_percent: f64,
+}
+
+impl Trend {
+    /// The default number of latest commits the trend covers.
+    pub const DEFAULT_WINDOW: usize = 30;
+
+    /// The default drift threshold, in percent.
+    pub const DEFAULT_THRESHOLD_PERCENT: f64 = 1.0;
+
+    ///
+    /// Reduces the entries, oldest first, to the trend over the latest
+    /// `window` of them.
+    ///
+    pub fn new(entries: &[Entry], window: usize, threshold_percent: f64) -> Self {
+        let entries = &entries[entries.len().saturating_sub(window)..];
+        let series = Series::collect(entries);
+        let mut drifts: Vec<Drift> = series
+            .iter()
+            .filter_map(|series| Drift::detect(series, threshold_percent))
+            .collect();
+        drifts.sort_by(|left, right| right.change_percent.total_cmp(&left.change_percent));
+        Self {
+            commits: entries
+                .iter()
+                .map(|entry| (entry.short_commit().to_owned(), entry.day().to_owned()))
+                .collect(),
+            series,
+            drifts,
+            threshold_percent,
+        }
+    }
+
+    ///
+    /// Renders the Markdown report.
+    ///
+    pub fn markdown(&self) -> String {
+        TrendTemplate::rendered(self)
+    }
+
+    ///
+    /// Renders the XLSX report.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing a worksheet cell fails.
+    ///
+    pub fn xlsx(&self) -> anyhow::Result<Workbook> {
+        Ok(TrendXlsx::try_from(self)?.finalize())
+    }
+}
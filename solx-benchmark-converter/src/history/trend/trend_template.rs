@@ -0,0 +1,82 @@
+//!
+//! The Markdown trend report: every string the template interpolates,
+//! precomputed from the trend.
+//!
+//! Like the PR summary, the report's shape lives in `templates/trend.md`, and
+//! anything that formats a value is Rust.
+//!
+
+use askama::Template;
+
+use crate::history::trend::Trend;
+
+///
+/// The Markdown trend report.
+///
+#[derive(Template)]
+#[template(path = "trend.md", escape = "none")]
+pub struct TrendTemplate {
+    /// The window headline: its length and commit range.
+    pub window_line: String,
+    /// The drift verdict.
+    pub verdict_line: String,
+    /// The drift table rows.
+    pub drift_rows: Vec<Vec<String>>,
+}
+
+impl TrendTemplate {
+    /// Renders the Markdown report of the trend.
+    pub fn rendered(trend: &Trend) -> String {
+        Self::from_trend(trend)
+            .render()
+            .expect("template rendering writes to a String")
+    }
+
+    /// Everything the template interpolates, precomputed from the trend.
+    fn from_trend(trend: &Trend) -> Self {
+        let commit_count = trend.commits.len() as u64;
+        let window_line = match (trend.commits.first(), trend.commits.last()) {
+            (Some((first, first_day)), Some((last, last_day))) => format!(
+                "Last {}: `{first}` ({first_day}) … `{last}` ({last_day})",
+                crate::utils::count_noun(commit_count, "commit")
+            ),
+            _ => "No commits recorded".to_owned(),
+        };
+
+        let threshold = crate::utils::percent(trend.threshold_percent);
+        let verdict_line = if trend.drifts.is_empty() {
+            format!(
+                "✅ No slow drift: no series grew by {threshold} or more through commits under it each."
+            )
+        } else {
+            format!(
+                "⚠️ {} series drifted by {threshold} or more through commits under it each:",
+                crate::utils::commas(trend.drifts.len() as u64),
+            )
+        };
+
+        let drift_rows = trend
+            .drifts
+            .iter()
+            .map(|drift| {
+                vec![
+                    format!("`{}`", drift.test),
+                    drift.mode.clone(),
+                    drift.metric.label().to_owned(),
+                    crate::utils::commas(drift.first),
+                    crate::utils::commas(drift.last),
+                    crate::utils::percent(drift.change_percent),
+                    crate::utils::percent(drift.largest_step_percent),
+                    crate::utils::commas(drift.step_regressions as u64),
+                    format!("`{}`", drift.sparkline),
+                ]
+            })
+            .collect();
+
+        Self {
+            window_line,
+            verdict_line,
+            drift_rows,
+        }
+    }
+}
@@ -0,0 +1,118 @@
+//!
+//! The XLSX trend report.
+//!
+
+use rust_xlsxwriter::Workbook;
+
+use crate::history::metric::Metric;
+use crate::history::trend::Trend;
+use crate::output::xlsx::worksheet::Worksheet;
+
+///
+/// The XLSX trend report: the drifts, the commits of the window, and one
+/// sheet per metric with a column per commit and the window's overall change.
+///
+pub struct TrendXlsx {
+    /// The drifting series with their first and last measurements.
+    pub drifts: Worksheet,
+    /// The commits of the window, oldest first.
+    pub commits: Worksheet,
+    /// The full time series, one worksheet per metric in `Metric::ALL` order.
+    pub series: Vec<Worksheet>,
+}
+
+impl TrendXlsx {
+    ///
+    /// Returns the final workbook, dropping the worksheets without rows.
+    ///
+    pub fn finalize(self) -> Workbook {
+        let mut workbook = Workbook::new();
+        for worksheet in [self.drifts, self.commits].into_iter().chain(self.series) {
+            if !worksheet.rows.is_empty() {
+                workbook.push_worksheet(worksheet.into_inner());
+            }
+        }
+        workbook
+    }
+}
+
+impl TryFrom<&Trend> for TrendXlsx {
+    type Error = anyhow::Error;
+
+    fn try_from(trend: &Trend) -> Result<Self, Self::Error> {
+        let test = ("Test", 80);
+        let mode = ("Mode", 40);
+
+        let mut drifts = Worksheet::new("Drifts", vec![test, mode, ("Metric", 15)])?;
+        for drift in trend.drifts.iter() {
+            for (column_id, column_name, value) in
+                [(0, "First", drift.first), (1, "Last", drift.last)]
+            {
+                drifts.record(
+                    column_name,
+                    column_id,
+                    drift.test.as_str(),
+                    Some(drift.mode.as_str()),
+                    Some(drift.metric.label()),
+                    value,
+                    &[],
+                )?;
+            }
+        }
+        drifts.set_diffs(1, "Last", 0, "First", 2, 0)?;
+
+        let mut commits = Worksheet::new("Commits", vec![("Commit", 15), ("Date", 15)])?;
+        for (index, (commit, day)) in trend.commits.iter().enumerate() {
+            commits.record(
+                "Index",
+                0,
+                commit.as_str(),
+                Some(day.as_str()),
+                None,
+                index as u64,
+                &[],
+            )?;
+        }
+
+        let mut series_sheets = Vec::with_capacity(Metric::ALL.len());
+        for metric in Metric::ALL {
+            let mut worksheet = Worksheet::new(metric.label(), vec![test, mode])?;
+            for (commit_id, (commit, _)) in trend.commits.iter().enumerate() {
+                worksheet.add_toolchain_column(commit.as_str(), commit_id as u16)?;
+            }
+            for series in trend.series.iter().filter(|series| series.metric == metric) {
+                for (commit_id, point) in series.points.iter().enumerate() {
+                    let Some(value) = point else {
+                        continue;
+                    };
+                    worksheet.write_test_value(
+                        series.test.as_str(),
+                        Some(series.mode.as_str()),
+                        None,
+                        commit_id as u16,
+                        *value,
+                        &[],
+                    )?;
+                }
+            }
+            if trend.commits.len() > 1 {
+                let last_id = trend.commits.len() - 1;
+                worksheet.set_diffs(
+                    last_id as u16,
+                    trend.commits[last_id].0.as_str(),
+                    0,
+                    trend.commits[0].0.as_str(),
+                    trend.commits.len() as u16,
+                    0,
+                )?;
+            }
+            series_sheets.push(worksheet);
+        }
+
+        Ok(Self {
+            drifts,
+            commits,
+            series: series_sheets,
+        })
+    }
+}
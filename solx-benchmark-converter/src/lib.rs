@@ -6,10 +6,12 @@
 
 pub(crate) mod benchmark;
 pub(crate) mod comparison;
+pub(crate) mod history;
 pub(crate) mod input;
 pub(crate) mod output;
 pub(crate) mod pipeline;
 pub(crate) mod role;
+pub(crate) mod statistics;
 pub(crate) mod suite_kind;
 pub(crate) mod suite_outcome;
 pub(crate) mod summary_suite;
@@ -25,6 +27,9 @@ pub use crate::benchmark::test::input::Input as BenchmarkTestInput;
 pub use crate::benchmark::test::metadata::Metadata as BenchmarkTestMetadata;
 pub use crate::benchmark::test::selector::Selector as BenchmarkTestSelector;
 pub use crate::comparison::Comparison as OutputComparison;
+pub use crate::history::History;
+pub use crate::history::entry::Entry as HistoryEntry;
+pub use crate::history::trend::Trend;
 pub use crate::input::Input;
 pub use crate::input::build_failures::BuildFailuresReport;
 pub use crate::input::compilation_time::CompilationTimeReport;
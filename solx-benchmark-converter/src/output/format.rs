@@ -18,6 +18,8 @@ pub enum Format {
     Json,
     /// Excel spreadsheet format.
     Xlsx,
+    /// Self-contained HTML page with sortable and filterable tables.
+    Html,
 }
 
 impl Display for Format {
@@ -25,6 +27,7 @@ impl Display for Format {
         match self {
             Self::Json => write!(f, "json"),
             Self::Xlsx => write!(f, "xlsx"),
+            Self::Html => write!(f, "html"),
         }
     }
 }
@@ -0,0 +1,113 @@
+//!
+//! An inline SVG bar chart of relative changes.
+//!
+
+///
+/// An inline SVG bar chart of relative changes, with bars growing right of
+/// the zero axis for regressions and left of it for improvements.
+///
+/// Every coordinate is precomputed here; the template only places them.
+///
+pub struct Chart {
+    /// The chart caption.
+    pub title: String,
+    /// The image width.
+    pub width: u32,
+    /// The image height.
+    pub height: u32,
+    /// The x coordinate of the zero axis.
+    pub axis: u32,
+    /// The x coordinate the bar labels end at.
+    pub label_end: u32,
+    /// The x coordinate of the value labels.
+    pub value_x: u32,
+    /// The bars, top to bottom.
+    pub bars: Vec<Bar>,
+}
+
+///
+/// One bar of a chart.
+///
+pub struct Bar {
+    /// The bar label.
+    pub label: String,
+    /// The formatted value.
+    pub value: String,
+    /// The CSS class.
+    pub class: &'static str,
+    /// The left edge.
+    pub x: f64,
+    /// The top edge.
+    pub y: u32,
+    /// The width.
+    pub width: f64,
+    /// The baseline of the bar's texts.
+    pub text_y: u32,
+}
+
+impl Chart {
+    /// The width of the bar label column.
+    const LABEL_WIDTH: u32 = 140;
+    /// The width of each side of the zero axis.
+    const HALF_WIDTH: u32 = 160;
+    /// The width of the value label column.
+    const VALUE_WIDTH: u32 = 80;
+    /// The height of a bar.
+    pub const BAR_HEIGHT: u32 = 16;
+    /// The vertical distance between bar tops.
+    const ROW_HEIGHT: u32 = 24;
+    /// The gap between the columns.
+    const GAP: u32 = 8;
+
+    ///
+    /// Lays out a chart of the labeled relative changes, in percent. The
+    /// largest magnitude spans a full side of the axis.
+    ///
+    pub fn new(title: String, changes: &[(String, f64)]) -> Self {
+        let axis = Self::LABEL_WIDTH + Self::GAP + Self::HALF_WIDTH;
+        let scale = changes
+            .iter()
+            .map(|(_, change)| change.abs())
+            .fold(0.0, f64::max);
+        let bars = changes
+            .iter()
+            .enumerate()
+            .map(|(index, (label, change))| {
+                let width = if scale > 0.0 {
+                    change.abs() / scale * Self::HALF_WIDTH as f64
+                } else {
+                    0.0
+                };
+                let y = Self::GAP + index as u32 * Self::ROW_HEIGHT;
+                Bar {
+                    label: label.to_owned(),
+                    value: crate::utils::percent(*change),
+                    class: if *change > 0.0 {
+                        "regressed"
+                    } else if *change < 0.0 {
+                        "improved"
+                    } else {
+                        "unchanged"
+                    },
+                    x: if *change < 0.0 {
+                        axis as f64 - width
+                    } else {
+                        axis as f64
+                    },
+                    y,
+                    width,
+                    text_y: y + Self::BAR_HEIGHT - 4,
+                }
+            })
+            .collect();
+        Self {
+            title,
+            width: axis + Self::HALF_WIDTH + Self::GAP + Self::VALUE_WIDTH,
+            height: Self::GAP * 2 + changes.len() as u32 * Self::ROW_HEIGHT,
+            axis,
+            label_end: Self::LABEL_WIDTH,
+            value_x: axis + Self::HALF_WIDTH + Self::GAP,
+            bars,
+        }
+    }
+}
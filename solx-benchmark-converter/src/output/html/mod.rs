@@ -0,0 +1,38 @@
+//!
+//! HTML output format for benchmark data.
+//!
+//! A single static page with no external assets, so it opens anywhere a
+//! browser does: the worksheets of the XLSX report as sortable, filterable
+//! tables, preceded by the toolchain matrix, the per-comparison statistics
+//! with their charts, the failure regressions, and the top movers. Unlike
+//! the PR summary comment, nothing is truncated.
+//!
+
+pub mod chart;
+pub mod report_template;
+pub mod section;
+pub mod sheet_comparison;
+pub mod sheet_rows;
+pub mod table;
+
+use crate::benchmark::Benchmark;
+use crate::comparison::Comparison;
+
+use self::report_template::ReportTemplate;
+
+///
+/// HTML output format for benchmark data.
+///
+#[derive(Default)]
+pub struct Html {
+    /// The rendered page.
+    pub content: String,
+}
+
+impl From<(Benchmark, Vec<Comparison>)> for Html {
+    fn from((benchmark, comparisons): (Benchmark, Vec<Comparison>)) -> Self {
+        Self {
+            content: ReportTemplate::rendered(&benchmark, comparisons.as_slice()),
+        }
+    }
+}
@@ -0,0 +1,430 @@
+//!
+//! The HTML report page: every table, chart, and line the template
+//! interpolates, precomputed from the benchmark.
+//!
+//! As with the summary comment, the page's shape lives in
+//! `templates/report.html` and anything that formats a value is Rust. The
+//! template escapes every interpolated string, since test and toolchain names
+//! come from the benchmarked projects.
+//!
+
+use std::cmp::Reverse;
+
+use askama::Template;
+
+use crate::benchmark::Benchmark;
+use crate::comparison::Comparison;
+use crate::output::html::chart::Chart;
+use crate::output::html::section::Section;
+use crate::output::html::sheet_comparison::SheetComparison;
+use crate::output::html::sheet_rows::Row;
+use crate::output::html::sheet_rows::SheetRows;
+use crate::output::html::table::Cell;
+use crate::output::html::table::Table;
+use crate::output::measurement::Measurement;
+use crate::output::summary::failure_regressions::FailureRegression;
+use crate::output::summary::failure_regressions::FailureRegressions;
+use crate::output::xlsx::sheet::Sheet;
+use crate::toolchain_matrix::ToolchainMatrix;
+
+///
+/// The HTML report page.
+///
+#[derive(Template)]
+#[template(path = "report.html")]
+pub struct ReportTemplate {
+    /// The page title.
+    pub title: String,
+    /// The line counting tests, toolchains, and comparisons.
+    pub overview: String,
+    /// The sections, in page order.
+    pub sections: Vec<Section>,
+    /// The bar height shared by every chart.
+    pub bar_height: u32,
+}
+
+impl ReportTemplate {
+    /// Renders the page for the benchmark and its comparisons.
+    pub fn rendered(benchmark: &Benchmark, comparisons: &[Comparison]) -> String {
+        Self::new(benchmark, comparisons)
+            .render()
+            .expect("template rendering writes to a String")
+    }
+
+    /// Everything the template interpolates, precomputed from the benchmark.
+    fn new(benchmark: &Benchmark, comparisons: &[Comparison]) -> Self {
+        let toolchain_set = benchmark.toolchains();
+        let matrix = ToolchainMatrix::detect(&toolchain_set);
+        let toolchains: Vec<String> = toolchain_set.into_iter().collect();
+        // Like the XLSX diff columns, a comparison naming an absent toolchain
+        // has nothing to compare and is left out.
+        let comparisons: Vec<&Comparison> = comparisons
+            .iter()
+            .filter(|comparison| {
+                toolchains.contains(&comparison.left) && toolchains.contains(&comparison.right)
+            })
+            .collect();
+
+        let mut sheets: Vec<SheetRows> = Sheet::ALL.into_iter().map(SheetRows::new).collect();
+        for test in benchmark.tests.values() {
+            for (mode, run) in test.runs.iter() {
+                for measurement in Measurement::of_run(test, run) {
+                    sheets[measurement.sheet as usize].insert(mode.as_str(), &measurement);
+                }
+            }
+        }
+        sheets.retain(|rows| !rows.rows.is_empty());
+
+        let mut sections = vec![Self::toolchain_section(
+            matrix,
+            toolchains.as_slice(),
+            sheets.as_slice(),
+        )];
+        if !comparisons.is_empty() {
+            sections.push(Self::stats_section(
+                comparisons.as_slice(),
+                sheets.as_slice(),
+            ));
+            sections.push(Self::failures_section(
+                comparisons.as_slice(),
+                sheets.as_slice(),
+            ));
+            sections.push(Self::movers_section(
+                comparisons.as_slice(),
+                sheets.as_slice(),
+            ));
+        }
+        for rows in sheets.iter() {
+            sections.push(Self::sheet_section(
+                rows,
+                toolchains.as_slice(),
+                comparisons.as_slice(),
+            ));
+        }
+
+        Self {
+            title: "solx benchmark report".to_owned(),
+            overview: format!(
+                "{} · {} · {}",
+                crate::utils::count_noun(benchmark.tests.len() as u64, "test"),
+                crate::utils::count_noun(toolchains.len() as u64, "toolchain"),
+                crate::utils::count_noun(comparisons.len() as u64, "comparison"),
+            ),
+            sections,
+            bar_height: Chart::BAR_HEIGHT,
+        }
+    }
+
+    /// The toolchains with their roles, pipelines, and rows measured per sheet.
+    fn toolchain_section(
+        matrix: ToolchainMatrix,
+        toolchains: &[String],
+        sheets: &[SheetRows],
+    ) -> Section {
+        let mut headers = vec![
+            "Toolchain".to_owned(),
+            "Role".to_owned(),
+            "Pipeline".to_owned(),
+        ];
+        headers.extend(sheets.iter().map(|rows| rows.sheet.spec().0.to_owned()));
+
+        let table_rows = toolchains
+            .iter()
+            .map(|toolchain| {
+                let mut cells = vec![
+                    Cell::label(toolchain.as_str()),
+                    Cell::label(matrix.classify(toolchain).0.to_string()),
+                    Cell::label(
+                        ToolchainMatrix::pipeline_of(toolchain)
+                            .map(|pipeline| pipeline.to_string())
+                            .unwrap_or_default(),
+                    ),
+                ];
+                cells.extend(sheets.iter().map(|rows| {
+                    Cell::count(
+                        rows.rows
+                            .iter()
+                            .filter(|row| row.values.contains_key(toolchain))
+                            .count() as u64,
+                    )
+                }));
+                cells
+            })
+            .collect();
+
+        Section {
+            heading: "Toolchain matrix".to_owned(),
+            note: "The rows each toolchain measured on each sheet.".to_owned(),
+            charts: Vec::new(),
+            table: Table {
+                id: "toolchains".to_owned(),
+                headers,
+                rows: table_rows,
+            },
+        }
+    }
+
+    /// Each comparison's statistics per sheet, charted by total change.
+    fn stats_section(comparisons: &[&Comparison], sheets: &[SheetRows]) -> Section {
+        let mut charts = Vec::with_capacity(comparisons.len());
+        let mut table_rows = Vec::new();
+        for comparison in comparisons.iter() {
+            let mut changes = Vec::new();
+            for rows in sheets.iter() {
+                let stats = SheetComparison::new(rows, comparison);
+                if !stats.counter.collected() {
+                    continue;
+                }
+                let sheet_name = rows.sheet.spec().0;
+                if let Some(change) = stats.change_percent() {
+                    changes.push((sheet_name.to_owned(), change));
+                }
+                table_rows.push(vec![
+                    Cell::label(Self::comparison_label(comparison)),
+                    Cell::label(sheet_name),
+                    Cell::count(stats.counter.cells),
+                    Cell::count(stats.counter.diffs),
+                    Cell::count(stats.improved),
+                    Cell::count(stats.regressed),
+                    Cell::count(stats.baseline_total),
+                    Cell::count(stats.pr_total),
+                    Cell::change(stats.change_percent()),
+                ]);
+            }
+            if !changes.is_empty() {
+                charts.push(Chart::new(
+                    Self::comparison_label(comparison),
+                    changes.as_slice(),
+                ));
+            }
+        }
+
+        Section {
+            heading: "Comparison statistics".to_owned(),
+            note: "Totals over the rows both toolchains measured; positive changes are the PR \
+                   measuring more than the baseline."
+                .to_owned(),
+            charts,
+            table: Table {
+                id: "statistics".to_owned(),
+                headers: [
+                    "Comparison",
+                    "Sheet",
+                    "Compared",
+                    "Differing",
+                    "Improved",
+                    "Regressed",
+                    "Baseline total",
+                    "PR total",
+                    "Change",
+                ]
+                .map(str::to_owned)
+                .to_vec(),
+                rows: table_rows,
+            },
+        }
+    }
+
+    /// The projects failing more on the PR than on the baseline.
+    fn failures_section(comparisons: &[&Comparison], sheets: &[SheetRows]) -> Section {
+        let mut table_rows = Vec::new();
+        for comparison in comparisons.iter() {
+            let mut regressions = FailureRegressions::default();
+            for rows in sheets.iter() {
+                let Some(kind) = rows.sheet.failure_kind() else {
+                    continue;
+                };
+                for (row, pr, baseline) in
+                    rows.pairs(comparison.left.as_str(), comparison.right.as_str())
+                {
+                    if pr > baseline {
+                        regressions.push(FailureRegression {
+                            label: row.label(),
+                            mode: Self::comparison_label(comparison),
+                            kind,
+                            main: baseline as usize,
+                            pr: pr as usize,
+                        });
+                    }
+                }
+            }
+            table_rows.extend(regressions.ranked().into_iter().map(|regression| {
+                vec![
+                    Cell::label(regression.mode.as_str()),
+                    Cell::label(regression.label.as_str()),
+                    Cell::label(regression.kind.to_string()),
+                    Cell::count(regression.main as u64),
+                    Cell::count(regression.pr as u64),
+                    Cell::count((regression.pr - regression.main) as u64),
+                ]
+            }));
+        }
+
+        Section {
+            heading: "Failure regressions".to_owned(),
+            note: "Projects whose PR run failed more than its baseline.".to_owned(),
+            charts: Vec::new(),
+            table: Table {
+                id: "failures".to_owned(),
+                headers: [
+                    "Comparison",
+                    "Project",
+                    "Kind",
+                    "Baseline",
+                    "PR",
+                    "New failures",
+                ]
+                .map(str::to_owned)
+                .to_vec(),
+                rows: table_rows,
+            },
+        }
+    }
+
+    /// Every differing gas and size row, the largest difference first.
+    fn movers_section(comparisons: &[&Comparison], sheets: &[SheetRows]) -> Section {
+        let mut table_rows = Vec::new();
+        for comparison in comparisons.iter() {
+            let mut movers: Vec<(Sheet, &Row, u64, u64)> = Vec::new();
+            for rows in sheets.iter().filter(|rows| rows.sheet.is_output()) {
+                movers.extend(
+                    rows.pairs(comparison.left.as_str(), comparison.right.as_str())
+                        .filter(|(_, pr, baseline)| pr != baseline)
+                        .map(|(row, pr, baseline)| (rows.sheet, row, baseline, pr)),
+                );
+            }
+            movers.sort_by_key(|(_, _, baseline, pr)| {
+                Reverse((*pr as i128 - *baseline as i128).unsigned_abs())
+            });
+            table_rows.extend(movers.into_iter().map(|(sheet, row, baseline, pr)| {
+                vec![
+                    Cell::label(Self::comparison_label(comparison)),
+                    Cell::label(sheet.spec().0),
+                    Cell::label(row.label()),
+                    Cell::count(baseline),
+                    Cell::count(pr),
+                    Cell::delta(pr as i128 - baseline as i128),
+                    Cell::change(crate::utils::relative_percent(pr, baseline)),
+                ]
+            }));
+        }
+
+        Section {
+            heading: "Top movers".to_owned(),
+            note: "Every gas and bytecode-size row that differs, the largest difference first."
+                .to_owned(),
+            charts: Vec::new(),
+            table: Table {
+                id: "movers".to_owned(),
+                headers: [
+                    "Comparison",
+                    "Sheet",
+                    "Test",
+                    "Baseline",
+                    "PR",
+                    "Δ",
+                    "Change",
+                ]
+                .map(str::to_owned)
+                .to_vec(),
+                rows: table_rows,
+            },
+        }
+    }
+
+    /// One worksheet of the XLSX report: a value column per toolchain and a
+    /// change column per comparison.
+    fn sheet_section(
+        rows: &SheetRows,
+        toolchains: &[String],
+        comparisons: &[&Comparison],
+    ) -> Section {
+        let (name, spec_headers) = rows.sheet.spec();
+        let toolchains: Vec<&String> = toolchains
+            .iter()
+            .filter(|toolchain| {
+                rows.rows
+                    .iter()
+                    .any(|row| row.values.contains_key(*toolchain))
+            })
+            .collect();
+
+        let mut headers: Vec<String> = spec_headers
+            .iter()
+            .map(|(header, _)| (*header).to_owned())
+            .collect();
+        headers.extend(toolchains.iter().map(|toolchain| (*toolchain).to_owned()));
+        headers.extend(
+            comparisons
+                .iter()
+                .map(|comparison| format!("Change {}", Self::comparison_label(comparison))),
+        );
+        headers.extend(
+            comparisons
+                .iter()
+                .map(|comparison| format!("p-value {}", Self::comparison_label(comparison))),
+        );
+
+        let table_rows = rows
+            .rows
+            .iter()
+            .map(|row| {
+                let mut cells = vec![Cell::label(row.project.as_str())];
+                if spec_headers.len() > 1 {
+                    cells.push(Cell::label(row.contract.clone().unwrap_or_default()));
+                }
+                if spec_headers.len() > 2 {
+                    cells.push(Cell::label(row.function.clone().unwrap_or_default()));
+                }
+                cells.extend(toolchains.iter().map(|toolchain| {
+                    row.values
+                        .get(*toolchain)
+                        .map_or_else(Cell::blank, |value| {
+                            Cell::measurement(
+                                *value,
+                                row.samples
+                                    .get(*toolchain)
+                                    .map(Vec::as_slice)
+                                    .unwrap_or_default(),
+                            )
+                        })
+                }));
+                cells.extend(comparisons.iter().map(|comparison| {
+                    match (
+                        row.values.get(&comparison.left),
+                        row.values.get(&comparison.right),
+                    ) {
+                        (Some(pr), Some(baseline)) if *pr != 0 && *baseline != 0 => {
+                            Cell::change(crate::utils::relative_percent(*pr, *baseline))
+                        }
+                        _ => Cell::blank(),
+                    }
+                }));
+                cells.extend(comparisons.iter().map(|comparison| {
+                    Cell::p_value(row.p_value(comparison.left.as_str(), comparison.right.as_str()))
+                }));
+                cells
+            })
+            .collect();
+
+        Section {
+            heading: name.to_owned(),
+            note: format!(
+                "{} as in the XLSX worksheet. Values averaged from several samples show their \
+                 confidence interval on hover, and their comparisons a Mann–Whitney p-value.",
+                crate::utils::count_noun(rows.rows.len() as u64, "row")
+            ),
+            charts: Vec::new(),
+            table: Table {
+                id: format!("sheet-{}", name.to_lowercase().replace(' ', "-")),
+                headers,
+                rows: table_rows,
+            },
+        }
+    }
+
+    /// The comparison's column pair, PR first.
+    fn comparison_label(comparison: &Comparison) -> String {
+        format!("{} vs {}", comparison.left, comparison.right)
+    }
+}
@@ -0,0 +1,20 @@
+//!
+//! A section of the HTML report.
+//!
+
+use crate::output::html::chart::Chart;
+use crate::output::html::table::Table;
+
+///
+/// A section of the HTML report: a heading, its charts, and its table.
+///
+pub struct Section {
+    /// The heading.
+    pub heading: String,
+    /// The explanatory line under the heading.
+    pub note: String,
+    /// The charts above the table.
+    pub charts: Vec<Chart>,
+    /// The table.
+    pub table: Table,
+}
@@ -0,0 +1,53 @@
+//!
+//! One comparison's statistics over one worksheet.
+//!
+
+use crate::comparison::Comparison;
+use crate::output::html::sheet_rows::SheetRows;
+use crate::output::summary::diff_counter::DiffCounter;
+
+///
+/// One comparison's statistics over one worksheet, counting only the rows
+/// both toolchains measured.
+///
+#[derive(Default)]
+pub struct SheetComparison {
+    /// The paired rows and the differing ones.
+    pub counter: DiffCounter,
+    /// Rows where the PR value is lower.
+    pub improved: u64,
+    /// Rows where the PR value is higher.
+    pub regressed: u64,
+    /// The baseline total over the paired rows.
+    pub baseline_total: u64,
+    /// The PR total over the paired rows.
+    pub pr_total: u64,
+}
+
+impl SheetComparison {
+    ///
+    /// Compares the comparison's left, PR, column against its right, baseline,
+    /// column.
+    ///
+    pub fn new(rows: &SheetRows, comparison: &Comparison) -> Self {
+        let mut stats = Self::default();
+        for (_, pr, baseline) in rows.pairs(comparison.left.as_str(), comparison.right.as_str()) {
+            stats.counter.observe(pr, baseline);
+            if pr < baseline {
+                stats.improved += 1;
+            } else if pr > baseline {
+                stats.regressed += 1;
+            }
+            stats.baseline_total += baseline;
+            stats.pr_total += pr;
+        }
+        stats
+    }
+
+    ///
+    /// The relative change of the PR total, `None` without a baseline total.
+    ///
+    pub fn change_percent(&self) -> Option<f64> {
+        crate::utils::relative_percent(self.pr_total, self.baseline_total)
+    }
+}
@@ -0,0 +1,134 @@
+//!
+//! The rows of one worksheet, with a value per toolchain.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use crate::output::measurement::Measurement;
+use crate::output::xlsx::sheet::Sheet;
+use crate::statistics::mann_whitney::MannWhitney;
+use crate::statistics::noise::Noise;
+
+///
+/// The rows of one worksheet, in first-measured order like the XLSX rows.
+///
+pub struct SheetRows {
+    /// The worksheet.
+    pub sheet: Sheet,
+    /// The rows.
+    pub rows: Vec<Row>,
+    /// Row indexes by project, contract, and function.
+    index: HashMap<(String, Option<String>, Option<String>), usize>,
+}
+
+///
+/// One row of a worksheet.
+///
+pub struct Row {
+    /// The project.
+    pub project: String,
+    /// The contract, on per-contract sheets.
+    pub contract: Option<String>,
+    /// The function, on per-function sheets.
+    pub function: Option<String>,
+    /// The values by toolchain.
+    pub values: BTreeMap<String, u64>,
+    /// The samples the values were averaged from, by toolchain.
+    pub samples: BTreeMap<String, Vec<u64>>,
+    /// How noisy the values are, or `None` for counts.
+    pub noise: Option<Noise>,
+}
+
+impl SheetRows {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(sheet: Sheet) -> Self {
+        Self {
+            sheet,
+            rows: Vec::new(),
+            index: HashMap::new(),
+        }
+    }
+
+    ///
+    /// Records a toolchain's measurement, adding its row on first sight.
+    ///
+    pub fn insert(&mut self, toolchain: &str, measurement: &Measurement) {
+        let key = (
+            measurement.project.to_owned(),
+            measurement.contract.map(str::to_owned),
+            measurement.function.map(str::to_owned),
+        );
+        let index = match self.index.get(&key) {
+            Some(index) => *index,
+            None => {
+                let (project, contract, function) = key.clone();
+                self.rows.push(Row {
+                    project,
+                    contract,
+                    function,
+                    values: BTreeMap::new(),
+                    samples: BTreeMap::new(),
+                    noise: measurement.noise,
+                });
+                self.index.insert(key, self.rows.len() - 1);
+                self.rows.len() - 1
+            }
+        };
+        let row = &mut self.rows[index];
+        row.values.insert(toolchain.to_owned(), measurement.value);
+        row.samples
+            .insert(toolchain.to_owned(), measurement.samples.to_vec());
+    }
+
+    ///
+    /// The PR and baseline values of each row both toolchains measured.
+    ///
+    pub fn pairs<'a>(
+        &'a self,
+        pr: &'a str,
+        baseline: &'a str,
+    ) -> impl Iterator<Item = (&'a Row, u64, u64)> + 'a {
+        self.rows
+            .iter()
+            .filter_map(move |row| Some((row, *row.values.get(pr)?, *row.values.get(baseline)?)))
+    }
+}
+
+impl Row {
+    ///
+    /// The row's project, contract, and function, as far as the sheet has
+    /// them.
+    ///
+    pub fn label(&self) -> String {
+        let mut label = self.project.clone();
+        if let Some(contract) = self.contract.as_deref() {
+            label.push_str(" · ");
+            label.push_str(contract);
+        }
+        if let Some(function) = self.function.as_deref() {
+            label.push_str(" · ");
+            label.push_str(function);
+        }
+        label
+    }
+
+    ///
+    /// The Mann–Whitney p-value of the PR samples against the baseline ones.
+    ///
+    /// Like the XLSX p-value columns, a row gets one only if its values are
+    /// noisy and either side was averaged from several samples.
+    ///
+    pub fn p_value(&self, pr: &str, baseline: &str) -> Option<f64> {
+        if self.noise != Some(Noise::Noisy) {
+            return None;
+        }
+        let (pr, baseline) = (self.samples.get(pr)?, self.samples.get(baseline)?);
+        if pr.len() < 2 && baseline.len() < 2 {
+            return None;
+        }
+        MannWhitney::p_value(pr, baseline)
+    }
+}
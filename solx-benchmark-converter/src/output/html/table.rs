@@ -0,0 +1,139 @@
+//!
+//! A sortable, filterable table of the HTML report.
+//!
+
+use crate::statistics::confidence_interval::ConfidenceInterval;
+
+///
+/// A sortable, filterable table of the HTML report.
+///
+pub struct Table {
+    /// The element identifier, which also names the filter input.
+    pub id: String,
+    /// The column headers.
+    pub headers: Vec<String>,
+    /// The rows, each with a cell per header.
+    pub rows: Vec<Vec<Cell>>,
+}
+
+///
+/// A table cell: its text, the number it sorts by, if it is numeric, and its
+/// tooltip.
+///
+pub struct Cell {
+    /// The displayed text.
+    pub text: String,
+    /// The sort key of a numeric cell. Text cells sort by their text.
+    pub sort: Option<String>,
+    /// The CSS class.
+    pub class: &'static str,
+    /// The tooltip, such as a confidence interval.
+    pub title: Option<String>,
+}
+
+impl Cell {
+    ///
+    /// A text cell.
+    ///
+    pub fn label(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            sort: None,
+            class: "",
+            title: None,
+        }
+    }
+
+    ///
+    /// An empty cell, sorting below every number.
+    ///
+    pub fn blank() -> Self {
+        Self {
+            text: String::new(),
+            sort: Some("-Infinity".to_owned()),
+            class: "number",
+            title: None,
+        }
+    }
+
+    ///
+    /// A count or measurement.
+    ///
+    pub fn count(value: u64) -> Self {
+        Self {
+            text: crate::utils::commas(value),
+            sort: Some(value.to_string()),
+            class: "number",
+            title: None,
+        }
+    }
+
+    ///
+    /// A measurement, with the confidence interval of its samples as the
+    /// tooltip if it was averaged from several.
+    ///
+    pub fn measurement(value: u64, samples: &[u64]) -> Self {
+        Self {
+            title: ConfidenceInterval::from_samples(samples).map(|interval| interval.to_string()),
+            ..Self::count(value)
+        }
+    }
+
+    ///
+    /// A signed PR-minus-baseline difference.
+    ///
+    pub fn delta(delta: i128) -> Self {
+        Self {
+            text: crate::utils::signed_commas(delta),
+            sort: Some(delta.to_string()),
+            class: Self::direction(delta.signum() as f64),
+            title: None,
+        }
+    }
+
+    ///
+    /// A relative change, or a dash without a baseline.
+    ///
+    pub fn change(percentage: Option<f64>) -> Self {
+        match percentage {
+            Some(percentage) => Self {
+                text: crate::utils::percent(percentage),
+                sort: Some(percentage.to_string()),
+                class: Self::direction(percentage),
+                title: None,
+            },
+            None => Self {
+                text: "—".to_owned(),
+                ..Self::blank()
+            },
+        }
+    }
+
+    ///
+    /// A Mann–Whitney p-value, or an empty cell without one.
+    ///
+    pub fn p_value(p_value: Option<f64>) -> Self {
+        match p_value {
+            Some(p_value) => Self {
+                text: format!("{p_value:.4}"),
+                sort: Some(p_value.to_string()),
+                class: "number",
+                title: None,
+            },
+            None => Self::blank(),
+        }
+    }
+
+    ///
+    /// The class coloring a change: growth is a regression for every sheet.
+    ///
+    fn direction(change: f64) -> &'static str {
+        if change > 0.0 {
+            "number regressed"
+        } else if change < 0.0 {
+            "number improved"
+        } else {
+            "number"
+        }
+    }
+}
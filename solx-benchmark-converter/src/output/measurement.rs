@@ -0,0 +1,130 @@
+//!
+//! One measured cell of the benchmark reports.
+//!
+
+use crate::benchmark::test::Test;
+use crate::benchmark::test::run::Run;
+use crate::output::xlsx::sheet::Sheet;
+use crate::statistics::noise::Noise;
+
+///
+/// One measured cell of the benchmark reports: a run's value on one sheet.
+///
+/// The XLSX and HTML reports both lay their tables out from these, so a test
+/// cannot land on a sheet in one report and be missing from the other.
+///
+pub struct Measurement<'a> {
+    /// The sheet the value belongs to.
+    pub sheet: Sheet,
+    /// The project the test belongs to.
+    pub project: &'a str,
+    /// The contract, for per-contract sheets.
+    pub contract: Option<&'a str>,
+    /// The function, for per-function sheets.
+    pub function: Option<&'a str>,
+    /// The averaged value.
+    pub value: u64,
+    /// The samples the value was averaged from, or empty for a count.
+    pub samples: &'a [u64],
+    /// How noisy the value is, or `None` for a count.
+    pub noise: Option<Noise>,
+}
+
+impl<'a> Measurement<'a> {
+    ///
+    /// Every value a run of the test contributes, in sheet order within the
+    /// run.
+    ///
+    pub fn of_run(test: &'a Test, run: &'a Run) -> Vec<Self> {
+        let project = test.metadata.selector.project.as_str();
+        let contract = test.metadata.selector.case.as_deref();
+        let function = test
+            .metadata
+            .selector
+            .input
+            .as_ref()
+            .and_then(|input| input.runtime_name());
+        let per_project = |sheet: Sheet, value: u64, samples: &'a [u64]| Self {
+            sheet,
+            project,
+            contract: None,
+            function: None,
+            value,
+            samples,
+            noise: sheet.noise(test.gas_noise),
+        };
+
+        let mut measurements = Vec::new();
+        if !run.compilation_time.is_empty() {
+            measurements.push(per_project(
+                Sheet::CompilationTime,
+                run.average_compilation_time(),
+                run.compilation_time.as_slice(),
+            ));
+        }
+        if !run.testing_time.is_empty() {
+            measurements.push(per_project(
+                Sheet::TestingTime,
+                run.average_testing_time(),
+                run.testing_time.as_slice(),
+            ));
+        }
+        if let Some(build_failures) = run.build_failures_count() {
+            measurements.push(per_project(
+                Sheet::BuildFailures,
+                build_failures as u64,
+                &[],
+            ));
+        }
+        if let Some(test_failures) = run.test_failures_count() {
+            measurements.push(per_project(Sheet::TestFailures, test_failures as u64, &[]));
+        }
+
+        if contract.is_none() && function.is_none() {
+            return measurements;
+        }
+        let per_contract = |sheet: Sheet, value: u64, samples: &'a [u64]| Self {
+            sheet,
+            project,
+            contract,
+            function: None,
+            value,
+            samples,
+            noise: sheet.noise(test.gas_noise),
+        };
+        if test.is_deploy() {
+            if test.non_zero_gas_values > 0 {
+                measurements.push(per_contract(
+                    Sheet::DeployFee,
+                    run.average_gas(),
+                    run.gas.as_slice(),
+                ));
+            }
+        } else {
+            measurements.push(Self {
+                sheet: Sheet::RuntimeFee,
+                project,
+                contract,
+                function,
+                value: run.average_gas(),
+                samples: run.gas.as_slice(),
+                noise: Sheet::RuntimeFee.noise(test.gas_noise),
+            });
+        }
+        if !run.size.is_empty() {
+            measurements.push(per_contract(
+                Sheet::DeploySize,
+                run.average_size(),
+                run.size.as_slice(),
+            ));
+        }
+        if !run.runtime_size.is_empty() {
+            measurements.push(per_contract(
+                Sheet::RuntimeSize,
+                run.average_runtime_size(),
+                run.runtime_size.as_slice(),
+            ));
+        }
+        measurements
+    }
+}
@@ -3,7 +3,9 @@
 //!
 
 pub mod format;
+pub mod html;
 pub mod json;
+pub mod measurement;
 pub mod summary;
 pub mod xlsx;
 
@@ -15,6 +17,7 @@ use crate::benchmark::Benchmark;
 use crate::comparison::Comparison;
 
 use self::format::Format;
+use self::html::Html;
 use self::json::Json;
 use self::xlsx::Xlsx;
 
@@ -26,6 +29,8 @@ pub enum Output {
     Json(String),
     /// Benchmark Excel/XLSX output.
     Xlsx(Workbook),
+    /// Benchmark HTML output.
+    Html(String),
 }
 
 impl Output {
@@ -38,7 +43,7 @@ impl Output {
     ///
     pub fn write_to_file(self, path: PathBuf) -> anyhow::Result<()> {
         match self {
-            Self::Json(content) => {
+            Self::Json(content) | Self::Html(content) => {
                 std::fs::write(path.as_path(), content)
                     .map_err(|error| anyhow::anyhow!("Benchmark file {path:?} writing: {error}"))?;
             }
@@ -61,6 +66,7 @@ impl TryFrom<(Benchmark, Vec<Comparison>, Format)> for Output {
         Ok(match output_format {
             Format::Json => Json::from(benchmark).into(),
             Format::Xlsx => Xlsx::try_from((benchmark, comparisons))?.into(),
+            Format::Html => Html::from((benchmark, comparisons)).into(),
         })
     }
 }
@@ -71,6 +77,12 @@ impl From<Json> for Output {
     }
 }
 
+impl From<Html> for Output {
+    fn from(value: Html) -> Self {
+        Self::Html(value.content)
+    }
+}
+
 impl From<Xlsx> for Output {
     fn from(value: Xlsx) -> Self {
         Self::Xlsx(value.finalize())
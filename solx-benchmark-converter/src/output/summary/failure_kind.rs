@@ -9,6 +9,7 @@ use std::fmt::Result;
 ///
 /// Whether a failure regression is a build failure or a test failure.
 ///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FailureKind {
     /// A compilation failure.
     Build,
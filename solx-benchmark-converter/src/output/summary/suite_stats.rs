@@ -21,6 +21,7 @@ use crate::output::summary::suite_row::SuiteRow;
 use crate::output::summary::top_movers::TopMovers;
 use crate::pipeline::Pipeline;
 use crate::role::Role;
+use crate::statistics::noise::Noise;
 use crate::suite_outcome::SuiteOutcome;
 use crate::summary_suite::SummarySuite;
 use crate::toolchain_matrix::ToolchainMatrix;
@@ -89,6 +90,9 @@ pub struct SuiteStats {
     /// they are reported next to the jitter median rather than folded into it
     /// as a fabricated sample.
     pub gas_one_sided: u64,
+    /// Non-gated two-sided pairs whose gas samples differ significantly
+    /// rather than by fuzzing noise alone. Reported, never gated on.
+    pub gas_significant: u64,
 
     /// Failures on the PR runs in excess of their main counterparts.
     pub new_build_failures: usize,
@@ -300,6 +304,9 @@ impl SuiteStats {
                         stats.gas_one_sided += 1;
                     } else if let Some(pct) = crate::utils::relative_percent(pr_gas, main_gas) {
                         stats.gas_jitter_percents.push(pct.abs());
+                        if Noise::Noisy.is_significant(&pr.gas, &main.gas) {
+                            stats.gas_significant += 1;
+                        }
                     }
                 }
             }
@@ -405,8 +412,8 @@ impl SuiteStats {
         }
     }
 
-    /// The gas column: the gate verdict, or the jitter median and one-sided
-    /// count on a non-gated suite.
+    /// The gas column: the gate verdict, or the jitter median, significant
+    /// and one-sided counts on a non-gated suite.
     fn gas_cell(&self) -> String {
         if !self.gas.collected() {
             return "⚪ not collected".to_owned();
@@ -426,6 +433,12 @@ impl SuiteStats {
                     crate::utils::commas(self.gas.cells)
                 ));
             }
+            if self.gas_significant > 0 {
+                parts.push(format!(
+                    "{} significant",
+                    crate::utils::commas(self.gas_significant)
+                ));
+            }
             if self.gas_one_sided > 0 {
                 parts.push(format!(
                     "{} one-sided",
@@ -11,6 +11,7 @@ use rust_xlsxwriter::Workbook;
 
 use crate::benchmark::Benchmark;
 use crate::comparison::Comparison;
+use crate::output::measurement::Measurement;
 
 use self::sheet::Sheet;
 use self::worksheet::Worksheet;
@@ -105,105 +106,19 @@ impl TryFrom<(Benchmark, Vec<Comparison>)> for Xlsx {
     ) -> Result<Self, Self::Error> {
         let mut xlsx = Self::new()?;
 
-        for test in benchmark.tests.into_values() {
-            let is_deployer = test.is_deploy();
-            let project = test.metadata.selector.project;
-            let contract = test.metadata.selector.case.as_deref();
-            let function = test
-                .metadata
-                .selector
-                .input
-                .as_ref()
-                .and_then(|input| input.runtime_name());
-
-            for (mode_name, run) in test.runs.into_iter() {
-                let toolchain_id = xlsx.get_toolchain_id(mode_name.as_str());
-                let mode = mode_name.as_str();
-                let project = project.as_str();
-
-                if !run.compilation_time.is_empty() {
-                    xlsx.sheet(Sheet::CompilationTime).record(
-                        mode,
+        for test in benchmark.tests.values() {
+            for (mode, run) in test.runs.iter() {
+                let toolchain_id = xlsx.get_toolchain_id(mode.as_str());
+                for measurement in Measurement::of_run(test, run) {
+                    xlsx.sheet(measurement.sheet).record(
+                        mode.as_str(),
                         toolchain_id,
-                        project,
-                        None,
-                        None,
-                        run.average_compilation_time(),
-                    )?;
-                }
-                if !run.testing_time.is_empty() {
-                    xlsx.sheet(Sheet::TestingTime).record(
-                        mode,
-                        toolchain_id,
-                        project,
-                        None,
-                        None,
-                        run.average_testing_time(),
-                    )?;
-                }
-                if let Some(build_failures) = run.build_failures_count() {
-                    xlsx.sheet(Sheet::BuildFailures).record(
-                        mode,
-                        toolchain_id,
-                        project,
-                        None,
-                        None,
-                        build_failures as u64,
-                    )?;
-                }
-                if let Some(test_failures) = run.test_failures_count() {
-                    xlsx.sheet(Sheet::TestFailures).record(
-                        mode,
-                        toolchain_id,
-                        project,
-                        None,
-                        None,
-                        test_failures as u64,
-                    )?;
-                }
-
-                if contract.is_none() && function.is_none() {
-                    continue;
-                }
-                if is_deployer {
-                    if test.non_zero_gas_values > 0 {
-                        xlsx.sheet(Sheet::DeployFee).record(
-                            mode,
-                            toolchain_id,
-                            project,
-                            contract,
-                            None,
-                            run.average_gas(),
-                        )?;
-                    }
-                } else {
-                    xlsx.sheet(Sheet::RuntimeFee).record(
-                        mode,
-                        toolchain_id,
-                        project,
-                        contract,
-                        function,
-                        run.average_gas(),
-                    )?;
-                }
-                if !run.size.is_empty() {
-                    xlsx.sheet(Sheet::DeploySize).record(
-                        mode,
-                        toolchain_id,
-                        project,
-                        contract,
-                        None,
-                        run.average_size(),
-                    )?;
-                }
-                if !run.runtime_size.is_empty() {
-                    xlsx.sheet(Sheet::RuntimeSize).record(
-                        mode,
-                        toolchain_id,
-                        project,
-                        contract,
-                        None,
-                        run.average_runtime_size(),
+                        measurement.project,
+                        measurement.contract,
+                        measurement.function,
+                        measurement.value,
+                        measurement.samples,
+                        measurement.noise,
                     )?;
                 }
             }
@@ -228,6 +143,7 @@ impl TryFrom<(Benchmark, Vec<Comparison>)> for Xlsx {
             })
             .collect();
 
+        let comparison_count = comparison_mapping.len();
         for (index, (left_id, left_name, right_id, right_name)) in
             comparison_mapping.into_iter().enumerate()
         {
@@ -240,6 +156,15 @@ impl TryFrom<(Benchmark, Vec<Comparison>)> for Xlsx {
                     toolchain_count as u16,
                     index as u16,
                 )?;
+                worksheet.set_p_values(
+                    left_id,
+                    left_name.as_str(),
+                    right_id,
+                    right_name.as_str(),
+                    toolchain_count as u16,
+                    comparison_count as u16,
+                    index as u16,
+                )?;
             }
         }
 
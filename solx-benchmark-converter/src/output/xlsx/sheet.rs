@@ -2,6 +2,9 @@
 //! A worksheet of the benchmark workbook.
 //!
 
+use crate::output::summary::failure_kind::FailureKind;
+use crate::statistics::noise::Noise;
+
 ///
 /// A worksheet of the benchmark workbook, and the only enumeration of them:
 /// the variants are the workbook's sheet order, and creation, totals, diffs,
@@ -58,4 +61,41 @@ impl Sheet {
             Self::TestingTime => ("Testing Time", vec![project]),
         }
     }
+
+    /// The failure kind the sheet counts, if it counts failures.
+    pub fn failure_kind(self) -> Option<FailureKind> {
+        match self {
+            Self::BuildFailures => Some(FailureKind::Build),
+            Self::TestFailures => Some(FailureKind::Test),
+            Self::RuntimeFee
+            | Self::DeployFee
+            | Self::RuntimeSize
+            | Self::DeploySize
+            | Self::CompilationTime
+            | Self::TestingTime => None,
+        }
+    }
+
+    /// How noisy the sheet's values are, given how noisy the gas is, or
+    /// `None` for failure counts.
+    pub fn noise(self, gas_noise: Noise) -> Option<Noise> {
+        match self {
+            Self::RuntimeFee | Self::DeployFee => Some(gas_noise),
+            Self::RuntimeSize | Self::DeploySize => Some(Noise::Deterministic),
+            Self::CompilationTime | Self::TestingTime => Some(Noise::Noisy),
+            Self::BuildFailures | Self::TestFailures => None,
+        }
+    }
+
+    /// Whether the sheet measures compiler output, gas or bytecode size,
+    /// rather than failures or wall-clock time.
+    pub fn is_output(self) -> bool {
+        match self {
+            Self::RuntimeFee | Self::DeployFee | Self::RuntimeSize | Self::DeploySize => true,
+            Self::BuildFailures
+            | Self::TestFailures
+            | Self::CompilationTime
+            | Self::TestingTime => false,
+        }
+    }
 }
@@ -7,8 +7,13 @@ use std::collections::HashMap;
 use rust_xlsxwriter::Format;
 use rust_xlsxwriter::FormatAlign;
 use rust_xlsxwriter::FormatBorder;
+use rust_xlsxwriter::Note;
 use rust_xlsxwriter::Worksheet as XlsxWorksheet;
 
+use crate::statistics::confidence_interval::ConfidenceInterval;
+use crate::statistics::mann_whitney::MannWhitney;
+use crate::statistics::noise::Noise;
+
 ///
 /// XLSX worksheet for benchmark data.
 ///
@@ -19,6 +24,8 @@ pub struct Worksheet {
     pub rows: HashMap<String, u32>,
     /// Header names and their column widths.
     pub headers: Vec<(&'static str, usize)>,
+    /// Samples behind each written noisy value, keyed by row and toolchain.
+    pub samples: HashMap<(u32, u16), Vec<u64>>,
 }
 
 impl Worksheet {
@@ -53,6 +60,7 @@ impl Worksheet {
             worksheet,
             rows: HashMap::new(),
             headers,
+            samples: HashMap::new(),
         })
     }
 
@@ -87,10 +95,15 @@ impl Worksheet {
     ///
     /// Adds a new row for a test and writes a value.
     ///
+    /// The value's confidence interval is attached as a cell note if it was
+    /// averaged from several `samples`, which are kept for the p-values only
+    /// if the value is noisy.
+    ///
     /// # Errors
     ///
-    /// Returns an error if writing a row header or value cell fails.
+    /// Returns an error if writing a row header, value cell, or note fails.
     ///
+    #[allow(clippy::too_many_arguments)]
     pub fn write_test_value(
         &mut self,
         project: &str,
@@ -98,6 +111,8 @@ impl Worksheet {
         function: Option<&str>,
         toolchain_id: u16,
         value: u64,
+        samples: &[u64],
+        noise: Option<Noise>,
     ) -> anyhow::Result<()> {
         let row_identifier = format!("{project}/{contract:?}.{function:?}");
         let row_index = if let Some(index) = self.rows.get(row_identifier.as_str()) {
@@ -132,23 +147,33 @@ impl Worksheet {
             row_index
         };
 
-        self.worksheet.write_with_format(
-            row_index,
-            (self.headers.len() as u16) + toolchain_id,
-            value,
-            &Self::value_format(),
-        )?;
+        let column_index = (self.headers.len() as u16) + toolchain_id;
+        self.worksheet
+            .write_with_format(row_index, column_index, value, &Self::value_format())?;
+        if let Some(interval) = ConfidenceInterval::from_samples(samples) {
+            self.worksheet.insert_note(
+                row_index,
+                column_index,
+                &Note::new(interval.to_string()),
+            )?;
+        }
+        if noise == Some(Noise::Noisy) {
+            self.samples
+                .insert((row_index, toolchain_id), samples.to_vec());
+        }
         Ok(())
     }
 
     ///
     /// Writes one measurement cell, allocating the toolchain column on
-    /// demand.
+    /// demand. The `samples` are the measurements the value was averaged
+    /// from, or empty for a count, and `noise` is how noisy they are.
     ///
     /// # Errors
     ///
     /// Returns an error if allocating the toolchain column or writing the value fails.
     ///
+    #[allow(clippy::too_many_arguments)]
     pub fn record(
         &mut self,
         toolchain_name: &str,
@@ -157,9 +182,19 @@ impl Worksheet {
         contract: Option<&str>,
         function: Option<&str>,
         value: u64,
+        samples: &[u64],
+        noise: Option<Noise>,
     ) -> anyhow::Result<()> {
         self.add_toolchain_column(toolchain_name, toolchain_id)?;
-        self.write_test_value(project, contract, function, toolchain_id, value)
+        self.write_test_value(
+            project,
+            contract,
+            function,
+            toolchain_id,
+            value,
+            samples,
+            noise,
+        )
     }
 
     ///
@@ -292,6 +327,73 @@ impl Worksheet {
         Ok(())
     }
 
+    ///
+    /// Sets the Mann–Whitney p-values of two data columns, placed after all
+    /// diff columns.
+    ///
+    /// A row gets a p-value only if its values are noisy and at least one
+    /// side was averaged from several samples: a deterministic difference is
+    /// real whatever its p-value, and a single measurement against another
+    /// carries no information about noise.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing the header, column width, or a value cell fails.
+    ///
+    #[allow(clippy::too_many_arguments)]
+    pub fn set_p_values(
+        &mut self,
+        toolchain_id_1: u16,
+        toolchain_name_1: &str,
+        toolchain_id_2: u16,
+        toolchain_name_2: &str,
+        total_toolchains: u16,
+        total_diffs: u16,
+        p_value_index: u16,
+    ) -> anyhow::Result<()> {
+        if self.rows.is_empty() {
+            return Ok(());
+        }
+        let column_identifier = format!(
+            "p-value\n{}\n------- vs -------\n{}",
+            toolchain_name_1.replace('-', "\n"),
+            toolchain_name_2.replace('-', "\n")
+        );
+        let column_index =
+            (self.headers.len() as u16) + total_toolchains + total_diffs + p_value_index;
+        self.worksheet.write_with_format(
+            0,
+            column_index,
+            column_identifier,
+            &Self::column_comparison_header_format(),
+        )?;
+        self.worksheet
+            .set_column_width(column_index, Self::COMPARISON_COLUMN_WIDTH as f64)?;
+
+        for row_index in self.rows.values() {
+            let (Some(samples_1), Some(samples_2)) = (
+                self.samples.get(&(*row_index, toolchain_id_1)),
+                self.samples.get(&(*row_index, toolchain_id_2)),
+            ) else {
+                continue;
+            };
+            if samples_1.len() < 2 && samples_2.len() < 2 {
+                continue;
+            }
+            let Some(p_value) = MannWhitney::p_value(samples_1, samples_2) else {
+                continue;
+            };
+            self.worksheet.write_with_format(
+                *row_index,
+                column_index,
+                p_value,
+                &Self::p_value_format(),
+            )?;
+        }
+
+        Ok(())
+    }
+
     ///
     /// Finalizes the worksheet and returns its inner object.
     ///
@@ -381,4 +483,11 @@ impl Worksheet {
     fn percent_format() -> Format {
         Self::value_format().set_num_format("0.000%")
     }
+
+    ///
+    /// Returns the eponymous cell format.
+    ///
+    fn p_value_format() -> Format {
+        Self::value_format().set_num_format("0.0000")
+    }
 }
@@ -18,3 +18,15 @@ pub enum Role {
     /// Unrecognized naming, surfaced as a harness error, never dropped.
     Other,
 }
+
+impl std::fmt::Display for Role {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pr => write!(f, "PR"),
+            Self::Main => write!(f, "main"),
+            Self::Latest => write!(f, "released solx"),
+            Self::Solc => write!(f, "solc"),
+            Self::Other => write!(f, "unrecognized"),
+        }
+    }
+}
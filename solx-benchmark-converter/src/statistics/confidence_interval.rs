@@ -0,0 +1,88 @@
+//!
+//! The confidence interval of a run's average.
+//!
+
+///
+/// The 95% confidence interval of the mean of a series of samples, from the
+/// Student's t-distribution.
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConfidenceInterval {
+    /// The sample mean.
+    pub mean: f64,
+    /// The lower bound.
+    pub low: f64,
+    /// The upper bound.
+    pub high: f64,
+    /// The number of samples.
+    pub samples: usize,
+}
+
+impl ConfidenceInterval {
+    /// Two-sided 95% quantiles of the t-distribution for 1 to 30 degrees of
+    /// freedom.
+    const T_QUANTILES: [f64; 30] = [
+        12.706, 4.303, 3.182, 2.776, 2.571, 2.447, 2.365, 2.306, 2.262, 2.228, 2.201, 2.179, 2.160,
+        2.145, 2.131, 2.120, 2.110, 2.101, 2.093, 2.086, 2.080, 2.074, 2.069, 2.064, 2.060, 2.056,
+        2.052, 2.048, 2.045, 2.042,
+    ];
+
+    ///
+    /// Computes the interval, or `None` for fewer than two samples, which
+    /// carry no information about the spread.
+    ///
+    pub fn from_samples(samples: &[u64]) -> Option<Self> {
+        if samples.len() < 2 {
+            return None;
+        }
+        let count = samples.len() as f64;
+        let mean = samples.iter().map(|sample| *sample as f64).sum::<f64>() / count;
+        let variance = samples
+            .iter()
+            .map(|sample| (*sample as f64 - mean).powi(2))
+            .sum::<f64>()
+            / (count - 1.0);
+        let half_width = Self::t_quantile(samples.len() - 1) * (variance / count).sqrt();
+        Some(Self {
+            mean,
+            low: mean - half_width,
+            high: mean + half_width,
+            samples: samples.len(),
+        })
+    }
+
+    ///
+    /// The distance from the mean to either bound.
+    ///
+    pub fn half_width(&self) -> f64 {
+        self.high - self.mean
+    }
+
+    ///
+    /// The two-sided 95% t quantile. Degrees of freedom past the table are
+    /// rounded down to the nearest tabulated value, which widens the interval
+    /// slightly rather than understating the noise.
+    ///
+    fn t_quantile(degrees_of_freedom: usize) -> f64 {
+        match degrees_of_freedom {
+            0 => f64::INFINITY,
+            1..=30 => Self::T_QUANTILES[degrees_of_freedom - 1],
+            31..=40 => 2.042,
+            41..=60 => 2.021,
+            61..=120 => 2.000,
+            _ => 1.980,
+        }
+    }
+}
+
+impl std::fmt::Display for ConfidenceInterval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:.1} ± {:.1} (95% CI, n = {})",
+            self.mean,
+            self.half_width(),
+            self.samples
+        )
+    }
+}
@@ -0,0 +1,131 @@
+//!
+//! The Mann–Whitney U test.
+//!
+
+///
+/// The two-sided Mann–Whitney U test: whether the samples of one run tend to
+/// be larger or smaller than those of another. It compares ranks only, so a
+/// single outlier, such as a fuzz case that hit a cold path, cannot make a
+/// change look significant on its own.
+///
+pub struct MannWhitney;
+
+impl MannWhitney {
+    /// The largest product of the sample counts the exact distribution is
+    /// enumerated for. Beyond it, the normal approximation is accurate.
+    const EXACT_LIMIT: usize = 400;
+
+    ///
+    /// The two-sided p-value of the samples coming from the same
+    /// distribution, or `None` if either side has no samples.
+    ///
+    /// Small samples without ties use the exact distribution of U. Otherwise,
+    /// the normal approximation is used with the tie and continuity
+    /// corrections.
+    ///
+    pub fn p_value(left: &[u64], right: &[u64]) -> Option<f64> {
+        if left.is_empty() || right.is_empty() {
+            return None;
+        }
+        let (left_count, right_count) = (left.len(), right.len());
+        let product = (left_count * right_count) as f64;
+
+        let mut pooled: Vec<(u64, bool)> = left
+            .iter()
+            .map(|value| (*value, true))
+            .chain(right.iter().map(|value| (*value, false)))
+            .collect();
+        pooled.sort_unstable_by_key(|(value, _)| *value);
+
+        let mut left_rank_sum = 0.0;
+        let mut tie_term = 0.0;
+        let mut start = 0;
+        while start < pooled.len() {
+            let end = start
+                + pooled[start..]
+                    .iter()
+                    .take_while(|(value, _)| *value == pooled[start].0)
+                    .count();
+            let rank = (start + end + 1) as f64 / 2.0;
+            let left_in_group = pooled[start..end]
+                .iter()
+                .filter(|(_, is_left)| *is_left)
+                .count();
+            left_rank_sum += rank * left_in_group as f64;
+            let group = (end - start) as f64;
+            tie_term += group.powi(3) - group;
+            start = end;
+        }
+
+        let left_u = left_rank_sum - (left_count * (left_count + 1)) as f64 / 2.0;
+        let u = left_u.min(product - left_u);
+
+        if tie_term == 0.0 && left_count * right_count <= Self::EXACT_LIMIT {
+            return Some(Self::exact_p_value(left_count, right_count, u as usize));
+        }
+
+        let total = (left_count + right_count) as f64;
+        let variance = product / 12.0 * ((total + 1.0) - tie_term / (total * (total - 1.0)));
+        if variance <= 0.0 {
+            return Some(1.0);
+        }
+        let z = ((left_u - product / 2.0).abs() - 0.5).max(0.0) / variance.sqrt();
+        Some(Self::erfc(z / std::f64::consts::SQRT_2).min(1.0))
+    }
+
+    ///
+    /// The exact two-sided p-value of the smaller U statistic `u`, counting
+    /// the rank arrangements that produce each U value.
+    ///
+    fn exact_p_value(left_count: usize, right_count: usize, u: usize) -> f64 {
+        let max_u = left_count * right_count;
+        // `counts[i][j][u]` is the number of arrangements of `i` left and `j`
+        // right samples whose U equals `u`.
+        let mut counts = vec![vec![vec![0.0f64; max_u + 1]; right_count + 1]; left_count + 1];
+        for (i, row) in counts.iter_mut().enumerate() {
+            row[0][0] = 1.0;
+            if i == 0 {
+                for cell in row.iter_mut() {
+                    cell[0] = 1.0;
+                }
+            }
+        }
+        for i in 1..=left_count {
+            for j in 1..=right_count {
+                for value in 0..=i * j {
+                    let with_left_last = if value >= j {
+                        counts[i - 1][j][value - j]
+                    } else {
+                        0.0
+                    };
+                    counts[i][j][value] = with_left_last + counts[i][j - 1][value];
+                }
+            }
+        }
+
+        let distribution = &counts[left_count][right_count];
+        let total: f64 = distribution.iter().sum();
+        let tail: f64 = distribution[..=u].iter().sum();
+        (2.0 * tail / total).min(1.0)
+    }
+
+    ///
+    /// The complementary error function, with a fractional error below
+    /// 1.2e-7 (Numerical Recipes, `erfcc`).
+    ///
+    fn erfc(x: f64) -> f64 {
+        let z = x.abs();
+        let t = 1.0 / (1.0 + 0.5 * z);
+        let polynomial = -z * z - 1.265_512_23
+            + t * (1.000_023_68
+                + t * (0.374_091_96
+                    + t * (0.096_784_18
+                        + t * (-0.186_288_06
+                            + t * (0.278_868_07
+                                + t * (-1.135_203_98
+                                    + t * (1.488_515_87
+                                        + t * (-0.822_152_23 + t * 0.170_872_77))))))));
+        let result = t * polynomial.exp();
+        if x >= 0.0 { result } else { 2.0 - result }
+    }
+}
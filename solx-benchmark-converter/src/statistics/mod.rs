@@ -0,0 +1,13 @@
+//!
+//! Noise modeling for benchmark measurements.
+//!
+//! A run keeps every sample of its measurements, but the reports used to
+//! compare only their averages, so a fuzzed gas figure or a wall-clock time
+//! that moved by chance read the same as a real change. The confidence
+//! intervals describe how far a run's average can be trusted, and the
+//! Mann–Whitney U test decides whether two runs differ beyond their noise.
+//!
+
+pub mod confidence_interval;
+pub mod mann_whitney;
+pub mod noise;
@@ -0,0 +1,50 @@
+//!
+//! How noisy a measured metric is.
+//!
+
+use crate::statistics::mann_whitney::MannWhitney;
+
+///
+/// How noisy a measured metric is, which decides what counts as a real
+/// change between two runs.
+///
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Noise {
+    /// Repeated measurements are identical, so any difference between the
+    /// averages is real: bytecode size, and gas on the tester's fixed inputs.
+    Deterministic,
+    /// Measurements vary between samples, so a difference must pass the
+    /// significance test: fuzzed Foundry and Hardhat gas, and wall-clock
+    /// times.
+    #[default]
+    Noisy,
+}
+
+impl Noise {
+    /// The p-value below which a difference is significant.
+    pub const SIGNIFICANCE_LEVEL: f64 = 0.05;
+
+    ///
+    /// Whether the PR samples differ from the `main` ones beyond noise.
+    ///
+    /// A measurement against its absence is always a change. A deterministic
+    /// metric differs exactly when its averages do, as its samples may only
+    /// vary by the inputs being measured, never by noise. A noisy metric needs
+    /// a Mann–Whitney p-value below the significance level, which a single
+    /// sample per side can never reach.
+    ///
+    pub fn is_significant(self, pr: &[u64], main: &[u64]) -> bool {
+        if pr.is_empty() || main.is_empty() {
+            return pr.is_empty() != main.is_empty();
+        }
+        match self {
+            Self::Deterministic => {
+                let sum =
+                    |samples: &[u64]| samples.iter().map(|&sample| sample as u128).sum::<u128>();
+                sum(pr) * main.len() as u128 != sum(main) * pr.len() as u128
+            }
+            Self::Noisy => MannWhitney::p_value(pr, main)
+                .is_some_and(|p_value| p_value < Self::SIGNIFICANCE_LEVEL),
+        }
+    }
+}
@@ -0,0 +1,182 @@
+//!
+//! Tests for the historical benchmark store and the trend report.
+//!
+
+use tempfile::TempDir;
+
+use crate::benchmark::Benchmark;
+use crate::history::History;
+use crate::history::entry::Entry;
+use crate::history::metric::Metric;
+use crate::history::trend::Trend;
+
+#[test]
+fn history_loads_entries_chronologically() {
+    let dir = TempDir::new().expect("scratch directory");
+    let history = History::new(dir.path().join("store"));
+    assert!(history.load().expect("empty history").is_empty());
+
+    for (commit, date) in [
+        ("bbbbbbbbbb", "2026-10-02T08:00:00Z"),
+        ("cccccccccc", "2026-10-01T12:00:00Z"),
+        ("aaaaaaaaaa", "2026-10-01T09:00:00Z"),
+    ] {
+        history
+            .insert(&Entry::measured(commit, date, 1_000, 100))
+            .expect("insertion");
+    }
+    let commits: Vec<String> = history
+        .load()
+        .expect("loading")
+        .into_iter()
+        .map(|entry| entry.commit)
+        .collect();
+    assert_eq!(commits, ["aaaaaaaaaa", "cccccccccc", "bbbbbbbbbb"]);
+}
+
+#[test]
+fn history_is_append_only() {
+    let dir = TempDir::new().expect("scratch directory");
+    let history = History::new(dir.path().to_path_buf());
+    let entry = Entry::measured("abcdef0123", "2026-10-01T09:00:00Z", 1_000, 100);
+    let path = history.insert(&entry).expect("insertion");
+    assert!(path.ends_with("2026-10-01-abcdef0123.json"), "{path:?}");
+
+    let error = history
+        .insert(&Entry::measured(
+            "abcdef0123",
+            "2026-10-01T09:00:00Z",
+            2_000,
+            200,
+        ))
+        .expect_err("duplicate insertion");
+    assert!(error.to_string().contains("already recorded"), "{error}");
+    let entries = history.load().expect("loading");
+    let test = entries[0].benchmark.tests.values().next().expect("test");
+    assert_eq!(test.runs["03.solx-legacy"].size, [1_000]);
+}
+
+#[test]
+fn entries_reject_malformed_commits_and_dates() {
+    for (commit, date) in [
+        ("../escape", "2026-10-01T09:00:00Z"),
+        ("", "2026-10-01T09:00:00Z"),
+        ("abcdef0123", "yesterday"),
+        ("abcdef0123", "01.10.2026"),
+    ] {
+        assert!(
+            Entry::new(commit.to_owned(), date.to_owned(), Benchmark::default()).is_err(),
+            "{commit} {date}"
+        );
+    }
+}
+
+#[test]
+fn many_small_regressions_are_a_drift() {
+    // Gas grows 0.5% per commit: 3% over the window, never 1% at once.
+    let entries: Vec<Entry> = (0..7)
+        .map(|index| {
+            Entry::measured(
+                format!("{index:x}abcdef012").as_str(),
+                format!("2026-10-{:02}T09:00:00Z", index + 1).as_str(),
+                1_000,
+                10_000 + index * 50,
+            )
+        })
+        .collect();
+    let trend = Trend::new(entries.as_slice(), Trend::DEFAULT_WINDOW, 1.0);
+    assert_eq!(trend.drifts.len(), 1);
+    let drift = &trend.drifts[0];
+    assert_eq!(drift.metric, Metric::Gas);
+    assert_eq!((drift.first, drift.last), (10_000, 10_300));
+    assert!(drift.largest_step_percent < 1.0);
+    assert_eq!(drift.sparkline, "▁▂▃▄▅▆█");
+
+    let markdown = trend.markdown();
+    assert!(markdown.contains("Last 7 commits"), "{markdown}");
+    assert!(
+        markdown.contains("⚠️ 1 series drifted by +1.0% or more"),
+        "{markdown}"
+    );
+    assert!(
+        markdown.contains("| Gas | 10,000 | 10,300 | +3.0% | +0.5% |"),
+        "{markdown}"
+    );
+    trend.xlsx().expect("spreadsheet");
+}
+
+#[test]
+fn step_regressions_and_improvements_are_not_drifts() {
+    // Gas jumps 3% in one commit, the PR comparison's to catch; size shrinks.
+    let entries: Vec<Entry> = [(1_200, 10_000), (1_100, 10_000), (1_000, 10_300)]
+        .into_iter()
+        .enumerate()
+        .map(|(index, (size, gas))| {
+            Entry::measured(
+                format!("{index:x}abcdef012").as_str(),
+                format!("2026-10-{:02}T09:00:00Z", index + 1).as_str(),
+                size,
+                gas,
+            )
+        })
+        .collect();
+    let trend = Trend::new(entries.as_slice(), Trend::DEFAULT_WINDOW, 1.0);
+    assert!(trend.drifts.is_empty());
+    assert!(trend.markdown().contains("✅ No slow drift"));
+}
+
+#[test]
+fn step_regressions_are_left_out_of_a_drift() {
+    // Gas grows 0.5% per commit for 29 commits, and 1.1% at once in another
+    // one: the step is the PR comparison's, the rest still adds up.
+    let mut gas = 100_000;
+    let entries: Vec<Entry> = (0..31)
+        .map(|index| {
+            if index > 0 {
+                gas += gas * if index == 15 { 11 } else { 5 } / 1_000;
+            }
+            Entry::measured(
+                format!("{index:02x}abcdef01").as_str(),
+                format!("2026-10-{:02}T09:00:00Z", index + 1).as_str(),
+                1_000,
+                gas,
+            )
+        })
+        .collect();
+    let trend = Trend::new(entries.as_slice(), 31, 1.0);
+    assert_eq!(trend.drifts.len(), 1);
+    let drift = &trend.drifts[0];
+    assert_eq!(drift.metric, Metric::Gas);
+    assert_eq!(drift.step_regressions, 1);
+    assert!(drift.largest_step_percent < 1.0);
+    assert!(
+        (15.0..16.0).contains(&drift.change_percent),
+        "{}",
+        drift.change_percent
+    );
+
+    let markdown = trend.markdown();
+    assert!(markdown.contains("| +0.5% | 1 |"), "{markdown}");
+}
+
+#[test]
+fn trend_covers_the_latest_window_only() {
+    // The drift happened before the window, which is flat.
+    let entries: Vec<Entry> = [10_000, 10_050, 10_100, 10_100, 10_100]
+        .into_iter()
+        .enumerate()
+        .map(|(index, gas)| {
+            Entry::measured(
+                format!("{index:x}abcdef012").as_str(),
+                format!("2026-10-{:02}T09:00:00Z", index + 1).as_str(),
+                1_000,
+                gas,
+            )
+        })
+        .collect();
+    let trend = Trend::new(entries.as_slice(), 3, 0.8);
+    assert_eq!(trend.commits.len(), 3);
+    assert_eq!(trend.commits[0].0, "2abcdef");
+    assert!(trend.drifts.is_empty());
+    assert!(!Trend::new(entries.as_slice(), 5, 0.8).drifts.is_empty());
+}
@@ -0,0 +1,154 @@
+//!
+//! Tests for the self-contained HTML report.
+//!
+
+use crate::benchmark::Benchmark;
+use crate::benchmark::run_failures::RunFailures;
+use crate::benchmark::test::Test;
+use crate::output::html::Html;
+use crate::statistics::noise::Noise;
+use crate::toolchain_matrix::ToolchainMatrix;
+
+/// A project benchmark with a size regression, a gas improvement, and a new
+/// test failure on the PR, and a contract name that needs escaping.
+fn rendered() -> String {
+    let mut benchmark = Benchmark::default();
+    for (name, test) in [
+        Test::contract(
+            "p",
+            "<script>alert(1)</script>",
+            &[
+                ("02.solx-main-legacy", 1_000, 500),
+                ("03.solx-legacy", 1_100, 450),
+            ],
+        ),
+        Test::failure(
+            "p",
+            &[
+                ("02.solx-main-legacy", RunFailures::Test(1)),
+                ("03.solx-legacy", RunFailures::Test(3)),
+            ],
+        ),
+    ] {
+        benchmark.tests.insert(name, test);
+    }
+    let comparisons = ToolchainMatrix::Project.comparisons(&benchmark.toolchains());
+    Html::from((benchmark, comparisons)).content
+}
+
+#[test]
+fn html_renders_every_section() {
+    let html = rendered();
+    for heading in [
+        "Toolchain matrix",
+        "Comparison statistics",
+        "Failure regressions",
+        "Top movers",
+        "Runtime Gas",
+        "Deploy Size",
+        "Test Failures",
+    ] {
+        assert!(
+            html.contains(format!("<h2>{heading}</h2>").as_str()),
+            "{heading}"
+        );
+    }
+    assert!(html.contains("<td class=\"\">PR</td>"), "{html}");
+    assert!(html.contains("<td class=\"\">main</td>"), "{html}");
+    assert!(html.contains("+10.0%"), "{html}");
+    assert!(html.contains("-10.0%"), "{html}");
+    assert!(html.contains("<svg"), "{html}");
+}
+
+#[test]
+fn html_escapes_benchmark_names() {
+    let html = rendered();
+    assert!(html.contains("&lt;script&gt;alert(1)"), "{html}");
+    assert!(!html.contains("<script>alert"), "{html}");
+}
+
+#[test]
+fn html_loads_no_external_assets() {
+    let html = rendered();
+    assert_eq!(html.matches("<script").count(), 1, "{html}");
+    for reference in ["<link", " src=", "@import", "url("] {
+        assert!(!html.contains(reference), "{reference}");
+    }
+}
+
+#[test]
+fn html_without_comparisons_lists_the_sheets_only() {
+    let (name, test) = Test::contract("p", "C", &[("03.solx-legacy", 1_000, 500)]);
+    let mut benchmark = Benchmark::default();
+    benchmark.tests.insert(name, test);
+    let html = Html::from((benchmark, vec![])).content;
+    assert!(html.contains("<h2>Deploy Size</h2>"), "{html}");
+    assert!(!html.contains("<h2>Top movers</h2>"), "{html}");
+    assert!(!html.contains("<svg"), "{html}");
+}
+
+#[test]
+fn html_shows_confidence_intervals_and_p_values() {
+    let (name, mut test) = Test::contract(
+        "p",
+        "C",
+        &[
+            ("02.solx-main-legacy", 1_000, 1),
+            ("03.solx-legacy", 1_000, 11),
+        ],
+    );
+    for (mode, samples) in [
+        ("02.solx-main-legacy", [2, 3, 4, 5]),
+        ("03.solx-legacy", [12, 13, 14, 15]),
+    ] {
+        test.runs.get_mut(mode).expect("run").gas.extend(samples);
+    }
+    let mut benchmark = Benchmark::default();
+    benchmark.tests.insert(name, test);
+    let comparisons = ToolchainMatrix::Project.comparisons(&benchmark.toolchains());
+    let html = Html::from((benchmark, comparisons)).content;
+
+    assert!(html.contains("<th>p-value "), "{html}");
+    assert!(html.contains(">0.0079</td>"), "{html}");
+    assert!(
+        html.contains("title=\"13.0 ± 2.0 (95% CI, n = 5)\""),
+        "{html}"
+    );
+    // The single-sample sizes have no p-value, and the blank cell sorts below
+    // every number.
+    assert!(html.contains("data-sort=\"-Infinity\""), "{html}");
+    assert!(!html.contains(f64::MIN.to_string().as_str()), "{html}");
+}
+
+#[test]
+fn html_shows_no_p_values_for_deterministic_metrics() {
+    let (name, mut test) = Test::contract(
+        "p",
+        "C",
+        &[
+            ("02.solx-main-legacy", 1_000, 1),
+            ("03.solx-legacy", 1_100, 11),
+        ],
+    );
+    test.gas_noise = Noise::Deterministic;
+    for (mode, samples) in [
+        ("02.solx-main-legacy", [2, 3, 4, 5]),
+        ("03.solx-legacy", [12, 13, 14, 15]),
+    ] {
+        let run = test.runs.get_mut(mode).expect("run");
+        run.gas.extend(samples);
+        run.size.extend(samples);
+    }
+    let mut benchmark = Benchmark::default();
+    benchmark.tests.insert(name, test);
+    let comparisons = ToolchainMatrix::Project.comparisons(&benchmark.toolchains());
+    let html = Html::from((benchmark, comparisons)).content;
+
+    // The samples still show their confidence intervals, but a difference of
+    // deterministic gas or size is real whatever its p-value.
+    assert!(
+        html.contains("title=\"13.0 ± 2.0 (95% CI, n = 5)\""),
+        "{html}"
+    );
+    assert!(!html.contains(">0.0079</td>"), "{html}");
+}
@@ -5,8 +5,11 @@
 //! the submodules below carry test functions only.
 //!
 
+mod history;
+mod html;
 mod io;
 mod listings;
+mod statistics;
 mod summary;
 mod toolchain_matrix;
 mod utils;
@@ -19,6 +22,7 @@ use crate::benchmark::test::input::Input as TestInput;
 use crate::benchmark::test::metadata::Metadata;
 use crate::benchmark::test::run::Run;
 use crate::benchmark::test::selector::Selector;
+use crate::history::entry::Entry;
 use crate::output::summary::Summary;
 use crate::output::summary::diff_counter::DiffCounter;
 use crate::output::summary::suite_stats::SuiteStats;
@@ -115,6 +119,16 @@ impl Test {
     }
 }
 
+impl Entry {
+    /// One commit's benchmark of a single contract, measured in one mode.
+    fn measured(commit: &str, date: &str, size: u64, gas: u64) -> Self {
+        let (name, test) = Test::contract("p", "C", &[("03.solx-legacy", size, gas)]);
+        let mut benchmark = Benchmark::default();
+        benchmark.tests.insert(name, test);
+        Self::new(commit.to_owned(), date.to_owned(), benchmark).expect("valid entry")
+    }
+}
+
 impl SummarySuite {
     /// Merges the given tests by selector, like the real report ingestion
     /// does: a project's failure and compile-time entries share one key.
@@ -198,6 +212,7 @@ impl DiffCounter {
             cells,
             diffs,
             delta,
+            noisy: 0,
         }
     }
 }
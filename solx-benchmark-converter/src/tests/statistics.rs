@@ -0,0 +1,114 @@
+//!
+//! Tests for the noise modeling: confidence intervals, the Mann–Whitney U
+//! test, and the significance gating of the summary diff counts.
+//!
+
+use crate::benchmark::test::Test;
+use crate::benchmark::test::input::Input as TestInput;
+use crate::output::summary::Summary;
+use crate::output::summary::suite_stats::SuiteStats;
+use crate::statistics::confidence_interval::ConfidenceInterval;
+use crate::statistics::mann_whitney::MannWhitney;
+use crate::statistics::noise::Noise;
+use crate::suite_kind::SuiteKind;
+use crate::summary_suite::SummarySuite;
+
+#[test]
+fn mann_whitney_exact_distribution() {
+    // Complete separation of three samples each: 2 of the 20 rank
+    // arrangements are at least as extreme.
+    let p_value = MannWhitney::p_value(&[1, 2, 3], &[4, 5, 6]).expect("samples");
+    assert!((p_value - 0.1).abs() < 1e-9, "{p_value}");
+    let p_value = MannWhitney::p_value(&[4, 5, 6], &[1, 2, 3]).expect("samples");
+    assert!((p_value - 0.1).abs() < 1e-9, "{p_value}");
+}
+
+#[test]
+fn mann_whitney_identical_samples_are_not_significant() {
+    let p_value = MannWhitney::p_value(&[7, 7, 7], &[7, 7, 7]).expect("samples");
+    assert_eq!(p_value, 1.0);
+    assert_eq!(MannWhitney::p_value(&[], &[1]), None);
+}
+
+#[test]
+fn mann_whitney_normal_approximation_separates_shifted_samples() {
+    let main: Vec<u64> = (0..30).map(|index| 1_000 + index % 7).collect();
+    let pr: Vec<u64> = (0..30).map(|index| 1_100 + index % 7).collect();
+    let p_value = MannWhitney::p_value(&pr, &main).expect("samples");
+    assert!(p_value < 1e-6, "{p_value}");
+}
+
+#[test]
+fn confidence_interval_of_the_mean() {
+    assert_eq!(ConfidenceInterval::from_samples(&[5]), None);
+    let interval = ConfidenceInterval::from_samples(&[1, 2, 3, 4, 5]).expect("samples");
+    assert_eq!(interval.mean, 3.0);
+    // t(0.975, 4) * sqrt(2.5 / 5)
+    assert!((interval.half_width() - 1.963).abs() < 1e-3, "{interval}");
+    assert_eq!(interval.to_string(), "3.0 ± 2.0 (95% CI, n = 5)");
+}
+
+#[test]
+fn deterministic_metrics_count_every_difference() {
+    assert!(Noise::Deterministic.is_significant(&[101], &[100]));
+    assert!(!Noise::Deterministic.is_significant(&[100, 100], &[100]));
+    assert!(Noise::Deterministic.is_significant(&[100], &[]));
+    assert!(!Noise::Deterministic.is_significant(&[], &[]));
+    // Samples that vary are compared by their averages, never tested for noise.
+    assert!(Noise::Deterministic.is_significant(&[110, 130], &[100, 120]));
+    assert!(!Noise::Deterministic.is_significant(&[100, 140], &[110, 130]));
+}
+
+#[test]
+fn noisy_metrics_need_a_significant_p_value() {
+    assert!(!Noise::Noisy.is_significant(&[101], &[100]));
+    assert!(!Noise::Noisy.is_significant(&[1, 2, 3], &[4, 5, 6]));
+    assert!(Noise::Noisy.is_significant(&[1, 2, 3, 4, 5], &[11, 12, 13, 14, 15]));
+}
+
+#[test]
+fn overlapping_gated_gas_samples_are_still_flagged() {
+    let tests = [(100, 110), (120, 130)]
+        .into_iter()
+        .map(|(main_gas, pr_gas)| {
+            Test::input(
+                "c.sol:C",
+                TestInput::Runtime {
+                    input_index: 0,
+                    name: "f".to_owned(),
+                },
+                &[
+                    ("00.solx-main-solx-E-M3B3-0.8.34", main_gas),
+                    ("01.solx-solx-E-M3B3-0.8.34", pr_gas),
+                ],
+            )
+        })
+        .collect();
+    let suite = SummarySuite::merged(SuiteKind::Tester, tests);
+    let stats = SuiteStats::from_suite(&suite);
+    assert_eq!(stats.gas.cells, 1);
+    assert_eq!(stats.gas.diffs, 1);
+    assert_eq!(stats.gas.delta, 10);
+    assert!(!stats.top_gas_movers.is_empty());
+
+    let out = Summary::new(vec![suite]).render();
+    assert!(out.contains("⚠️ 1 of 1"), "{out}");
+}
+
+#[test]
+fn shifted_ungated_gas_samples_are_reported_significant() {
+    let tests = (0..5)
+        .map(|index| {
+            Test::contract(
+                "p",
+                "C",
+                &[
+                    ("02.solx-main-legacy", 1_000, 1_000 + index),
+                    ("03.solx-legacy", 1_000, 2_000 + index),
+                ],
+            )
+        })
+        .collect();
+    let out = Summary::new(vec![SummarySuite::merged(SuiteKind::Foundry, tests)]).render();
+    assert!(out.contains("; 1 significant (not gated)"), "{out}");
+}
@@ -81,6 +81,24 @@ impl ToolchainMatrix {
         comparisons
     }
 
+    ///
+    /// The matrix whose declared names recognize the most of the toolchain
+    /// columns, for reports that carry no suite kind. Ties go to the tester.
+    ///
+    pub fn detect(toolchains: &BTreeSet<String>) -> Self {
+        let recognized = |matrix: Self| {
+            toolchains
+                .iter()
+                .filter(|toolchain| matrix.classify(toolchain).0 != Role::Other)
+                .count()
+        };
+        if recognized(Self::Project) > recognized(Self::Tester) {
+            Self::Project
+        } else {
+            Self::Tester
+        }
+    }
+
     /// The compilation pipeline a mode belongs to, parsed from its `legacy`/
     /// `viaIR` flag or its `E`/`Y` codegen token. `None` for a mode carrying
     /// neither, surfaced as a harness error upstream, since a silent fallback
@@ -234,6 +234,12 @@ impl<'ctx> Context<'ctx> {
                 is_size_fallback,
                 spill_area_size,
             )?;
+            output_config.dump_cfg_llvm(
+                contract_path,
+                self.module(),
+                is_size_fallback,
+                spill_area_size,
+            )?;
         }
 
         // Capture optimized LLVM IR for output if requested and not writing to files
@@ -337,6 +343,7 @@ impl<'ctx> Context<'ctx> {
                         .store(true, std::sync::atomic::Ordering::Relaxed);
                     let mut size_fallback_settings = OptimizerSettings::size();
                     size_fallback_settings.metadata_size = self.optimizer.settings().metadata_size;
+                    size_fallback_settings.expected_runs = self.optimizer.settings().expected_runs;
                     self.optimizer = Optimizer::new(size_fallback_settings);
                     self.module = module_size_fallback
                         .expect("cloned when the settings enable the size fallback");
@@ -477,6 +484,20 @@ impl<'ctx> Context<'ctx> {
         &self.intrinsics
     }
 
+    ///
+    /// Returns the Solidity source code location of the code being translated, if known.
+    ///
+    pub fn diagnostic_location(&self) -> Option<solx_utils::DiagnosticLocation> {
+        let solidity = self.solidity_data.as_ref()?;
+        let solc_location = solidity.get_debug_info_solc_location()?;
+        let path = solidity.sources().get(&solc_location.source_id)?;
+        Some(solx_utils::DiagnosticLocation::new(
+            path.to_owned(),
+            solc_location.start,
+            solc_location.end,
+        ))
+    }
+
     ///
     /// Returns a Yul function type with the specified arguments and number of return values.
     ///
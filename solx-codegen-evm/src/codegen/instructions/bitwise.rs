@@ -249,6 +249,11 @@ pub fn clz<'ctx>(
     context: &mut Context<'ctx>,
     operand: inkwell::values::IntValue<'ctx>,
 ) -> anyhow::Result<inkwell::values::BasicValueEnum<'ctx>> {
+    context.evm_version().check_opcode(
+        solx_utils::EVMOpcode::Clz,
+        solx_utils::DiagnosticComponent::Codegen,
+    )?;
+
     Ok(context
         .build_call(
             context.intrinsics().ctlz,
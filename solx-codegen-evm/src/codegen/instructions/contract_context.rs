@@ -129,6 +129,11 @@ pub fn blob_hash<'ctx>(
     context: &mut Context<'ctx>,
     index: inkwell::values::IntValue<'ctx>,
 ) -> anyhow::Result<inkwell::values::BasicValueEnum<'ctx>> {
+    context.evm_version().check_opcode(
+        solx_utils::EVMOpcode::BlobHash,
+        solx_utils::DiagnosticComponent::Codegen,
+    )?;
+
     Ok(context
         .build_call(
             context.intrinsics().blobhash,
@@ -144,6 +149,11 @@ pub fn blob_hash<'ctx>(
 pub fn blob_basefee<'ctx>(
     context: &mut Context<'ctx>,
 ) -> anyhow::Result<inkwell::values::BasicValueEnum<'ctx>> {
+    context.evm_version().check_opcode(
+        solx_utils::EVMOpcode::BlobBaseFee,
+        solx_utils::DiagnosticComponent::Codegen,
+    )?;
+
     Ok(context
         .build_call(context.intrinsics().blobbasefee, &[], "blobbasefee")?
         .expect("Always exists"))
@@ -6,6 +6,7 @@ pub mod run;
 
 use std::cell::RefCell;
 use std::rc::Rc;
+use std::sync::OnceLock;
 
 use indexmap::IndexMap;
 
@@ -13,6 +14,9 @@ use crate::optimizer::settings::Settings as OptimizerSettings;
 
 use self::run::Run;
 
+/// The process-wide observer notified about every started pipeline stage.
+static STAGE_OBSERVER: OnceLock<fn(&str)> = OnceLock::new();
+
 ///
 /// Compiler pipeline profiler.
 ///
@@ -23,6 +27,16 @@ pub struct Profiler {
 }
 
 impl Profiler {
+    ///
+    /// Installs a process-wide observer notified with the description of every started stage.
+    ///
+    /// Used by worker subprocesses to report their progress to the parent process.
+    /// Only the first installed observer is kept.
+    ///
+    pub fn install_stage_observer(observer: fn(&str)) {
+        let _ = STAGE_OBSERVER.set(observer);
+    }
+
     ///
     /// Starts a new run for a generic part of the pipeline.
     ///
@@ -33,6 +47,7 @@ impl Profiler {
             "Translation unit run `{run_name}` already exists"
         );
 
+        Self::notify_stage_observer(description);
         self.start_run(run_name)
     }
 
@@ -58,6 +73,7 @@ impl Profiler {
             "Translation unit run `{run_name}` already exists"
         );
 
+        Self::notify_stage_observer(description);
         self.start_run(run_name)
     }
 
@@ -90,4 +106,13 @@ impl Profiler {
         self.timings.insert(name, run.clone());
         run
     }
+
+    ///
+    /// Notifies the stage observer, if installed.
+    ///
+    fn notify_stage_observer(description: &str) {
+        if let Some(observer) = STAGE_OBSERVER.get() {
+            observer(description);
+        }
+    }
 }
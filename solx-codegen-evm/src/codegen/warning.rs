@@ -31,15 +31,33 @@ pub enum Warning {
 }
 
 impl Warning {
+    ///
+    /// Returns the catalogued diagnostic code.
+    ///
+    pub fn diagnostic_code(&self) -> solx_utils::DiagnosticCode {
+        match self {
+            Self::DeployCodeSize { .. } => solx_utils::DiagnosticCode::DeployCodeSize,
+            Self::RuntimeCodeSize { .. } => solx_utils::DiagnosticCode::RuntimeCodeSize,
+        }
+    }
+
     ///
     /// Warning code.
     ///
     /// Mimic `solc` warning codes where possible for compatibility.
     ///
     pub fn code(&self) -> Option<isize> {
-        match self {
-            Self::DeployCodeSize { .. } => Some(3860),
-            Self::RuntimeCodeSize { .. } => Some(5574),
-        }
+        Some(self.diagnostic_code().code())
+    }
+
+    ///
+    /// Converts the warning into a diagnostic.
+    ///
+    pub fn to_diagnostic(&self) -> solx_utils::Diagnostic {
+        solx_utils::Diagnostic::new(
+            self.diagnostic_code(),
+            solx_utils::DiagnosticComponent::Codegen,
+            self,
+        )
     }
 }
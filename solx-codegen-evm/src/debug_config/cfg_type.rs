@@ -0,0 +1,48 @@
+//!
+//! The control-flow graph type.
+//!
+
+use std::str::FromStr;
+
+///
+/// The control-flow graph type.
+///
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize, serde::Deserialize,
+)]
+pub enum CFGType {
+    /// The Ethereal IR blocks, with a node per block instance and the stack state.
+    #[serde(rename = "ethir")]
+    EthIR,
+    /// The optimized LLVM IR basic blocks.
+    #[serde(rename = "llvm")]
+    LLVM,
+    /// The EVM legacy assembly blocks, with a node per block tag.
+    #[serde(rename = "asm")]
+    EVMLA,
+}
+
+impl FromStr for CFGType {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string {
+            "ethir" => Ok(Self::EthIR),
+            "llvm" => Ok(Self::LLVM),
+            "asm" => Ok(Self::EVMLA),
+            string => anyhow::bail!(
+                "unknown control-flow graph type: `{string}`, expected `ethir`, `llvm`, or `asm`"
+            ),
+        }
+    }
+}
+
+impl std::fmt::Display for CFGType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::EthIR => write!(f, "ethir"),
+            Self::LLVM => write!(f, "llvm"),
+            Self::EVMLA => write!(f, "asm"),
+        }
+    }
+}
@@ -0,0 +1,32 @@
+//!
+//! The Graphviz DOT graph edge.
+//!
+
+///
+/// The Graphviz DOT graph edge.
+///
+#[derive(Debug, Clone)]
+pub struct Edge {
+    /// The source node identifier.
+    pub from: String,
+    /// The destination node identifier.
+    pub to: String,
+    /// The optional label, e.g. the kind of the control transfer.
+    pub label: Option<String>,
+    /// Whether the edge is drawn dashed, e.g. for jumps resolved from the stack.
+    pub is_dashed: bool,
+}
+
+impl Edge {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(from: String, to: String, label: Option<String>, is_dashed: bool) -> Self {
+        Self {
+            from,
+            to,
+            label,
+            is_dashed,
+        }
+    }
+}
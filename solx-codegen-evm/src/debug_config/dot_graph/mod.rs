@@ -0,0 +1,299 @@
+//!
+//! The Graphviz DOT control-flow graph.
+//!
+
+pub mod edge;
+pub mod node;
+
+use self::edge::Edge;
+use self::node::Node;
+
+///
+/// The Graphviz DOT control-flow graph.
+///
+/// Represents a single function, with a node per basic block and an edge per control transfer.
+///
+#[derive(Debug, Clone)]
+pub struct DotGraph {
+    /// The function name.
+    pub name: String,
+    /// The basic blocks.
+    pub nodes: Vec<Node>,
+    /// The control transfers.
+    pub edges: Vec<Edge>,
+}
+
+impl DotGraph {
+    /// The LLVM IR prefix of a basic block reference.
+    const LLVM_LABEL_PREFIX: &'static str = "label %";
+
+    /// The name of the LLVM IR entry block, if it is unnamed.
+    const LLVM_ENTRY_BLOCK_NAME: &'static str = "entry";
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            nodes: Vec::new(),
+            edges: Vec::new(),
+        }
+    }
+
+    ///
+    /// Builds the graph from the textual LLVM IR of a function definition.
+    ///
+    /// The textual form is used, as it names the basic blocks exactly as the LLVM IR output does.
+    ///
+    pub fn from_llvm_ir(name: String, function_ir: &str) -> Self {
+        let mut graph = Self::new(name);
+
+        let mut block: Option<(String, Vec<String>)> = None;
+        for line in function_ir.lines() {
+            if line.trim().is_empty() || line.starts_with("define") || line.starts_with('}') {
+                continue;
+            }
+
+            if !line.starts_with(char::is_whitespace) {
+                if let Some(label) = line
+                    .split(';')
+                    .next()
+                    .and_then(|label| label.trim_end().strip_suffix(':'))
+                {
+                    if let Some((label, lines)) = block.take() {
+                        graph.push_llvm_block(label, lines);
+                    }
+                    block = Some((label.trim_matches('"').to_owned(), Vec::new()));
+                }
+                continue;
+            }
+
+            block
+                .get_or_insert_with(|| (Self::LLVM_ENTRY_BLOCK_NAME.to_owned(), Vec::new()))
+                .1
+                .push(line.trim().to_owned());
+        }
+        if let Some((label, lines)) = block.take() {
+            graph.push_llvm_block(label, lines);
+        }
+
+        graph
+    }
+
+    ///
+    /// Adds a node.
+    ///
+    pub fn add_node(&mut self, identifier: String, lines: Vec<String>) {
+        self.nodes.push(Node::new(identifier, lines));
+    }
+
+    ///
+    /// Adds an edge.
+    ///
+    pub fn add_edge(&mut self, from: String, to: String, label: Option<String>, is_dashed: bool) {
+        self.edges.push(Edge::new(from, to, label, is_dashed));
+    }
+
+    ///
+    /// Adds an LLVM IR basic block with edges to every block referenced by its terminator.
+    ///
+    fn push_llvm_block(&mut self, label: String, instructions: Vec<String>) {
+        for instruction in instructions.iter() {
+            let mut references = instruction.as_str();
+            while let Some(offset) = references.find(Self::LLVM_LABEL_PREFIX) {
+                references = &references[offset + Self::LLVM_LABEL_PREFIX.len()..];
+                let successor = match references.strip_prefix('"') {
+                    Some(quoted) => quoted.split('"').next().unwrap_or_default(),
+                    None => references
+                        .split(|character: char| {
+                            !(character.is_alphanumeric() || "._$-".contains(character))
+                        })
+                        .next()
+                        .unwrap_or_default(),
+                };
+                self.add_edge(label.clone(), successor.to_owned(), None, false);
+            }
+        }
+
+        let mut lines = Vec::with_capacity(1 + instructions.len());
+        lines.push(format!("{label}:"));
+        lines.extend(instructions);
+        self.add_node(label, lines);
+    }
+
+    ///
+    /// Escapes a string for a quoted DOT identifier or label.
+    ///
+    fn escape(string: &str) -> String {
+        string.replace('\\', "\\\\").replace('"', "\\\"")
+    }
+}
+
+impl std::fmt::Display for DotGraph {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "digraph \"{}\" {{", Self::escape(self.name.as_str()))?;
+        writeln!(f, "    node [shape=box, fontname=\"monospace\"];")?;
+        for node in self.nodes.iter() {
+            write!(
+                f,
+                "    \"{}\" [label=\"",
+                Self::escape(node.identifier.as_str())
+            )?;
+            for line in node.lines.iter() {
+                write!(f, "{}\\l", Self::escape(line.as_str()))?;
+            }
+            writeln!(f, "\"];")?;
+        }
+        for edge in self.edges.iter() {
+            write!(
+                f,
+                "    \"{}\" -> \"{}\"",
+                Self::escape(edge.from.as_str()),
+                Self::escape(edge.to.as_str())
+            )?;
+            let mut attributes = Vec::with_capacity(2);
+            if let Some(label) = edge.label.as_deref() {
+                attributes.push(format!("label=\"{}\"", Self::escape(label)));
+            }
+            if edge.is_dashed {
+                attributes.push("style=dashed".to_owned());
+            }
+            if !attributes.is_empty() {
+                write!(f, " [{}]", attributes.join(", "))?;
+            }
+            writeln!(f, ";")?;
+        }
+        writeln!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DotGraph;
+
+    /// Collects the edges as `(from, to)` pairs.
+    fn edges(graph: &DotGraph) -> Vec<(&str, &str)> {
+        graph
+            .edges
+            .iter()
+            .map(|edge| (edge.from.as_str(), edge.to.as_str()))
+            .collect()
+    }
+
+    /// The unnamed entry block must be named `entry`, and numbered blocks must keep their numbers.
+    #[test]
+    fn numbered_and_unnamed_blocks() {
+        let graph = DotGraph::from_llvm_ir(
+            "f".to_owned(),
+            r#"define void @f(i1 %0) {
+  br i1 %0, label %2, label %3
+
+2:                                                ; preds = %1
+  ret void
+
+3:                                                ; preds = %1
+  unreachable
+}"#,
+        );
+        let identifiers: Vec<&str> = graph
+            .nodes
+            .iter()
+            .map(|node| node.identifier.as_str())
+            .collect();
+        assert_eq!(identifiers, vec!["entry", "2", "3"]);
+        assert_eq!(
+            graph.nodes[0].lines,
+            vec!["entry:", "br i1 %0, label %2, label %3"]
+        );
+        assert_eq!(graph.nodes[1].lines, vec!["2:", "ret void"]);
+        assert_eq!(edges(&graph), vec![("entry", "2"), ("entry", "3")]);
+    }
+
+    /// Every case of a multi-line `switch` and both `br` targets must become edges.
+    #[test]
+    fn switch_and_branch_edges() {
+        let graph = DotGraph::from_llvm_ir(
+            "g".to_owned(),
+            r#"define void @g(i256 %value) {
+entry:
+  switch i256 %value, label %default [
+    i256 0, label %case.zero
+    i256 1, label %case.one
+  ]
+
+case.zero:
+  br label %join
+
+case.one:
+  %condition = icmp eq i256 %value, 1
+  br i1 %condition, label %join, label %default
+
+default:
+  br label %join
+
+join:
+  ret void
+}"#,
+        );
+        assert_eq!(graph.nodes.len(), 5);
+        assert_eq!(
+            edges(&graph),
+            vec![
+                ("entry", "default"),
+                ("entry", "case.zero"),
+                ("entry", "case.one"),
+                ("case.zero", "join"),
+                ("case.one", "join"),
+                ("case.one", "default"),
+                ("default", "join"),
+            ]
+        );
+    }
+
+    /// Quoted block names must be unquoted both in the definition and in the references.
+    #[test]
+    fn quoted_labels() {
+        let graph = DotGraph::from_llvm_ir(
+            "h".to_owned(),
+            r#"define void @h() {
+entry:
+  br label %"block with spaces"
+
+"block with spaces":                              ; preds = %entry
+  ret void
+}"#,
+        );
+        assert_eq!(graph.nodes[1].identifier, "block with spaces");
+        assert_eq!(edges(&graph), vec![("entry", "block with spaces")]);
+    }
+
+    /// Quotes and backslashes must be escaped in the graph name, node identifiers, and labels.
+    #[test]
+    fn dot_escaping() {
+        let mut graph = DotGraph::new(r#"f"\"#.to_owned());
+        graph.add_node(
+            r#"a"b"#.to_owned(),
+            vec![r#"a"b:"#.to_owned(), r#"call "\x""#.to_owned()],
+        );
+        graph.add_node("c".to_owned(), vec!["c:".to_owned()]);
+        graph.add_edge(
+            r#"a"b"#.to_owned(),
+            "c".to_owned(),
+            Some(r#"case "1""#.to_owned()),
+            true,
+        );
+        graph.add_edge("c".to_owned(), r#"a"b"#.to_owned(), None, false);
+        assert_eq!(
+            graph.to_string(),
+            r#"digraph "f\"\\" {
+    node [shape=box, fontname="monospace"];
+    "a\"b" [label="a\"b:\lcall \"\\x\"\l"];
+    "c" [label="c:\l"];
+    "a\"b" -> "c" [label="case \"1\"", style=dashed];
+    "c" -> "a\"b";
+}
+"#
+        );
+    }
+}
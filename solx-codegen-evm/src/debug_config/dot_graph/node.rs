@@ -0,0 +1,23 @@
+//!
+//! The Graphviz DOT graph node.
+//!
+
+///
+/// The Graphviz DOT graph node.
+///
+#[derive(Debug, Clone)]
+pub struct Node {
+    /// The unique node identifier.
+    pub identifier: String,
+    /// The label lines, usually the block header followed by its instructions.
+    pub lines: Vec<String>,
+}
+
+impl Node {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(identifier: String, lines: Vec<String>) -> Self {
+        Self { identifier, lines }
+    }
+}
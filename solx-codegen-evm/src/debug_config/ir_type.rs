@@ -19,6 +19,8 @@ pub enum IRType {
     LLVM,
     /// Whether to dump the EVM assembly code.
     EVMAssembly,
+    /// Whether to dump the control-flow graph.
+    CFG,
 }
 
 impl IRType {
@@ -32,6 +34,7 @@ impl IRType {
             Self::EVMLA => solx_utils::EXTENSION_EVMLA,
             Self::LLVM => solx_utils::EXTENSION_LLVM_SOURCE,
             Self::EVMAssembly => solx_utils::EXTENSION_EVM_ASSEMBLY,
+            Self::CFG => solx_utils::EXTENSION_DOT,
         }
     }
 }
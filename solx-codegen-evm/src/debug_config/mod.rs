@@ -2,11 +2,16 @@
 //! The output configuration for IR artifacts.
 //!
 
+pub mod cfg_type;
+pub mod dot_graph;
 pub mod ir_type;
 
+use std::collections::BTreeSet;
 use std::path::Path;
 use std::path::PathBuf;
 
+use self::cfg_type::CFGType;
+use self::dot_graph::DotGraph;
 use self::ir_type::IRType;
 
 ///
@@ -36,6 +41,9 @@ pub struct OutputConfig {
     /// Whether to output LLVM assembly.
     #[serde(default)]
     pub output_assembly: bool,
+    /// The control-flow graphs to output.
+    #[serde(default)]
+    pub output_cfg: BTreeSet<CFGType>,
 }
 
 impl OutputConfig {
@@ -51,6 +59,7 @@ impl OutputConfig {
             output_ethir: true,
             output_llvm_ir: true,
             output_assembly: true,
+            output_cfg: BTreeSet::new(),
         }
     }
 
@@ -65,6 +74,7 @@ impl OutputConfig {
         output_ethir: bool,
         output_llvm_ir: bool,
         output_assembly: bool,
+        output_cfg: BTreeSet<CFGType>,
     ) -> Self {
         Self {
             output_directory,
@@ -74,6 +84,7 @@ impl OutputConfig {
             output_ethir,
             output_llvm_ir,
             output_assembly,
+            output_cfg,
         }
     }
 
@@ -86,6 +97,7 @@ impl OutputConfig {
             || self.output_ethir
             || self.output_llvm_ir
             || self.output_assembly
+            || !self.output_cfg.is_empty()
     }
 
     ///
@@ -103,6 +115,7 @@ impl OutputConfig {
             output_ethir: self.output_ethir,
             output_llvm_ir: self.output_llvm_ir,
             output_assembly: self.output_assembly,
+            output_cfg: self.output_cfg.clone(),
         })
     }
 
@@ -270,6 +283,72 @@ impl OutputConfig {
         Ok(())
     }
 
+    ///
+    /// Dumps the control-flow graphs of the specified type, one file per function.
+    ///
+    pub fn dump_cfg(
+        &self,
+        contract_path: &str,
+        cfg_type: CFGType,
+        graphs: Vec<DotGraph>,
+        is_size_fallback: bool,
+        spill_area_size: Option<u64>,
+    ) -> anyhow::Result<()> {
+        if !self.output_cfg.contains(&cfg_type) {
+            return Ok(());
+        }
+        for graph in graphs.into_iter() {
+            let mut suffix = format!(
+                "{cfg_type}.{}",
+                solx_utils::ContractName::sanitize_path(graph.name.as_str())
+            );
+            if let Some(extra) = Self::build_suffix(is_size_fallback, spill_area_size) {
+                suffix.push('.');
+                suffix.push_str(extra.as_str());
+            }
+
+            let mut file_path = self.output_directory.to_owned();
+            let full_file_name =
+                Self::full_file_name(contract_path, Some(suffix.as_str()), IRType::CFG);
+            file_path.push(full_file_name);
+            self.write_file(file_path.as_path(), graph.to_string())?;
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Dumps the control-flow graphs of the LLVM IR functions defined in the module.
+    ///
+    pub fn dump_cfg_llvm(
+        &self,
+        contract_path: &str,
+        module: &inkwell::module::Module,
+        is_size_fallback: bool,
+        spill_area_size: Option<u64>,
+    ) -> anyhow::Result<()> {
+        if !self.output_cfg.contains(&CFGType::LLVM) {
+            return Ok(());
+        }
+        let graphs = module
+            .get_functions()
+            .filter(|function| function.count_basic_blocks() > 0)
+            .map(|function| {
+                DotGraph::from_llvm_ir(
+                    function.get_name().to_string_lossy().to_string(),
+                    function.print_to_string().to_string().as_str(),
+                )
+            })
+            .collect();
+        self.dump_cfg(
+            contract_path,
+            CFGType::LLVM,
+            graphs,
+            is_size_fallback,
+            spill_area_size,
+        )
+    }
+
     ///
     /// Writes data to the file, respecting the `overwrite` flag.
     ///
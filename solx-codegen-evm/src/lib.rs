@@ -69,6 +69,8 @@ pub use self::context::traits::solidity_data::ISolidityData;
 pub use self::context::value::Value;
 pub use self::debug_config::DebugConfig;
 pub use self::debug_config::OutputConfig;
+pub use self::debug_config::cfg_type::CFGType;
+pub use self::debug_config::dot_graph::DotGraph;
 pub use self::debug_config::ir_type::IRType;
 pub use self::dependencies::Dependencies;
 pub use self::optimizer::Optimizer;
@@ -24,6 +24,11 @@ pub struct Settings {
     pub spill_area_size: Option<u64>,
     /// Metadata size, used for LLVM for gas/size tradeoffs.
     pub metadata_size: Option<u64>,
+    /// Expected number of contract executions, mirroring the `solc` optimizer `runs` setting.
+    /// Kept to reproduce the compilation, as its effect, if opted in, is already applied to the
+    /// size level.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected_runs: Option<u64>,
 
     /// Whether the LLVM `verify each` option is enabled.
     pub is_verify_each_enabled: bool,
@@ -41,6 +46,12 @@ impl Settings {
     /// The default optimization mode when none is specified.
     pub const DEFAULT_OPTIMIZATION_MODE: char = '3';
 
+    /// The `solc` default `runs`, below which the deploy cost is favored over the runtime gas.
+    pub const SIZE_EXPECTED_RUNS_THRESHOLD: u64 = 200;
+
+    /// The expected runs at or below which the contract is considered to be executed only once.
+    pub const MINIMAL_SIZE_EXPECTED_RUNS_THRESHOLD: u64 = 1;
+
     /// Environment variable name for the optimization level override.
     pub const OPTIMIZATION_ENV: &str = "SOLX_OPTIMIZATION";
 
@@ -83,6 +94,7 @@ impl Settings {
 
             spill_area_size: None,
             metadata_size: None,
+            expected_runs: None,
 
             is_verify_each_enabled,
             is_debug_logging_enabled,
@@ -252,6 +264,40 @@ impl Settings {
     pub fn set_metadata_size(&mut self, size: u64) {
         self.metadata_size = Some(size);
     }
+
+    ///
+    /// Sets the expected number of contract executions.
+    ///
+    pub fn set_expected_runs(&mut self, runs: u64) {
+        self.expected_runs = Some(runs);
+    }
+
+    ///
+    /// Switches the aggressive mode to optimizing for size if the expected number of contract
+    /// executions is low, and returns the new mode if it has changed.
+    ///
+    /// The middle-end size level drives the inliner and constant materialization towards
+    /// smaller code. Modes explicitly tuned for size or for lower optimization levels are left
+    /// intact.
+    ///
+    pub fn apply_expected_runs(&mut self) -> Option<char> {
+        let runs = self.expected_runs?;
+        if self.level_middle_end != inkwell::OptimizationLevel::Aggressive
+            || self.level_middle_end_size != SizeLevel::Zero
+        {
+            return None;
+        }
+        if runs <= Self::MINIMAL_SIZE_EXPECTED_RUNS_THRESHOLD {
+            self.level_middle_end = inkwell::OptimizationLevel::Default;
+            self.level_middle_end_size = SizeLevel::Z;
+        } else if runs < Self::SIZE_EXPECTED_RUNS_THRESHOLD {
+            self.level_middle_end = inkwell::OptimizationLevel::Default;
+            self.level_middle_end_size = SizeLevel::S;
+        } else {
+            return None;
+        }
+        Some(self.middle_end_as_char())
+    }
 }
 
 impl PartialEq for Settings {
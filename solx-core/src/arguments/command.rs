@@ -0,0 +1,24 @@
+//!
+//! Solidity compiler subcommands.
+//!
+
+use std::path::PathBuf;
+
+///
+/// Solidity compiler subcommands.
+///
+#[derive(Debug, clap::Subcommand)]
+pub enum Command {
+    /// Verify deployed bytecode against the contract metadata.
+    /// Recompile the sources described by the metadata and compare the result with the deployed bytecode.
+    /// Input files and output options cannot be specified.
+    Verify {
+        /// Specify the contract metadata JSON file.
+        #[arg(long, value_name = "METADATA")]
+        metadata: PathBuf,
+
+        /// Specify the deployed bytecode, as a hexadecimal string or a path to a file containing it.
+        #[arg(long, value_name = "BYTECODE")]
+        bytecode: String,
+    },
+}
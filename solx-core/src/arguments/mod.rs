@@ -2,6 +2,8 @@
 //! Solidity compiler arguments.
 //!
 
+pub mod command;
+
 use std::collections::BTreeSet;
 use std::path::Path;
 use std::path::PathBuf;
@@ -11,12 +13,18 @@ use std::sync::Mutex;
 use clap::Parser;
 use path_slash::PathExt;
 
+use self::command::Command;
+
 ///
 /// Solidity compiler arguments.
 ///
 #[derive(Debug, Parser)]
 #[command(about, long_about = "LLVM-based Solidity compiler for the EVM")]
 pub struct Arguments {
+    /// The subcommand.
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
     /// Print the version and exit.
     #[arg(long)]
     pub version: bool,
@@ -62,11 +70,23 @@ pub struct Arguments {
     #[arg(long, help_heading = "Input Options")]
     pub standard_json: Option<Option<String>>,
 
+    /// Directory with archived sources addressed by their content hash.
+    /// Resolves `ipfs://` and `bzz-raw://` source URLs in standard JSON input
+    /// from `<DIR>/ipfs/<CID>` and `<DIR>/bzz-raw/<hash>` respectively.
+    #[arg(long, value_name = "DIR", help_heading = "Input Options")]
+    pub source_archive: Option<PathBuf>,
+
     /// Specify addresses of deployable libraries. Syntax: `<libraryFullPath1>=<address1> ... <libraryFullPathN>=<addressN>`.
     /// Addresses are interpreted as hexadecimal strings prefixed with `0x`.
     #[arg(short, long, num_args = 1.., help_heading = "Input Options")]
     pub libraries: Vec<String>,
 
+    /// Switch to object linking mode. Assemble and link the relocatable objects emitted with `--emit-obj`,
+    /// passed as their `.o.json` manifests, using the libraries specified with `--libraries`.
+    /// Cannot be used with other modes.
+    #[arg(long, help_heading = "Input Options")]
+    pub link_objects: bool,
+
     //
     // Output Options
     //
@@ -78,9 +98,31 @@ pub struct Arguments {
     #[arg(long, help_heading = "Output Options")]
     pub overwrite: bool,
 
+    /// Stream the standard JSON output contract by contract instead of building it in memory.
+    /// The `ndjson` format writes every contract as a separate JSON line.
+    /// Only used together with `--standard-json`.
+    #[arg(
+        long,
+        value_name = "FORMAT",
+        num_args = 0..=1,
+        default_missing_value = "json",
+        help_heading = "Output Options"
+    )]
+    pub standard_json_stream: Option<solx_standard_json::OutputStreamFormat>,
+
     //
     // Output Selection
     //
+    /// Emit the solc-compatible combined JSON with the comma-separated outputs,
+    /// e.g. `abi,bin,bin-runtime,hashes,metadata,srcmap`.
+    #[arg(
+        long,
+        value_name = "OUTPUTS",
+        value_delimiter = ',',
+        help_heading = "Output Selection"
+    )]
+    pub combined_json: Option<Vec<crate::CombinedJsonSelector>>,
+
     /// Emit bytecode of the compiled contracts.
     #[arg(long = "bin", help_heading = "Output Selection")]
     pub output_bytecode: bool,
@@ -160,6 +202,21 @@ pub struct Arguments {
     #[arg(long = "emit-llvm-ir", help_heading = "Output Selection")]
     pub output_llvm_ir: bool,
 
+    /// Emit control-flow graphs in Graphviz DOT format, one file per function.
+    /// Accepts a comma-separated list of `ethir`, `llvm`, and `asm`. Must be used with --output-dir.
+    #[arg(
+        long = "emit-cfg",
+        value_delimiter = ',',
+        value_name = "TYPES",
+        help_heading = "Output Selection"
+    )]
+    pub output_cfg: Vec<solx_codegen_evm::CFGType>,
+
+    /// Emit relocatable EVM objects with their linker symbols, immutables, and debug info intact.
+    /// Must be used with --output-dir. The objects are linked later with `--link-objects`.
+    #[arg(long, help_heading = "Output Selection")]
+    pub emit_obj: bool,
+
     /// Emit MLIR at each pipeline stage.
     /// Without a value (`--emit-mlir`), prints every dialect in pipeline
     /// order. Pass `--emit-mlir=sol` or `--emit-mlir=llvm` to print one
@@ -191,6 +248,35 @@ pub struct Arguments {
     #[arg(short, long, help_heading = "Compilation Settings")]
     pub threads: Option<usize>,
 
+    /// Dispatch translation units to a worker server at `tcp://<host>:<port>` or `unix://<path>`
+    /// instead of local child processes. Can be specified multiple times to spread the load.
+    /// Workers are started with `solx --recursive-process --listen <ADDRESS>`.
+    /// The connection is not authenticated, so workers must only listen on trusted interfaces.
+    #[arg(long, value_name = "ADDRESS", help_heading = "Compilation Settings")]
+    pub remote_worker: Vec<crate::process::transport::address::Address>,
+
+    /// Silence solx warnings matching the rule `<code>[@<path-glob>]`.
+    /// The code may be `*` to match any warning. The glob is matched against the contract path.
+    /// Example: `--suppress-warning '5574@src/legacy/*.sol'`.
+    #[arg(long, value_name = "RULE", help_heading = "Compilation Settings")]
+    pub suppress_warning: Vec<solx_standard_json::InputWarningRule>,
+
+    /// Promote solx warnings matching the rule `<code>[@<path-glob>]` to errors.
+    /// Suppression takes precedence over promotion.
+    /// Example: `--warning-as-error 5574`.
+    #[arg(long, value_name = "RULE", help_heading = "Compilation Settings")]
+    pub warning_as_error: Vec<solx_standard_json::InputWarningRule>,
+
+    /// Sets the wall-clock time limit in seconds for compiling each translation unit.
+    /// A contract exceeding it is reported as an error, and the remaining ones keep compiling.
+    #[arg(long, value_name = "SECONDS", help_heading = "Compilation Settings")]
+    pub job_timeout: Option<u64>,
+
+    /// Sets the resident memory limit in megabytes for compiling each translation unit.
+    /// A contract exceeding it is reported as an error, and the remaining ones keep compiling.
+    #[arg(long, value_name = "MEGABYTES", help_heading = "Compilation Settings")]
+    pub job_memory_limit: Option<u64>,
+
     //
     // Optimization
     //
@@ -213,7 +299,7 @@ pub struct Arguments {
     // Metadata
     //
     /// Set the metadata hash type.
-    /// Available types: `none`, `ipfs`.
+    /// Available types: `none`, `ipfs`, `bzzr1`.
     /// The default is `ipfs`.
     #[arg(long, help_heading = "Metadata")]
     pub metadata_hash: Option<solx_utils::MetadataHashType>,
@@ -248,12 +334,28 @@ pub struct Arguments {
     /// Only for usage from within the compiler.
     #[arg(long, hide = true)]
     pub recursive_process: bool,
+
+    /// Serve worker connections at `tcp://<host>:<port>` or `unix://<path>` in the recursive process mode.
+    /// Every connection is served by its own worker process.
+    #[arg(long, hide = true, value_name = "ADDRESS")]
+    pub listen: Option<crate::process::transport::address::Address>,
+
+    /// Replay a crash reproducer bundle in this process, e.g. under a debugger.
+    /// Only for testing and debugging.
+    #[arg(long, hide = true, value_name = "BUNDLE")]
+    pub replay: Option<PathBuf>,
 }
 
 impl Arguments {
     /// Expected argument count for `--recursive-process` (binary name + flag).
     const RECURSIVE_PROCESS_MAX_ARGS: usize = 2;
 
+    /// Extra argument count allowed in the recursive process mode for `--listen` (flag + address).
+    const LISTEN_MAX_ARGS: usize = 2;
+
+    /// Expected argument count for `--replay` (binary name + flag + bundle path).
+    const REPLAY_MAX_ARGS: usize = 3;
+
     /// Expected argument count for `--version` (binary name + flag).
     const VERSION_MAX_ARGS: usize = 2;
 
@@ -267,13 +369,33 @@ impl Arguments {
         let mut messages = vec![];
 
         if self.recursive_process {
-            if std::env::args().count() > Self::RECURSIVE_PROCESS_MAX_ARGS {
+            let max_args = if self.listen.is_some() {
+                Self::RECURSIVE_PROCESS_MAX_ARGS + Self::LISTEN_MAX_ARGS
+            } else {
+                Self::RECURSIVE_PROCESS_MAX_ARGS
+            };
+            if std::env::args().count() > max_args {
                 messages.push(solx_standard_json::OutputError::new_error(
                     "No other options are allowed while running in the recursive process mode.",
                 ));
             }
             return Arc::new(Mutex::new(messages));
         }
+        if self.listen.is_some() {
+            messages.push(solx_standard_json::OutputError::new_error(
+                "Listening for worker connections is only allowed in the recursive process mode.",
+            ));
+            return Arc::new(Mutex::new(messages));
+        }
+
+        if self.replay.is_some() {
+            if std::env::args().count() > Self::REPLAY_MAX_ARGS {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    "No other options are allowed while replaying a crash reproducer.",
+                ));
+            }
+            return Arc::new(Mutex::new(messages));
+        }
 
         if self.version {
             if std::env::args().count() > Self::VERSION_MAX_ARGS {
@@ -284,19 +406,90 @@ impl Arguments {
             return Arc::new(Mutex::new(messages));
         }
 
-        if self.standard_json.is_none() && self.inputs.is_empty() {
+        if self.standard_json.is_none() && self.command.is_none() && self.inputs.is_empty() {
             messages.push(solx_standard_json::OutputError::new_error(
                 format!("No input files given. For standard input, specify `{}` explicitly, or visit `--help` to see all options.", solx_standard_json::InputSource::STDIN_INPUT_IDENTIFIER).as_str(),
             ));
         }
 
-        let modes_count = [self.yul, self.llvm_ir, self.standard_json.is_some()]
-            .iter()
-            .filter(|&&x| x)
-            .count();
+        let modes_count = [
+            self.yul,
+            self.llvm_ir,
+            self.standard_json.is_some(),
+            matches!(self.command, Some(Command::Verify { .. })),
+            self.link_objects,
+        ]
+        .iter()
+        .filter(|&&x| x)
+        .count();
         if modes_count > 1 {
             messages.push(solx_standard_json::OutputError::new_error(
-                "Only one mode is allowed at the same time: Yul, LLVM IR, standard JSON.",
+                "Only one mode is allowed at the same time: Yul, LLVM IR, standard JSON, verification, object linking.",
+            ));
+        }
+
+        if let Some(Command::Verify { .. }) = self.command {
+            if !self.inputs.is_empty() {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    "Input files are taken from the metadata in verification mode.",
+                ));
+            }
+            if !self.output_selection().is_empty() || self.output_dir.is_some() {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    "Cannot output data in verification mode.",
+                ));
+            }
+        }
+
+        if self.link_objects {
+            if self.has_non_bytecode_output_flags() || self.combined_json.is_some() || self.emit_obj
+            {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    "Only bytecode can be emitted in object linking mode.",
+                ));
+            }
+        }
+
+        if self.emit_obj {
+            if self.output_dir.is_none() {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    "Relocatable objects can only be written to the output directory specified with `--output-dir`.",
+                ));
+            }
+            if self.has_output_flags() || self.combined_json.is_some() {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    "Relocatable objects cannot be emitted together with other outputs.",
+                ));
+            }
+        }
+
+        if !self.output_cfg.is_empty() {
+            if self.output_dir.is_none() {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    "Control-flow graphs can only be written to the output directory specified with `--output-dir`.",
+                ));
+            }
+            if (self.yul || self.llvm_ir)
+                && self
+                    .output_cfg
+                    .iter()
+                    .any(|cfg_type| *cfg_type != solx_codegen_evm::CFGType::LLVM)
+            {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    "Ethereal IR and EVM assembly control-flow graphs are only available in Solidity mode.",
+                ));
+            }
+        }
+
+        if self.standard_json_stream.is_some() && self.standard_json.is_none() {
+            messages.push(solx_standard_json::OutputError::new_error(
+                "Streaming output can only be used in standard JSON mode.",
+            ));
+        }
+
+        if self.source_archive.is_some() && self.standard_json.is_none() {
+            messages.push(solx_standard_json::OutputError::new_error(
+                "Source archive can only be used in standard JSON mode.",
             ));
         }
 
@@ -350,29 +543,26 @@ impl Arguments {
             }
         }
 
+        if let Some(ref combined_json) = self.combined_json {
+            if self.yul || self.llvm_ir {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    "Combined JSON can be only emitted for Solidity contracts.",
+                ));
+            }
+            if self.has_output_flags() {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    "Combined JSON cannot be emitted together with other outputs.",
+                ));
+            }
+            if combined_json.is_empty() {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    "Combined JSON requires at least one output.",
+                ));
+            }
+        }
+
         if self.standard_json.is_some() {
-            let has_output_flags = self.output_bytecode
-                || self.output_bytecode_runtime
-                || self.output_assembly
-                || self.output_debug_info
-                || self.output_debug_info_runtime
-                || self.output_metadata
-                || self.output_abi
-                || self.output_hashes
-                || self.output_userdoc
-                || self.output_devdoc
-                || self.output_storage_layout
-                || self.output_transient_storage_layout
-                || self.output_ast_json
-                || self.output_asm_solc_json
-                || self.output_ir
-                || self.output_benchmarks
-                || self.output_evmla
-                || self.output_ethir
-                || self.output_llvm_ir;
-            #[cfg(feature = "mlir")]
-            let has_output_flags = has_output_flags || self.output_mlir.is_some();
-            if has_output_flags {
+            if self.has_output_flags() || self.combined_json.is_some() || self.emit_obj {
                 messages.push(solx_standard_json::OutputError::new_error(
                     "Cannot output data outside of JSON in standard JSON mode.",
                 ));
@@ -435,6 +625,16 @@ impl Arguments {
                     "Metadata literal content flag must be specified in standard JSON input settings.",
                 ));
             }
+            if !self.suppress_warning.is_empty() || !self.warning_as_error.is_empty() {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    "Warning rules must be specified in standard JSON input settings.",
+                ));
+            }
+            if self.job_timeout.is_some() || self.job_memory_limit.is_some() {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    "Job limits must be specified in standard JSON input settings.",
+                ));
+            }
         }
 
         Arc::new(Mutex::new(messages))
@@ -485,10 +685,14 @@ impl Arguments {
     pub fn output_selection(&self) -> solx_standard_json::InputSelection {
         let mut selectors = std::collections::BTreeSet::new();
 
-        if self.output_bytecode {
+        for selector in self.combined_json.iter().flatten() {
+            selectors.insert(selector.input_selector());
+        }
+
+        if self.output_bytecode || self.emit_obj {
             selectors.insert(solx_standard_json::InputSelector::BytecodeObject);
         }
-        if self.output_bytecode_runtime {
+        if self.output_bytecode_runtime || self.emit_obj {
             selectors.insert(solx_standard_json::InputSelector::RuntimeBytecodeObject);
         }
         if self.output_assembly {
@@ -501,6 +705,10 @@ impl Arguments {
             selectors.insert(solx_standard_json::InputSelector::RuntimeBytecodeLLVMIRUnoptimized);
             selectors.insert(solx_standard_json::InputSelector::RuntimeBytecodeLLVMIR);
         }
+        if !self.output_cfg.is_empty() {
+            selectors.insert(solx_standard_json::InputSelector::BytecodeLLVMIR);
+            selectors.insert(solx_standard_json::InputSelector::RuntimeBytecodeLLVMIR);
+        }
         if self.output_benchmarks {
             selectors.insert(solx_standard_json::InputSelector::Benchmarks);
         }
@@ -526,10 +734,10 @@ impl Arguments {
                 selectors.insert(solx_standard_json::InputSelector::BytecodeEthIR);
                 selectors.insert(solx_standard_json::InputSelector::RuntimeBytecodeEthIR);
             }
-            if self.output_debug_info {
+            if self.output_debug_info || (self.emit_obj && !self.yul && !self.llvm_ir) {
                 selectors.insert(solx_standard_json::InputSelector::BytecodeDebugInfo);
             }
-            if self.output_debug_info_runtime {
+            if self.output_debug_info_runtime || (self.emit_obj && !self.yul && !self.llvm_ir) {
                 selectors.insert(solx_standard_json::InputSelector::RuntimeBytecodeDebugInfo);
             }
             if self.output_abi {
@@ -610,7 +818,8 @@ impl Arguments {
             || self.output_evmla
             || self.output_ethir
             || self.output_llvm_ir
-            || self.output_assembly;
+            || self.output_assembly
+            || !self.output_cfg.is_empty();
         #[cfg(feature = "mlir")]
         let has_ir_flags = has_ir_flags || self.output_mlir.is_some();
         if !has_ir_flags {
@@ -626,9 +835,27 @@ impl Arguments {
             self.output_ethir,
             self.output_llvm_ir,
             self.output_assembly,
+            self.output_cfg.iter().copied().collect(),
         )))
     }
 
+    ///
+    /// Returns the warning suppression and promotion rules.
+    ///
+    pub fn warning_rules(&self) -> solx_standard_json::InputWarnings {
+        solx_standard_json::InputWarnings::new(
+            self.suppress_warning.clone(),
+            self.warning_as_error.clone(),
+        )
+    }
+
+    ///
+    /// Returns the per-translation-unit resource limits.
+    ///
+    pub fn job_limits(&self) -> solx_standard_json::InputLimits {
+        solx_standard_json::InputLimits::new(self.job_timeout, self.job_memory_limit)
+    }
+
     ///
     /// Parse the `--llvm-options` string into individual options.
     ///
@@ -655,4 +882,38 @@ impl Arguments {
         let path = PathBuf::from(path.as_str());
         Ok(path)
     }
+
+    ///
+    /// Whether any of the individual output selection flags is set.
+    ///
+    fn has_output_flags(&self) -> bool {
+        self.output_bytecode || self.output_bytecode_runtime || self.has_non_bytecode_output_flags()
+    }
+
+    ///
+    /// Whether any of the individual output selection flags other than bytecode is set.
+    ///
+    fn has_non_bytecode_output_flags(&self) -> bool {
+        let has_output_flags = self.output_assembly
+            || self.output_debug_info
+            || self.output_debug_info_runtime
+            || self.output_metadata
+            || self.output_abi
+            || self.output_hashes
+            || self.output_userdoc
+            || self.output_devdoc
+            || self.output_storage_layout
+            || self.output_transient_storage_layout
+            || self.output_ast_json
+            || self.output_asm_solc_json
+            || self.output_ir
+            || self.output_benchmarks
+            || self.output_evmla
+            || self.output_ethir
+            || self.output_llvm_ir
+            || !self.output_cfg.is_empty();
+        #[cfg(feature = "mlir")]
+        let has_output_flags = has_output_flags || self.output_mlir.is_some();
+        has_output_flags
+    }
 }
@@ -480,6 +480,90 @@ impl Contract {
         Ok(())
     }
 
+    ///
+    /// Converts the contract into the solc-compatible combined JSON contract.
+    ///
+    /// The source maps, opcodes, function debug data, and generated sources are written empty,
+    /// as in the standard JSON output.
+    ///
+    pub fn into_combined_json(
+        mut self,
+        selectors: &[crate::CombinedJsonSelector],
+    ) -> anyhow::Result<crate::CombinedJsonContract> {
+        let bytecode_hex = |object_result: &mut Option<crate::Result<Object>>| {
+            object_result
+                .as_mut()
+                .and_then(|result| result.as_mut().ok())
+                .and_then(|object| object.bytecode_hex.take())
+                .unwrap_or_default()
+        };
+
+        let mut combined_json_contract = crate::CombinedJsonContract::default();
+        for selector in selectors.iter() {
+            match selector {
+                crate::CombinedJsonSelector::ABI => {
+                    combined_json_contract.abi = self.abi.take();
+                }
+                crate::CombinedJsonSelector::Assembly => {
+                    combined_json_contract.asm = self
+                        .legacy_assembly
+                        .take()
+                        .map(serde_json::to_value)
+                        .transpose()?;
+                }
+                crate::CombinedJsonSelector::AST => {}
+                crate::CombinedJsonSelector::Bytecode => {
+                    combined_json_contract.bin = Some(bytecode_hex(&mut self.deploy_object_result));
+                }
+                crate::CombinedJsonSelector::RuntimeBytecode => {
+                    combined_json_contract.bin_runtime =
+                        Some(bytecode_hex(&mut self.runtime_object_result));
+                }
+                crate::CombinedJsonSelector::DeveloperDocumentation => {
+                    combined_json_contract.devdoc = self.devdoc.take();
+                }
+                crate::CombinedJsonSelector::FunctionDebugData => {
+                    combined_json_contract.function_debug = Some(serde_json::json!({}));
+                }
+                crate::CombinedJsonSelector::RuntimeFunctionDebugData => {
+                    combined_json_contract.function_debug_runtime = Some(serde_json::json!({}));
+                }
+                crate::CombinedJsonSelector::GeneratedSources => {
+                    combined_json_contract.generated_sources = Some(Vec::new());
+                }
+                crate::CombinedJsonSelector::RuntimeGeneratedSources => {
+                    combined_json_contract.generated_sources_runtime = Some(Vec::new());
+                }
+                crate::CombinedJsonSelector::Hashes => {
+                    combined_json_contract.hashes = self.method_identifiers.take();
+                }
+                crate::CombinedJsonSelector::Metadata => {
+                    combined_json_contract.metadata = self.metadata.take();
+                }
+                crate::CombinedJsonSelector::Opcodes => {
+                    combined_json_contract.opcodes = Some(String::new());
+                }
+                crate::CombinedJsonSelector::SourceMap => {
+                    combined_json_contract.srcmap = Some(String::new());
+                }
+                crate::CombinedJsonSelector::RuntimeSourceMap => {
+                    combined_json_contract.srcmap_runtime = Some(String::new());
+                }
+                crate::CombinedJsonSelector::StorageLayout => {
+                    combined_json_contract.storage_layout = self.storage_layout.take();
+                }
+                crate::CombinedJsonSelector::TransientStorageLayout => {
+                    combined_json_contract.transient_storage_layout =
+                        self.transient_storage_layout.take();
+                }
+                crate::CombinedJsonSelector::UserDocumentation => {
+                    combined_json_contract.userdoc = self.userdoc.take();
+                }
+            }
+        }
+        Ok(combined_json_contract)
+    }
+
     ///
     /// Writes the contract text assembly and bytecode to files.
     ///
@@ -489,22 +573,8 @@ impl Contract {
         output_selection: &solx_standard_json::InputSelection,
         overwrite: bool,
     ) -> anyhow::Result<()> {
-        let contract_path = PathBuf::from(self.name.path.as_str());
-        let contract_name = contract_path
-            .file_name()
-            .expect("Always exists")
-            .to_str()
-            .expect("Always valid");
-        let contract_path = contract_path.normalize()?;
-        let contract_path = if contract_path.starts_with(std::env::current_dir()?) {
-            contract_path
-                .as_path()
-                .strip_prefix(std::env::current_dir()?)?
-        } else {
-            contract_path.as_path()
-        }
-        .to_string_lossy();
-        let contract_path = solx_utils::ContractName::sanitize_path(contract_path.as_ref());
+        let (contract_path, contract_name) = self.output_path_parts()?;
+        let contract_name = contract_name.as_str();
 
         if let Some(deploy_object_result) = self.deploy_object_result.as_mut()
             && output_selection.check_selection(
@@ -1189,6 +1259,58 @@ impl Contract {
         )
     }
 
+    ///
+    /// Writes the contract relocatable objects and their manifest to files.
+    ///
+    /// The objects are neither assembled nor linked, so they can be linked later with `--link-objects`.
+    ///
+    pub fn write_to_object_files(
+        self,
+        output_directory: &Path,
+        overwrite: bool,
+    ) -> anyhow::Result<()> {
+        let objects = self.objects_ref();
+        if objects.is_empty() {
+            return Ok(());
+        }
+
+        let (contract_path, contract_name) = self.output_path_parts()?;
+        let base_name = format!(
+            "{contract_path}_{}.{}",
+            self.name.name.as_deref().unwrap_or(contract_name.as_str()),
+            solx_utils::EXTENSION_EVM_OBJECT,
+        );
+
+        let mut object_file = crate::ObjectFile::new(self.name.clone());
+        for object in objects.into_iter() {
+            let object_name = match object.code_segment {
+                solx_utils::CodeSegment::Deploy => base_name.clone(),
+                solx_utils::CodeSegment::Runtime => {
+                    format!("{base_name}-{}", solx_utils::CodeSegment::Runtime)
+                }
+            };
+            let mut output_path = output_directory.to_owned();
+            output_path.push(object_name.as_str());
+
+            let bytecode = object.bytecode.as_deref().expect("Always exists");
+            Self::write_to_file(output_path.as_path(), bytecode, overwrite)?;
+
+            let segment = crate::ObjectFileSegment::new(object, object_name);
+            match object.code_segment {
+                solx_utils::CodeSegment::Deploy => object_file.deploy = Some(segment),
+                solx_utils::CodeSegment::Runtime => object_file.runtime = Some(segment),
+            }
+        }
+
+        let mut output_path = output_directory.to_owned();
+        output_path.push(format!("{base_name}.{}", solx_utils::EXTENSION_JSON));
+        Self::write_to_file(
+            output_path.as_path(),
+            serde_json::to_vec_pretty(&object_file)?,
+            overwrite,
+        )
+    }
+
     ///
     /// Writes data to the file, checking the `overwrite` flag.
     ///
@@ -1208,6 +1330,30 @@ impl Contract {
         Ok(())
     }
 
+    ///
+    /// Returns the sanitized contract path and the source file name, which name the output files.
+    ///
+    fn output_path_parts(&self) -> anyhow::Result<(String, String)> {
+        let contract_path = PathBuf::from(self.name.path.as_str());
+        let contract_name = contract_path
+            .file_name()
+            .expect("Always exists")
+            .to_str()
+            .expect("Always valid")
+            .to_owned();
+        let contract_path = contract_path.normalize()?;
+        let contract_path = if contract_path.starts_with(std::env::current_dir()?) {
+            contract_path
+                .as_path()
+                .strip_prefix(std::env::current_dir()?)?
+        } else {
+            contract_path.as_path()
+        }
+        .to_string_lossy();
+        let contract_path = solx_utils::ContractName::sanitize_path(contract_path.as_ref());
+        Ok((contract_path, contract_name))
+    }
+
     ///
     /// Returns references to objects.
     ///
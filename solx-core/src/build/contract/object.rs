@@ -236,15 +236,19 @@ impl Object {
     ///
     /// Extracts warnings in standard JSON format.
     ///
-    pub fn take_warnings_standard_json(&mut self) -> Vec<solx_standard_json::OutputError> {
+    /// The warning `rules` may silence some warnings or promote them to errors.
+    ///
+    pub fn take_warnings_standard_json(
+        &mut self,
+        rules: &solx_standard_json::InputWarnings,
+    ) -> Vec<solx_standard_json::OutputError> {
         self.warnings
             .drain(..)
-            .map(|warning| {
-                solx_standard_json::OutputError::new_warning_with_data(
+            .filter_map(|warning| rules.apply(warning.to_diagnostic(), &self.contract_name))
+            .map(|diagnostic| {
+                solx_standard_json::OutputError::new_diagnostic(
                     Some(self.contract_name.path.as_str()),
-                    warning.code(),
-                    warning.to_string(),
-                    None,
+                    &diagnostic,
                     None,
                 )
             })
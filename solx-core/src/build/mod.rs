@@ -5,6 +5,7 @@
 pub mod contract;
 
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::io::Write;
 use std::path::Path;
 use std::path::PathBuf;
@@ -32,6 +33,8 @@ pub struct Build {
     pub messages: Arc<Mutex<Vec<solx_standard_json::OutputError>>>,
     /// Compilation pipeline benchmarks.
     pub benchmarks: Vec<(String, u64)>,
+    /// The warning suppression and promotion rules.
+    pub warning_rules: solx_standard_json::InputWarnings,
 }
 
 impl Build {
@@ -48,18 +51,86 @@ impl Build {
             ast_jsons,
             messages,
             benchmarks: Vec::new(),
+            warning_rules: solx_standard_json::InputWarnings::default(),
         }
     }
 
+    ///
+    /// Reads the relocatable objects emitted with `--emit-obj` into an unlinked build.
+    ///
+    /// Every dependency of the objects must be among them, as it is assembled into its dependents.
+    ///
+    pub fn try_from_object_files(
+        paths: &[PathBuf],
+        messages: Arc<Mutex<Vec<solx_standard_json::OutputError>>>,
+    ) -> anyhow::Result<Self> {
+        let mut contracts = BTreeMap::new();
+        for path in paths.iter() {
+            let contract =
+                crate::ObjectFile::try_from_path(path.as_path())?.into_contract(path.as_path())?;
+            let full_path = contract.name.full_path.clone();
+            if contracts.insert(full_path.clone(), contract).is_some() {
+                anyhow::bail!("Contract `{full_path}` is passed for linking more than once.");
+            }
+        }
+
+        let identifiers = contracts
+            .values()
+            .flat_map(|contract| contract.objects_ref())
+            .map(|object| object.identifier.as_str())
+            .collect::<BTreeSet<&str>>();
+        for object in contracts
+            .values()
+            .flat_map(|contract| contract.objects_ref())
+        {
+            if let Some(dependency) = object
+                .dependencies
+                .inner
+                .iter()
+                .find(|dependency| !identifiers.contains(dependency.as_str()))
+            {
+                anyhow::bail!(
+                    "Object `{}` depends on `{dependency}`, which is not among the linked objects.",
+                    object.identifier
+                );
+            }
+        }
+
+        Ok(Self::new(contracts, None, messages))
+    }
+
     ///
     /// Links the EVM build.
     ///
     pub fn link(
-        mut self,
+        self,
         linker_symbols: BTreeMap<String, [u8; solx_utils::BYTE_LENGTH_ETH_ADDRESS]>,
     ) -> Self {
-        let ast_jsons = self.ast_jsons.take();
+        let mut build = self.assemble(&linker_symbols);
+        let ast_jsons = build.ast_jsons.take();
+        let warning_rules = std::mem::take(&mut build.warning_rules);
+
+        for contract in build.contracts.values_mut() {
+            if let Err(error) = Self::link_contract(contract, &linker_symbols) {
+                build.messages.lock_sync().push(error);
+                return Self::new(BTreeMap::new(), ast_jsons, build.messages)
+                    .with_warning_rules(warning_rules);
+            }
+        }
+
+        Self::new(build.contracts, ast_jsons, build.messages).with_warning_rules(warning_rules)
+    }
 
+    ///
+    /// Assembles the objects of the EVM build, leaving them to be linked contract by contract.
+    ///
+    /// Dependencies are assembled into their dependents, so every object is kept until all
+    /// objects are assembled. If an object cannot be assembled, the build is left without contracts.
+    ///
+    pub fn assemble(
+        mut self,
+        linker_symbols: &BTreeMap<String, [u8; solx_utils::BYTE_LENGTH_ETH_ADDRESS]>,
+    ) -> Self {
         loop {
             let assembled_objects_data = {
                 let all_objects = self
@@ -95,12 +166,23 @@ impl Build {
                         Ok(assembled_object) => assembled_object,
                         Err(error) => {
                             self.messages.lock_sync().push(
-                                solx_standard_json::OutputError::new_error_contract(
+                                solx_standard_json::OutputError::new_diagnostic(
                                     Some(object.contract_name.path.as_str()),
-                                    &error,
+                                    &solx_utils::Diagnostic::new(
+                                        solx_utils::DiagnosticCode::LinkerFailure,
+                                        solx_utils::DiagnosticComponent::Linker,
+                                        error,
+                                    ),
+                                    None,
                                 ),
                             );
-                            return Self::new(BTreeMap::new(), ast_jsons, self.messages);
+                            let warning_rules = std::mem::take(&mut self.warning_rules);
+                            return Self::new(
+                                BTreeMap::new(),
+                                self.ast_jsons.take(),
+                                self.messages,
+                            )
+                            .with_warning_rules(warning_rules);
                         }
                     };
                     assembled_objects_data.push((
@@ -137,21 +219,38 @@ impl Build {
             }
         }
 
-        for contract in self.contracts.values_mut() {
-            for object in contract.objects_mut().into_iter() {
-                if let Err(error) = object.link(&linker_symbols) {
-                    self.messages.lock_sync().push(
-                        solx_standard_json::OutputError::new_error_contract(
-                            Some(object.contract_name.path.as_str()),
-                            &error,
-                        ),
-                    );
-                    return Self::new(BTreeMap::new(), ast_jsons, self.messages);
-                }
-            }
+        self
+    }
+
+    ///
+    /// Links the assembled objects of `contract`.
+    ///
+    fn link_contract(
+        contract: &mut Contract,
+        linker_symbols: &BTreeMap<String, [u8; solx_utils::BYTE_LENGTH_ETH_ADDRESS]>,
+    ) -> Result<(), solx_standard_json::OutputError> {
+        for object in contract.objects_mut().into_iter() {
+            object.link(linker_symbols).map_err(|error| {
+                solx_standard_json::OutputError::new_diagnostic(
+                    Some(object.contract_name.path.as_str()),
+                    &solx_utils::Diagnostic::new(
+                        solx_utils::DiagnosticCode::LinkerFailure,
+                        solx_utils::DiagnosticComponent::Linker,
+                        error,
+                    ),
+                    None,
+                )
+            })?;
         }
+        Ok(())
+    }
 
-        Self::new(self.contracts, ast_jsons, self.messages)
+    ///
+    /// Sets the warning suppression and promotion rules.
+    ///
+    pub fn with_warning_rules(mut self, warning_rules: solx_standard_json::InputWarnings) -> Self {
+        self.warning_rules = warning_rules;
+        self
     }
 
     ///
@@ -285,6 +384,89 @@ impl Build {
         Ok(())
     }
 
+    ///
+    /// Writes all contracts as unlinked relocatable objects to the specified directory.
+    ///
+    pub fn write_to_object_files(
+        mut self,
+        output_directory: &Path,
+        overwrite: bool,
+    ) -> anyhow::Result<()> {
+        self.take_and_write_warnings();
+        self.exit_on_error();
+
+        std::fs::create_dir_all(output_directory)?;
+
+        for contract in self.contracts.into_values() {
+            contract.write_to_object_files(output_directory, overwrite)?;
+        }
+
+        writeln!(
+            std::io::stderr(),
+            "Compiler run successful. Artifact(s) can be found in directory {output_directory:?}."
+        )?;
+        Ok(())
+    }
+
+    ///
+    /// Writes all contracts as the solc-compatible combined JSON.
+    ///
+    /// The output is written to `combined.json` in `output_directory` if it is specified, and to
+    /// the terminal otherwise.
+    ///
+    pub fn write_to_combined_json(
+        mut self,
+        selectors: &[crate::CombinedJsonSelector],
+        version: String,
+        output_directory: Option<&Path>,
+        overwrite: bool,
+    ) -> anyhow::Result<()> {
+        self.take_and_write_warnings();
+        self.exit_on_error();
+
+        let mut combined_json = crate::CombinedJson::new(version);
+
+        let mut ast_jsons = self.ast_jsons.take().unwrap_or_default();
+        if crate::CombinedJson::needs_source_list(selectors) {
+            let source_list = ast_jsons
+                .keys()
+                .cloned()
+                .chain(
+                    self.contracts
+                        .values()
+                        .map(|contract| contract.name.path.clone()),
+                )
+                .collect::<BTreeSet<String>>()
+                .into_iter()
+                .collect::<Vec<String>>();
+            if selectors.contains(&crate::CombinedJsonSelector::AST) {
+                let mut sources = BTreeMap::new();
+                for (id, path) in source_list.iter().enumerate() {
+                    if let Some(ast_json) = ast_jsons.remove(path.as_str()).flatten() {
+                        sources.insert(
+                            path.to_owned(),
+                            crate::CombinedJsonSource::new(ast_json, id),
+                        );
+                    }
+                }
+                combined_json.sources = Some(sources);
+            }
+            combined_json.source_list = Some(source_list);
+        }
+
+        for contract in self.contracts.into_values() {
+            let full_path = contract.name.full_path.clone();
+            combined_json
+                .contracts
+                .insert(full_path, contract.into_combined_json(selectors)?);
+        }
+
+        match output_directory {
+            Some(output_directory) => combined_json.write_to_directory(output_directory, overwrite),
+            None => combined_json.write_to_terminal(),
+        }
+    }
+
     ///
     /// Writes all contracts assembly and bytecode to the standard JSON.
     ///
@@ -317,13 +499,13 @@ impl Build {
                 standard_json.errors.extend(
                     deploy_object_result
                         .as_mut()
-                        .map(|object| object.take_warnings_standard_json())
+                        .map(|object| object.take_warnings_standard_json(&self.warning_rules))
                         .unwrap_or_default(),
                 );
                 standard_json.errors.extend(
                     runtime_object_result
                         .as_mut()
-                        .map(|object| object.take_warnings_standard_json())
+                        .map(|object| object.take_warnings_standard_json(&self.warning_rules))
                         .unwrap_or_default(),
                 );
                 if deploy_object_result.is_err() || runtime_object_result.is_err() {
@@ -391,6 +573,122 @@ impl Build {
         }
         Ok(())
     }
+
+    ///
+    /// Streams the build to stdout as standard JSON and exits.
+    ///
+    /// If `linker_symbols` are passed, the build must be assembled, and every contract is linked
+    /// right before it is written. Every contract is dropped as soon as it is written, so neither
+    /// the linked contracts nor their standard JSON output are kept in memory for the whole project.
+    ///
+    /// If the build has errors, including warnings promoted to errors, the regular output is
+    /// written instead, as it must not contain any contracts. If a contract fails to link, the
+    /// contracts after it are not written, and the error is reported at the end of the stream.
+    ///
+    pub fn write_to_standard_json_stream(
+        mut self,
+        mut standard_json: solx_standard_json::Output,
+        output_selection: &solx_standard_json::InputSelection,
+        linker_symbols: Option<&BTreeMap<String, [u8; solx_utils::BYTE_LENGTH_ETH_ADDRESS]>>,
+        benchmarks: Vec<(String, u64)>,
+        format: solx_standard_json::OutputStreamFormat,
+    ) -> ! {
+        let warnings = self.take_warnings();
+        standard_json.errors.extend(warnings);
+        if self.has_errors() || standard_json.has_errors() {
+            self.write_to_standard_json(&mut standard_json, output_selection, true, benchmarks)
+                .expect("Always valid");
+            standard_json.write_and_exit(output_selection);
+        }
+
+        for (path, ast_json) in self.ast_jsons.iter_mut().flatten() {
+            if let Some(source) = standard_json.sources.get_mut(path.as_str())
+                && let Some(ast_json) = ast_json.take().filter(|_| {
+                    output_selection.check_selection(
+                        path.as_str(),
+                        None,
+                        solx_standard_json::InputSelector::AST,
+                    )
+                })
+            {
+                source.ast = Some(ast_json);
+            }
+        }
+
+        let mut build_contracts: BTreeMap<String, BTreeMap<String, Contract>> = BTreeMap::new();
+        for contract in std::mem::take(&mut self.contracts).into_values() {
+            let path = contract.name.path.clone();
+            let name = contract
+                .name
+                .name
+                .clone()
+                .unwrap_or_else(|| contract.name.path.clone());
+            build_contracts
+                .entry(path)
+                .or_default()
+                .insert(name, contract);
+        }
+        let paths = build_contracts
+            .keys()
+            .chain(standard_json.contracts.keys())
+            .cloned()
+            .collect::<BTreeSet<String>>();
+
+        let mut stream = solx_standard_json::OutputStream::new(std::io::stdout().lock(), format);
+        'paths: for path in paths.into_iter() {
+            let mut build_contracts = build_contracts.remove(path.as_str()).unwrap_or_default();
+            let mut standard_json_contracts = standard_json
+                .contracts
+                .remove(path.as_str())
+                .unwrap_or_default();
+            let names = build_contracts
+                .keys()
+                .chain(standard_json_contracts.keys())
+                .cloned()
+                .collect::<BTreeSet<String>>();
+            for name in names.into_iter() {
+                let mut standard_json_contract = standard_json_contracts
+                    .remove(name.as_str())
+                    .unwrap_or_default();
+                if let Some(mut contract) = build_contracts.remove(name.as_str()) {
+                    if let Some(linker_symbols) = linker_symbols
+                        && let Err(error) = Self::link_contract(&mut contract, linker_symbols)
+                    {
+                        self.messages.lock_sync().push(error);
+                        break 'paths;
+                    }
+                    contract.write_to_standard_json(
+                        &mut standard_json_contract,
+                        output_selection,
+                        true,
+                    );
+                }
+                stream
+                    .write_contract(
+                        path.as_str(),
+                        name.as_str(),
+                        standard_json_contract,
+                        output_selection,
+                    )
+                    .expect("Stdout writing error");
+            }
+        }
+
+        standard_json
+            .errors
+            .extend(self.messages.lock_sync().drain(..));
+        if output_selection.check_selection(
+            solx_standard_json::InputSelection::WILDCARD,
+            Some(solx_standard_json::InputSelection::ANY_CONTRACT),
+            solx_standard_json::InputSelector::Benchmarks,
+        ) {
+            standard_json.benchmarks.extend(benchmarks);
+        }
+        stream
+            .finish(standard_json, output_selection)
+            .expect("Stdout writing error");
+        std::process::exit(solx_utils::EXIT_CODE_SUCCESS);
+    }
 }
 
 impl solx_standard_json::CollectableError for Build {
@@ -448,16 +746,23 @@ impl solx_standard_json::CollectableError for Build {
             warnings.extend(
                 deploy_object_result
                     .as_mut()
-                    .map(|object| object.take_warnings_standard_json())
+                    .map(|object| object.take_warnings_standard_json(&self.warning_rules))
                     .unwrap_or_default(),
             );
             warnings.extend(
                 runtime_object_result
                     .as_mut()
-                    .map(|object| object.take_warnings_standard_json())
+                    .map(|object| object.take_warnings_standard_json(&self.warning_rules))
                     .unwrap_or_default(),
             );
         }
+
+        // Warnings promoted to errors must fail the build.
+        let promoted_errors = warnings
+            .extract_if(.., |message| message.severity == "error")
+            .collect::<Vec<_>>();
+        self.messages.lock_sync().extend(promoted_errors);
+
         warnings
     }
 
@@ -0,0 +1,64 @@
+//!
+//! The `solc --combined-json` output contract.
+//!
+
+use std::collections::BTreeMap;
+
+///
+/// The `solc --combined-json` output contract.
+///
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Contract {
+    /// The ABI specification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub abi: Option<serde_json::Value>,
+    /// The solc EVM legacy assembly.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asm: Option<serde_json::Value>,
+    /// The deploy bytecode.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bin: Option<String>,
+    /// The runtime bytecode.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bin_runtime: Option<String>,
+    /// The developer documentation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub devdoc: Option<serde_json::Value>,
+    /// The deploy code function debug data.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub function_debug: Option<serde_json::Value>,
+    /// The runtime code function debug data.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub function_debug_runtime: Option<serde_json::Value>,
+    /// The deploy code generated sources.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub generated_sources: Option<Vec<serde_json::Value>>,
+    /// The runtime code generated sources.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub generated_sources_runtime: Option<Vec<serde_json::Value>>,
+    /// The function signature hashes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hashes: Option<BTreeMap<String, String>>,
+    /// The contract metadata.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<String>,
+    /// The deploy code opcodes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub opcodes: Option<String>,
+    /// The deploy code source map.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub srcmap: Option<String>,
+    /// The runtime code source map.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub srcmap_runtime: Option<String>,
+    /// The storage layout.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub storage_layout: Option<serde_json::Value>,
+    /// The transient storage layout.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transient_storage_layout: Option<serde_json::Value>,
+    /// The user documentation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub userdoc: Option<serde_json::Value>,
+}
@@ -0,0 +1,96 @@
+//!
+//! The `solc --combined-json` output.
+//!
+
+pub mod contract;
+pub mod selector;
+pub mod source;
+
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::Path;
+
+use self::contract::Contract;
+use self::selector::Selector;
+use self::source::Source;
+
+///
+/// The `solc --combined-json` output.
+///
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CombinedJson {
+    /// The contracts, keyed by their full paths.
+    pub contracts: BTreeMap<String, Contract>,
+    /// The source file paths in the order of their identifiers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_list: Option<Vec<String>>,
+    /// The source file ASTs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sources: Option<BTreeMap<String, Source>>,
+    /// The solc version.
+    pub version: String,
+}
+
+impl CombinedJson {
+    /// The output file name.
+    pub const FILE_NAME: &'static str = "combined.json";
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(version: String) -> Self {
+        Self {
+            contracts: BTreeMap::new(),
+            source_list: None,
+            sources: None,
+            version,
+        }
+    }
+
+    ///
+    /// Whether the source list must be written for `selectors`, as solc does.
+    ///
+    pub fn needs_source_list(selectors: &[Selector]) -> bool {
+        selectors.iter().any(|selector| {
+            matches!(
+                selector,
+                Selector::AST | Selector::SourceMap | Selector::RuntimeSourceMap
+            )
+        })
+    }
+
+    ///
+    /// Writes the output to the terminal.
+    ///
+    pub fn write_to_terminal(&self) -> anyhow::Result<()> {
+        serde_json::to_writer(std::io::stdout(), self)?;
+        writeln!(std::io::stdout())?;
+        Ok(())
+    }
+
+    ///
+    /// Writes the output to `combined.json` in the specified directory.
+    ///
+    pub fn write_to_directory(
+        &self,
+        output_directory: &Path,
+        overwrite: bool,
+    ) -> anyhow::Result<()> {
+        std::fs::create_dir_all(output_directory)?;
+
+        let mut output_path = output_directory.to_owned();
+        output_path.push(Self::FILE_NAME);
+        crate::EVMContractBuild::write_to_file(
+            output_path.as_path(),
+            serde_json::to_vec(self)?,
+            overwrite,
+        )?;
+
+        writeln!(
+            std::io::stderr(),
+            "Compiler run successful. Artifact(s) can be found in directory {output_directory:?}."
+        )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,137 @@
+//!
+//! The `solc --combined-json` output selector.
+//!
+
+use std::str::FromStr;
+
+///
+/// The `solc --combined-json` output selector.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Selector {
+    /// The ABI specification.
+    ABI,
+    /// The solc EVM legacy assembly.
+    Assembly,
+    /// The source file AST.
+    AST,
+    /// The deploy bytecode.
+    Bytecode,
+    /// The runtime bytecode.
+    RuntimeBytecode,
+    /// The developer documentation.
+    DeveloperDocumentation,
+    /// The deploy code function debug data.
+    FunctionDebugData,
+    /// The runtime code function debug data.
+    RuntimeFunctionDebugData,
+    /// The deploy code generated sources.
+    GeneratedSources,
+    /// The runtime code generated sources.
+    RuntimeGeneratedSources,
+    /// The function signature hashes.
+    Hashes,
+    /// The contract metadata.
+    Metadata,
+    /// The deploy code opcodes.
+    Opcodes,
+    /// The deploy code source map.
+    SourceMap,
+    /// The runtime code source map.
+    RuntimeSourceMap,
+    /// The storage layout.
+    StorageLayout,
+    /// The transient storage layout.
+    TransientStorageLayout,
+    /// The user documentation.
+    UserDocumentation,
+}
+
+impl Selector {
+    ///
+    /// Returns the standard JSON output selector that provides the data.
+    ///
+    pub fn input_selector(&self) -> solx_standard_json::InputSelector {
+        match self {
+            Self::ABI => solx_standard_json::InputSelector::ABI,
+            Self::Assembly => solx_standard_json::InputSelector::EVMLegacyAssembly,
+            Self::AST => solx_standard_json::InputSelector::AST,
+            Self::Bytecode => solx_standard_json::InputSelector::BytecodeObject,
+            Self::RuntimeBytecode => solx_standard_json::InputSelector::RuntimeBytecodeObject,
+            Self::DeveloperDocumentation => {
+                solx_standard_json::InputSelector::DeveloperDocumentation
+            }
+            Self::FunctionDebugData => solx_standard_json::InputSelector::BytecodeFunctionDebugData,
+            Self::RuntimeFunctionDebugData => {
+                solx_standard_json::InputSelector::RuntimeBytecodeFunctionDebugData
+            }
+            Self::GeneratedSources => solx_standard_json::InputSelector::BytecodeGeneratedSources,
+            Self::RuntimeGeneratedSources => {
+                solx_standard_json::InputSelector::RuntimeBytecodeGeneratedSources
+            }
+            Self::Hashes => solx_standard_json::InputSelector::MethodIdentifiers,
+            Self::Metadata => solx_standard_json::InputSelector::Metadata,
+            Self::Opcodes => solx_standard_json::InputSelector::BytecodeOpcodes,
+            Self::SourceMap => solx_standard_json::InputSelector::BytecodeSourceMap,
+            Self::RuntimeSourceMap => solx_standard_json::InputSelector::RuntimeBytecodeSourceMap,
+            Self::StorageLayout => solx_standard_json::InputSelector::StorageLayout,
+            Self::TransientStorageLayout => {
+                solx_standard_json::InputSelector::TransientStorageLayout
+            }
+            Self::UserDocumentation => solx_standard_json::InputSelector::UserDocumentation,
+        }
+    }
+}
+
+impl FromStr for Selector {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string {
+            "abi" => Ok(Self::ABI),
+            "asm" => Ok(Self::Assembly),
+            "ast" => Ok(Self::AST),
+            "bin" => Ok(Self::Bytecode),
+            "bin-runtime" => Ok(Self::RuntimeBytecode),
+            "devdoc" => Ok(Self::DeveloperDocumentation),
+            "function-debug" => Ok(Self::FunctionDebugData),
+            "function-debug-runtime" => Ok(Self::RuntimeFunctionDebugData),
+            "generated-sources" => Ok(Self::GeneratedSources),
+            "generated-sources-runtime" => Ok(Self::RuntimeGeneratedSources),
+            "hashes" => Ok(Self::Hashes),
+            "metadata" => Ok(Self::Metadata),
+            "opcodes" => Ok(Self::Opcodes),
+            "srcmap" => Ok(Self::SourceMap),
+            "srcmap-runtime" => Ok(Self::RuntimeSourceMap),
+            "storage-layout" => Ok(Self::StorageLayout),
+            "transient-storage-layout" => Ok(Self::TransientStorageLayout),
+            "userdoc" => Ok(Self::UserDocumentation),
+            string => anyhow::bail!("Invalid combined JSON selector `{string}`."),
+        }
+    }
+}
+
+impl std::fmt::Display for Selector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ABI => write!(f, "abi"),
+            Self::Assembly => write!(f, "asm"),
+            Self::AST => write!(f, "ast"),
+            Self::Bytecode => write!(f, "bin"),
+            Self::RuntimeBytecode => write!(f, "bin-runtime"),
+            Self::DeveloperDocumentation => write!(f, "devdoc"),
+            Self::FunctionDebugData => write!(f, "function-debug"),
+            Self::RuntimeFunctionDebugData => write!(f, "function-debug-runtime"),
+            Self::GeneratedSources => write!(f, "generated-sources"),
+            Self::RuntimeGeneratedSources => write!(f, "generated-sources-runtime"),
+            Self::Hashes => write!(f, "hashes"),
+            Self::Metadata => write!(f, "metadata"),
+            Self::Opcodes => write!(f, "opcodes"),
+            Self::SourceMap => write!(f, "srcmap"),
+            Self::RuntimeSourceMap => write!(f, "srcmap-runtime"),
+            Self::StorageLayout => write!(f, "storage-layout"),
+            Self::TransientStorageLayout => write!(f, "transient-storage-layout"),
+            Self::UserDocumentation => write!(f, "userdoc"),
+        }
+    }
+}
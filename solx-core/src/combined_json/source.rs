@@ -0,0 +1,24 @@
+//!
+//! The `solc --combined-json` output source.
+//!
+
+///
+/// The `solc --combined-json` output source.
+///
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct Source {
+    /// The source file AST.
+    #[serde(rename = "AST")]
+    pub ast: serde_json::Value,
+    /// The source file identifier, which is its index in the source list.
+    pub id: usize,
+}
+
+impl Source {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(ast: serde_json::Value, id: usize) -> Self {
+        Self { ast, id }
+    }
+}
@@ -2,6 +2,7 @@
 
 use std::collections::BTreeSet;
 use std::io::Write;
+use std::path::Path;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::Mutex;
@@ -15,6 +16,9 @@ use crate::EVMBuild;
 use crate::Frontend;
 use crate::Project;
 use crate::WORKER_THREAD_STACK_SIZE;
+use crate::arguments::command::Command;
+use crate::verification::Verification;
+use crate::verification::metadata::Metadata as VerificationMetadata;
 
 /// Orchestrates compilation from Solidity sources to EVM bytecode.
 pub struct Compiler<'arguments> {
@@ -32,8 +36,10 @@ impl<'arguments> Compiler<'arguments> {
     /// Initialize the compiler runtime: LLVM stack trace, EVM target, and
     /// rayon thread pool.
     ///
-    /// If `arguments.recursive_process` is set, runs the worker subprocess loop and
-    /// returns `Ok(true)` -- the caller should return immediately.
+    /// If `arguments.recursive_process` is set, runs the worker subprocess loop, or the worker
+    /// server if `arguments.listen` is also set, and returns `Ok(true)` -- the caller should
+    /// return immediately.
+    /// The same applies to `arguments.replay`, which replays a crash reproducer bundle.
     /// Otherwise returns `Ok(false)`.
     ///
     /// The rayon thread pool is built after the worker branch: workers compile
@@ -44,9 +50,19 @@ impl<'arguments> Compiler<'arguments> {
         solx_codegen_evm::initialize_target();
 
         if self.arguments.recursive_process {
-            crate::run_subprocess()?;
+            match self.arguments.listen {
+                Some(ref address) => crate::process::transport::server::run(address)?,
+                None => crate::run_subprocess()?,
+            }
+            return Ok(true);
+        }
+        if let Some(bundle) = self.arguments.replay.as_deref() {
+            crate::EVMProcessReproducer::replay(bundle)?;
             return Ok(true);
         }
+        if !self.arguments.remote_worker.is_empty() {
+            let _ = crate::process::REMOTE_WORKERS.set(self.arguments.remote_worker.clone());
+        }
 
         let mut thread_pool_builder = rayon::ThreadPoolBuilder::new();
         if let Some(threads) = self.arguments.threads {
@@ -80,6 +96,8 @@ impl<'arguments> Compiler<'arguments> {
         let optimizer_settings = self.arguments.optimizer_settings()?;
         let output_selection = self.arguments.output_selection();
         let llvm_options = self.arguments.llvm_options();
+        let warning_rules = self.arguments.warning_rules();
+        let job_limits = self.arguments.job_limits();
 
         let output_config = self.arguments.output_config()?;
 
@@ -89,6 +107,7 @@ impl<'arguments> Compiler<'arguments> {
             .unwrap_or(solx_utils::MetadataHashType::IPFS);
         let append_cbor = !self.arguments.no_cbor_metadata;
         let use_import_callback = !self.arguments.no_import_callback;
+        let frontend_version = frontend.version().long.clone();
 
         let build = if self.arguments.yul {
             self.yul_to_evm(
@@ -102,6 +121,8 @@ impl<'arguments> Compiler<'arguments> {
                 append_cbor,
                 optimizer_settings,
                 llvm_options,
+                warning_rules,
+                job_limits,
                 output_config,
             )
         } else if self.arguments.llvm_ir {
@@ -115,8 +136,17 @@ impl<'arguments> Compiler<'arguments> {
                 append_cbor,
                 optimizer_settings,
                 llvm_options,
+                warning_rules,
+                job_limits,
                 output_config,
             )
+        } else if self.arguments.link_objects {
+            self.link_objects_to_evm(
+                input_files.as_slice(),
+                self.arguments.libraries.as_slice(),
+                messages,
+                warning_rules,
+            )
         } else if let Some(ref standard_json) = self.arguments.standard_json {
             return self.standard_json_evm(
                 frontend,
@@ -128,6 +158,24 @@ impl<'arguments> Compiler<'arguments> {
                 use_import_callback,
                 output_config,
             );
+        } else if let Some(Command::Verify {
+            ref metadata,
+            ref bytecode,
+        }) = self.arguments.command
+        {
+            return self.verify(
+                frontend,
+                metadata.as_path(),
+                bytecode.as_str(),
+                messages,
+                self.arguments.base_path.clone(),
+                self.arguments.include_path.clone(),
+                self.arguments.allow_paths.clone(),
+                use_import_callback,
+                optimizer_settings,
+                llvm_options,
+                job_limits,
+            );
         } else {
             self.standard_output_evm(
                 frontend,
@@ -147,6 +195,8 @@ impl<'arguments> Compiler<'arguments> {
                 remappings,
                 optimizer_settings,
                 llvm_options,
+                warning_rules,
+                job_limits,
                 output_config,
             )
         }?;
@@ -168,7 +218,22 @@ impl<'arguments> Compiler<'arguments> {
             build
         };
 
-        if let Some(ref output_directory) = self.arguments.output_dir {
+        if self.arguments.emit_obj {
+            build.write_to_object_files(
+                self.arguments
+                    .output_dir
+                    .as_deref()
+                    .expect("Validated by the arguments"),
+                self.arguments.overwrite,
+            )?;
+        } else if let Some(ref selectors) = self.arguments.combined_json {
+            build.write_to_combined_json(
+                selectors.as_slice(),
+                frontend_version,
+                self.arguments.output_dir.as_deref(),
+                self.arguments.overwrite,
+            )?;
+        } else if let Some(ref output_directory) = self.arguments.output_dir {
             build.write_to_directory(
                 output_directory,
                 &output_selection,
@@ -196,6 +261,8 @@ impl<'arguments> Compiler<'arguments> {
         append_cbor: bool,
         optimizer_settings: solx_codegen_evm::OptimizerSettings,
         llvm_options: Vec<String>,
+        warning_rules: solx_standard_json::InputWarnings,
+        job_limits: solx_standard_json::InputLimits,
         output_config: Option<solx_codegen_evm::OutputConfig>,
     ) -> anyhow::Result<EVMBuild>
     where
@@ -221,20 +288,23 @@ impl<'arguments> Compiler<'arguments> {
             output_config.as_ref(),
         )?;
 
-        let mut build = project.compile_to_evm(
-            messages,
-            output_selection,
-            evm_version,
-            metadata_hash_type,
-            append_cbor,
-            optimizer_settings,
-            llvm_options,
-            output_config,
-        )?;
+        let mut build = project
+            .compile_to_evm(
+                messages,
+                output_selection,
+                evm_version,
+                metadata_hash_type,
+                append_cbor,
+                optimizer_settings,
+                llvm_options,
+                output_config,
+                job_limits,
+            )?
+            .with_warning_rules(warning_rules);
         build.take_and_write_warnings();
         build.check_errors()?;
 
-        Ok(if output_selection.is_bytecode_set_for_any() {
+        Ok(if self.is_linking_required(output_selection) {
             let mut build = build.link(linker_symbols);
             build.take_and_write_warnings();
             build.check_errors()?;
@@ -258,6 +328,8 @@ impl<'arguments> Compiler<'arguments> {
         append_cbor: bool,
         optimizer_settings: solx_codegen_evm::OptimizerSettings,
         llvm_options: Vec<String>,
+        warning_rules: solx_standard_json::InputWarnings,
+        job_limits: solx_standard_json::InputLimits,
         output_config: Option<solx_codegen_evm::OutputConfig>,
     ) -> anyhow::Result<EVMBuild> {
         if output_selection.is_debug_info_set_for_any() {
@@ -271,20 +343,23 @@ impl<'arguments> Compiler<'arguments> {
 
         let project = Project::try_from_llvm_ir_paths(paths, libraries, output_selection, None)?;
 
-        let mut build = project.compile_to_evm(
-            messages,
-            output_selection,
-            evm_version,
-            metadata_hash_type,
-            append_cbor,
-            optimizer_settings,
-            llvm_options,
-            output_config,
-        )?;
+        let mut build = project
+            .compile_to_evm(
+                messages,
+                output_selection,
+                evm_version,
+                metadata_hash_type,
+                append_cbor,
+                optimizer_settings,
+                llvm_options,
+                output_config,
+                job_limits,
+            )?
+            .with_warning_rules(warning_rules);
         build.take_and_write_warnings();
         build.check_errors()?;
 
-        Ok(if output_selection.is_bytecode_set_for_any() {
+        Ok(if self.is_linking_required(output_selection) {
             let mut build = build.link(linker_symbols);
             build.take_and_write_warnings();
             build.check_errors()?;
@@ -294,6 +369,27 @@ impl<'arguments> Compiler<'arguments> {
         })
     }
 
+    ///
+    /// Runs the object linking mode for the EVM target.
+    ///
+    pub fn link_objects_to_evm(
+        &self,
+        paths: &[PathBuf],
+        libraries: &[String],
+        messages: Arc<Mutex<Vec<solx_standard_json::OutputError>>>,
+        warning_rules: solx_standard_json::InputWarnings,
+    ) -> anyhow::Result<EVMBuild> {
+        let libraries = solx_utils::Libraries::try_from(libraries)?;
+        let linker_symbols = libraries.as_linker_symbols()?;
+
+        let build =
+            EVMBuild::try_from_object_files(paths, messages)?.with_warning_rules(warning_rules);
+        let mut build = build.link(linker_symbols);
+        build.take_and_write_warnings();
+        build.check_errors()?;
+        Ok(build)
+    }
+
     ///
     /// Runs the standard output mode for the EVM target.
     ///
@@ -316,13 +412,13 @@ impl<'arguments> Compiler<'arguments> {
         remappings: BTreeSet<String>,
         optimizer_settings: solx_codegen_evm::OptimizerSettings,
         llvm_options: Vec<String>,
+        warning_rules: solx_standard_json::InputWarnings,
+        job_limits: solx_standard_json::InputLimits,
         output_config: Option<solx_codegen_evm::OutputConfig>,
     ) -> anyhow::Result<EVMBuild>
     where
         F: Frontend,
     {
-        let mut profiler = solx_codegen_evm::Profiler::default();
-
         let mut solc_input = solx_standard_json::Input::try_from_solidity_paths(
             paths,
             libraries,
@@ -336,8 +432,51 @@ impl<'arguments> Compiler<'arguments> {
                 append_cbor,
                 metadata_hash_type,
             ),
-            llvm_options.clone(),
+            llvm_options,
         )?;
+        solc_input.settings.warnings = warning_rules;
+        solc_input.settings.limits = job_limits;
+
+        self.standard_input_evm(
+            frontend,
+            solc_input,
+            messages,
+            base_path,
+            include_paths,
+            allow_paths,
+            use_import_callback,
+            optimizer_settings,
+            output_config,
+        )
+    }
+
+    ///
+    /// Compiles the Solidity standard JSON input built by other modes.
+    ///
+    /// Unlike the standard JSON mode, returns the linked build instead of printing it.
+    ///
+    pub fn standard_input_evm<F>(
+        &self,
+        frontend: F,
+        mut solc_input: solx_standard_json::Input,
+        messages: Arc<Mutex<Vec<solx_standard_json::OutputError>>>,
+        base_path: Option<String>,
+        include_paths: Vec<String>,
+        allow_paths: Option<String>,
+        use_import_callback: bool,
+        optimizer_settings: solx_codegen_evm::OptimizerSettings,
+        output_config: Option<solx_codegen_evm::OutputConfig>,
+    ) -> anyhow::Result<EVMBuild>
+    where
+        F: Frontend,
+    {
+        let mut profiler = solx_codegen_evm::Profiler::default();
+
+        let via_ir = solc_input.settings.via_ir;
+        let evm_version = solc_input.settings.evm_version;
+        let metadata_hash_type = solc_input.settings.metadata.bytecode_hash;
+        let append_cbor = solc_input.settings.metadata.append_cbor;
+        let llvm_options = solc_input.settings.llvm_options.clone();
 
         let run_solc_standard_json = profiler.start_pipeline_element("solc_Solidity_Standard_JSON");
         let mut solc_output = frontend.standard_json(
@@ -353,7 +492,11 @@ impl<'arguments> Compiler<'arguments> {
 
         let linker_symbols = solc_input.settings.libraries.as_linker_symbols()?;
         solc_input.resolve_sources()?;
-        let debug_info = if output_selection.is_debug_info_emitted_for_any() {
+        let debug_info = if solc_input
+            .settings
+            .output_selection
+            .is_debug_info_emitted_for_any()
+        {
             Some(solc_output.get_debug_info(&solc_input.sources))
         } else {
             None
@@ -374,25 +517,24 @@ impl<'arguments> Compiler<'arguments> {
         solc_output.check_errors()?;
 
         let run_solx_compile = profiler.start_pipeline_element("solx_Compilation");
-        let mut build = project.compile_to_evm(
-            messages,
-            &solc_input.settings.output_selection,
-            evm_version,
-            metadata_hash_type,
-            append_cbor,
-            optimizer_settings.clone(),
-            llvm_options,
-            output_config.clone(),
-        )?;
+        let mut build = project
+            .compile_to_evm(
+                messages,
+                &solc_input.settings.output_selection,
+                evm_version,
+                metadata_hash_type,
+                append_cbor,
+                optimizer_settings.clone(),
+                llvm_options,
+                output_config.clone(),
+                solc_input.settings.limits.clone(),
+            )?
+            .with_warning_rules(std::mem::take(&mut solc_input.settings.warnings));
         run_solx_compile.borrow_mut().finish();
         build.take_and_write_warnings();
         build.check_errors()?;
 
-        let mut build = if solc_input
-            .settings
-            .output_selection
-            .is_bytecode_set_for_any()
-        {
+        let mut build = if self.is_linking_required(&solc_input.settings.output_selection) {
             let run_solx_link = profiler.start_pipeline_element("solx_Linking");
             let mut build = build.link(linker_symbols);
             run_solx_link.borrow_mut().finish();
@@ -423,15 +565,48 @@ impl<'arguments> Compiler<'arguments> {
     where
         F: Frontend,
     {
-        let mut solc_input = solx_standard_json::Input::try_from(json_path.as_deref())?;
+        let mut solc_input = match solx_standard_json::Input::try_from_validated(
+            json_path.as_deref(),
+            &mut messages
+                .lock()
+                .expect("lock is never poisoned because worker threads do not panic"),
+        )? {
+            Some(solc_input) => solc_input,
+            None => return Ok(()),
+        };
+        if let Some(ref source_archive) = self.arguments.source_archive {
+            let content_addressed_resolver =
+                solx_standard_json::InputSourceContentAddressedResolver::new(
+                    source_archive.to_owned(),
+                );
+            solc_input.resolve_sources_with(&[
+                &solx_standard_json::InputSourceFileSystemResolver,
+                &content_addressed_resolver,
+            ])?;
+        }
         let language = solc_input.language;
         let via_ir = solc_input.settings.via_ir;
         let linker_symbols = solc_input.settings.libraries.as_linker_symbols()?;
 
-        let optimizer_settings = solx_codegen_evm::OptimizerSettings::try_from_mode(
+        let mut optimizer_settings = solx_codegen_evm::OptimizerSettings::try_from_mode(
             solc_input.settings.optimizer.mode,
             solc_input.settings.optimizer.size_fallback,
         )?;
+        if let Some(runs) = solc_input.settings.optimizer.runs {
+            optimizer_settings.set_expected_runs(runs);
+            if solc_input.settings.optimizer.size_for_low_runs == Some(true)
+                && let Some(mode) = optimizer_settings.apply_expected_runs()
+            {
+                messages
+                    .lock()
+                    .expect("lock is never poisoned because worker threads do not panic")
+                    .push(solx_standard_json::OutputError::new_warning(format!(
+                        "The optimizer `runs` value {runs} is below {}, so the optimization mode is switched from `{}` to `{mode}`.",
+                        solx_codegen_evm::OptimizerSettings::SIZE_EXPECTED_RUNS_THRESHOLD,
+                        solx_codegen_evm::OptimizerSettings::DEFAULT_OPTIMIZATION_MODE,
+                    )));
+            }
+        }
         let llvm_options = solc_input.settings.llvm_options.clone();
 
         let metadata_hash_type = solc_input.settings.metadata.bytecode_hash;
@@ -463,6 +638,7 @@ impl<'arguments> Compiler<'arguments> {
                 };
 
                 if solc_output.has_errors() {
+                    solc_output.take_messages(&messages);
                     solc_output.write_and_exit(&solc_input.settings.output_selection);
                 }
                 messages
@@ -482,6 +658,7 @@ impl<'arguments> Compiler<'arguments> {
                 )?;
                 run_solx_project.borrow_mut().finish();
                 if solc_output.has_errors() {
+                    solc_output.take_messages(&messages);
                     solc_output.write_and_exit(&solc_input.settings.output_selection);
                 }
 
@@ -502,6 +679,7 @@ impl<'arguments> Compiler<'arguments> {
                 let mut solc_output = frontend.validate_yul_standard_json(&mut solc_input)?;
                 run_solc_validate_yul.borrow_mut().finish();
                 if solc_output.has_errors() {
+                    solc_output.take_messages(&messages);
                     solc_output.write_and_exit(&solc_input.settings.output_selection);
                 }
 
@@ -516,6 +694,7 @@ impl<'arguments> Compiler<'arguments> {
                 )?;
                 run_solx_yul_project.borrow_mut().finish();
                 if solc_output.has_errors() {
+                    solc_output.take_messages(&messages);
                     solc_output.write_and_exit(&solc_input.settings.output_selection);
                 }
 
@@ -544,6 +723,7 @@ impl<'arguments> Compiler<'arguments> {
                 )?;
                 run_solx_llvm_ir_project.borrow_mut().finish();
                 if solc_output.has_errors() {
+                    solc_output.take_messages(&messages);
                     solc_output.write_and_exit(&solc_input.settings.output_selection);
                 }
 
@@ -552,16 +732,19 @@ impl<'arguments> Compiler<'arguments> {
         };
 
         let run_solx_compile = profiler.start_pipeline_element("solx_Compilation");
-        let build = project.compile_to_evm(
-            messages,
-            &solc_input.settings.output_selection,
-            solc_input.settings.evm_version,
-            metadata_hash_type,
-            append_cbor,
-            optimizer_settings.clone(),
-            llvm_options,
-            output_config.clone(),
-        )?;
+        let mut build = project
+            .compile_to_evm(
+                messages,
+                &solc_input.settings.output_selection,
+                solc_input.settings.evm_version,
+                metadata_hash_type,
+                append_cbor,
+                optimizer_settings.clone(),
+                llvm_options,
+                output_config.clone(),
+                solc_input.settings.limits.clone(),
+            )?
+            .with_warning_rules(std::mem::take(&mut solc_input.settings.warnings));
         run_solx_compile.borrow_mut().finish();
         let output_selection = solc_input.settings.output_selection.clone();
         if build.has_errors() {
@@ -573,6 +756,23 @@ impl<'arguments> Compiler<'arguments> {
             )?;
             solc_output.write_and_exit(&solc_input.settings.output_selection);
         }
+        if let Some(format) = self.arguments.standard_json_stream {
+            let (build, linker_symbols) = if output_selection.is_bytecode_set_for_any() {
+                let run_solx_link = profiler.start_pipeline_element("solx_Linking");
+                let build = build.assemble(&linker_symbols);
+                run_solx_link.borrow_mut().finish();
+                (build, Some(&linker_symbols))
+            } else {
+                (build, None)
+            };
+            build.write_to_standard_json_stream(
+                solc_output,
+                &output_selection,
+                linker_symbols,
+                profiler.to_vec(),
+                format,
+            );
+        }
         let build = if output_selection.is_bytecode_set_for_any() {
             let run_solx_link = profiler.start_pipeline_element("solx_Linking");
             let build = build.link(linker_symbols);
@@ -590,6 +790,148 @@ impl<'arguments> Compiler<'arguments> {
         solc_output.write_and_exit(&output_selection);
     }
 
+    ///
+    /// Runs the verification mode for the EVM target.
+    ///
+    /// The metadata hash type and CBOR presence are detected from the deployed bytecode unless
+    /// they are set explicitly via the CLI. The optimizer settings and LLVM options recorded by
+    /// `solx` in the metadata take precedence over the ones set via the CLI.
+    ///
+    pub fn verify<F>(
+        &self,
+        frontend: F,
+        metadata_path: &Path,
+        bytecode: &str,
+        messages: Arc<Mutex<Vec<solx_standard_json::OutputError>>>,
+        base_path: Option<String>,
+        include_paths: Vec<String>,
+        allow_paths: Option<String>,
+        use_import_callback: bool,
+        optimizer_settings: solx_codegen_evm::OptimizerSettings,
+        llvm_options: Vec<String>,
+        job_limits: solx_standard_json::InputLimits,
+    ) -> anyhow::Result<()>
+    where
+        F: Frontend,
+    {
+        let mut metadata = VerificationMetadata::try_from_path(metadata_path)?;
+        let contract_name = metadata.compilation_target()?;
+
+        let bytecode = if Path::new(bytecode).is_file() {
+            std::fs::read_to_string(bytecode)
+                .map_err(|error| anyhow::anyhow!("Bytecode file `{bytecode}` reading: {error}"))?
+        } else {
+            bytecode.to_owned()
+        };
+        let bytecode = bytecode.trim();
+        let deployed_bytecode = hex::decode(bytecode.strip_prefix("0x").unwrap_or(bytecode))
+            .map_err(|error| anyhow::anyhow!("Invalid deployed bytecode: {error}"))?;
+
+        let solc_version = metadata.solc_version()?;
+        if solc_version != frontend.version().default {
+            messages
+                .lock()
+                .expect("lock is never poisoned because worker threads do not panic")
+                .push(solx_standard_json::OutputError::new_warning(format!(
+                    "The metadata was produced with solc v{solc_version}, but solc v{} is used for verification.",
+                    frontend.version().default,
+                )));
+        }
+        if let Some(solx_version) = metadata.solx.as_ref().map(|solx| &solx.solx_version)
+            && solx_version.to_string() != Self::version()
+        {
+            messages
+                .lock()
+                .expect("lock is never poisoned because worker threads do not panic")
+                .push(solx_standard_json::OutputError::new_warning(format!(
+                    "The metadata was produced with {DEFAULT_EXECUTABLE_NAME} v{solx_version}, but {DEFAULT_EXECUTABLE_NAME} v{} is used for verification.",
+                    Self::version(),
+                )));
+        }
+
+        let (_, cbor_payload) = Verification::split_cbor(deployed_bytecode.as_slice());
+        let append_cbor = if self.arguments.no_cbor_metadata {
+            false
+        } else {
+            cbor_payload.is_some()
+        };
+        let metadata_hash_type = self.arguments.metadata_hash.unwrap_or_else(|| {
+            cbor_payload
+                .map(Verification::metadata_hash_type)
+                .unwrap_or(solx_utils::MetadataHashType::IPFS)
+        });
+
+        let output_selection = solx_standard_json::InputSelection::new(BTreeSet::from([
+            solx_standard_json::InputSelector::BytecodeObject,
+            solx_standard_json::InputSelector::RuntimeBytecodeObject,
+        ]));
+        let (optimizer_settings, llvm_options) = match metadata.solx.take() {
+            Some(solx) => (solx.optimizer_settings, solx.llvm_options),
+            None => (optimizer_settings, llvm_options),
+        };
+        let mut solc_input = metadata.try_into_input(
+            base_path.as_deref(),
+            &output_selection,
+            metadata_hash_type,
+            append_cbor,
+            &optimizer_settings,
+            llvm_options,
+        )?;
+        solc_input.settings.limits = job_limits;
+
+        let mut build = self.standard_input_evm(
+            frontend,
+            solc_input,
+            messages,
+            base_path,
+            include_paths,
+            allow_paths,
+            use_import_callback,
+            optimizer_settings,
+            None,
+        )?;
+        build.take_and_write_warnings();
+
+        let contract = build
+            .contracts
+            .remove(contract_name.full_path.as_str())
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Contract `{}` is missing from the build.",
+                    contract_name.full_path
+                )
+            })?;
+        let runtime_object = match contract.runtime_object_result {
+            Some(Ok(object)) => object,
+            Some(Err(error)) => anyhow::bail!(error),
+            None => anyhow::bail!(
+                "Contract `{}` has no runtime code.",
+                contract_name.full_path
+            ),
+        };
+        let compiled_bytecode = runtime_object
+            .bytecode
+            .as_deref()
+            .expect("Always exists after linking");
+
+        let verification = Verification::compare(
+            deployed_bytecode.as_slice(),
+            compiled_bytecode,
+            runtime_object.immutables.as_ref(),
+        );
+        writeln!(
+            std::io::stdout(),
+            "{}: {verification}",
+            contract_name.full_path
+        )?;
+        if verification == Verification::Mismatch {
+            anyhow::bail!(
+                "The deployed bytecode does not match the bytecode compiled from the metadata."
+            );
+        }
+        Ok(())
+    }
+
     ///
     /// Prints the compiler version information to stdout.
     ///
@@ -608,6 +950,15 @@ impl<'arguments> Compiler<'arguments> {
         Ok(())
     }
 
+    ///
+    /// Whether the build must be linked for `output_selection`.
+    ///
+    /// Relocatable objects emitted with `--emit-obj` are left unlinked.
+    ///
+    fn is_linking_required(&self, output_selection: &solx_standard_json::InputSelection) -> bool {
+        output_selection.is_bytecode_set_for_any() && !self.arguments.emit_obj
+    }
+
     /// Returns the compiler version string from the package metadata.
     pub fn version() -> &'static str {
         env!("CARGO_PKG_VERSION")
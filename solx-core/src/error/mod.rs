@@ -17,6 +17,9 @@ pub enum Error {
     /// Standard JSON error.
     #[error("{0}")]
     StandardJson(solx_standard_json::OutputError),
+    /// Catalogued solx diagnostic.
+    #[error("{0}")]
+    Diagnostic(solx_utils::Diagnostic),
     /// Generic error.
     #[error("{0}")]
     Generic(String),
@@ -42,14 +45,40 @@ impl Error {
     pub fn into_standard_json(self, path: Option<&str>) -> solx_standard_json::OutputError {
         match self {
             Error::StandardJson(error) => error,
+            Error::Diagnostic(diagnostic) => {
+                solx_standard_json::OutputError::new_diagnostic(path, &diagnostic, None)
+            }
             error => solx_standard_json::OutputError::new_error_contract(path, error),
         }
     }
+
+    ///
+    /// Prepends `context` to the error message, keeping the diagnostic data if any.
+    ///
+    pub fn with_context<S>(self, context: S) -> Self
+    where
+        S: std::fmt::Display,
+    {
+        match self {
+            Error::Diagnostic(diagnostic) => Error::Diagnostic(diagnostic.with_context(context)),
+            Error::Generic(message) => Error::Generic(format!("{context}: {message}")),
+            error => error,
+        }
+    }
 }
 
 impl From<anyhow::Error> for Error {
     fn from(error: anyhow::Error) -> Self {
-        Error::Generic(error.to_string())
+        match error.downcast::<solx_utils::Diagnostic>() {
+            Ok(diagnostic) => Error::Diagnostic(diagnostic),
+            Err(error) => Error::Generic(error.to_string()),
+        }
+    }
+}
+
+impl From<solx_utils::Diagnostic> for Error {
+    fn from(diagnostic: solx_utils::Diagnostic) -> Self {
+        Error::Diagnostic(diagnostic)
     }
 }
 
@@ -12,29 +12,40 @@
 
 pub mod arguments;
 pub mod build;
+pub mod combined_json;
 pub mod compiler;
 pub mod r#const;
 pub mod error;
 pub mod frontend;
+pub mod object_file;
 pub mod process;
 pub mod project;
+pub mod verification;
 
 pub use self::arguments::Arguments;
 pub use self::build::Build as EVMBuild;
 pub use self::build::contract::Contract as EVMContractBuild;
+pub use self::combined_json::CombinedJson;
+pub use self::combined_json::contract::Contract as CombinedJsonContract;
+pub use self::combined_json::selector::Selector as CombinedJsonSelector;
+pub use self::combined_json::source::Source as CombinedJsonSource;
 pub use self::compiler::Compiler;
 pub use self::r#const::*;
 pub use self::error::Error;
 pub use self::error::stack_too_deep::StackTooDeep as StackTooDeepError;
 pub use self::frontend::Frontend;
+pub use self::object_file::ObjectFile;
+pub use self::object_file::segment::Segment as ObjectFileSegment;
 pub use self::process::EXECUTABLE;
 pub use self::process::child::run as run_subprocess;
 pub use self::process::job::Job as EVMProcessJob;
 pub use self::process::output::Output as EVMProcessOutput;
 pub use self::process::pool::Pool as EVMProcessPool;
+pub use self::process::reproducer::Reproducer as EVMProcessReproducer;
 pub use self::process::session::Session as EVMProcessSession;
 pub use self::project::Project;
 pub use self::project::contract::Contract as ProjectContract;
+pub use self::verification::Verification;
 
 /// The default error compatible with `solc` standard JSON output.
 pub type Result<T> = std::result::Result<T, Error>;
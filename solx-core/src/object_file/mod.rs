@@ -0,0 +1,107 @@
+//!
+//! The relocatable EVM object file manifest.
+//!
+
+pub mod segment;
+
+use std::path::Path;
+
+use self::segment::Segment;
+
+///
+/// The relocatable EVM object file manifest.
+///
+/// Written by `--emit-obj` next to the relocatable ELF objects of a contract, and describes
+/// the data required to assemble and link them later, which the ELF objects do not carry.
+///
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ObjectFile {
+    /// The solx version the objects were emitted by.
+    pub version: String,
+    /// The contract name.
+    pub contract_name: solx_utils::ContractName,
+    /// The deploy code object.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deploy: Option<Segment>,
+    /// The runtime code object.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub runtime: Option<Segment>,
+}
+
+impl ObjectFile {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(contract_name: solx_utils::ContractName) -> Self {
+        Self {
+            version: crate::Compiler::version().to_owned(),
+            contract_name,
+            deploy: None,
+            runtime: None,
+        }
+    }
+
+    ///
+    /// Reads the manifest from the file, checking that it was emitted by this solx version.
+    ///
+    pub fn try_from_path(path: &Path) -> anyhow::Result<Self> {
+        let manifest = std::fs::read(path)
+            .map_err(|error| anyhow::anyhow!("Object file manifest {path:?} reading: {error}"))?;
+        let object_file: Self = serde_json::from_slice(manifest.as_slice())
+            .map_err(|error| anyhow::anyhow!("Object file manifest {path:?} parsing: {error}"))?;
+        if object_file.version != crate::Compiler::version() {
+            anyhow::bail!(
+                "Object file manifest {path:?} was emitted by solx v{}, but can only be linked by the same version (v{}).",
+                object_file.version,
+                crate::Compiler::version(),
+            );
+        }
+        Ok(object_file)
+    }
+
+    ///
+    /// Reads the objects referenced by the manifest at `path` into an unlinked contract build.
+    ///
+    pub fn into_contract(self, path: &Path) -> anyhow::Result<crate::EVMContractBuild> {
+        let directory = path.parent().unwrap_or(Path::new(""));
+
+        let deploy_object = self
+            .deploy
+            .map(|segment| {
+                segment.into_object(
+                    self.contract_name.clone(),
+                    solx_utils::CodeSegment::Deploy,
+                    directory,
+                )
+            })
+            .transpose()?;
+        let runtime_object = self
+            .runtime
+            .map(|segment| {
+                segment.into_object(
+                    self.contract_name.clone(),
+                    solx_utils::CodeSegment::Runtime,
+                    directory,
+                )
+            })
+            .transpose()?;
+
+        Ok(crate::EVMContractBuild::new(
+            self.contract_name,
+            deploy_object.map(Ok),
+            runtime_object.map(Ok),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            #[cfg(feature = "mlir")]
+            None,
+        ))
+    }
+}
@@ -0,0 +1,100 @@
+//!
+//! The relocatable EVM object file manifest segment.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use crate::build::contract::object::Object as ContractObject;
+
+///
+/// The relocatable EVM object file manifest segment.
+///
+/// Describes either the deploy or the runtime code object.
+///
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Segment {
+    /// The object identifier, referenced by the dependent objects.
+    pub identifier: String,
+    /// The relocatable ELF object file name, relative to the manifest.
+    pub object: String,
+    /// The identifiers of the objects this object depends on.
+    pub dependencies: Vec<String>,
+    /// The immutables of the runtime code.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub immutables: Option<BTreeMap<String, BTreeSet<u64>>>,
+    /// The hexadecimal metadata bytes, appended to the runtime code at assembly.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<String>,
+    /// The hexadecimal debug info.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub debug_info: Option<String>,
+    /// Whether IR codegen was used.
+    pub via_ir: bool,
+}
+
+impl Segment {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(object: &ContractObject, object_file_name: String) -> Self {
+        Self {
+            identifier: object.identifier.to_owned(),
+            object: object_file_name,
+            dependencies: object.dependencies.inner.to_owned(),
+            immutables: object.immutables.to_owned(),
+            metadata: object.metadata_bytes.as_ref().map(hex::encode),
+            debug_info: object.debug_info.as_ref().map(hex::encode),
+            via_ir: object.via_ir,
+        }
+    }
+
+    ///
+    /// Reads the relocatable ELF object from `directory` into an unassembled contract object.
+    ///
+    pub fn into_object(
+        self,
+        contract_name: solx_utils::ContractName,
+        code_segment: solx_utils::CodeSegment,
+        directory: &Path,
+    ) -> anyhow::Result<ContractObject> {
+        let path = directory.join(self.object.as_str());
+        let bytecode = std::fs::read(path.as_path())
+            .map_err(|error| anyhow::anyhow!("Object file {path:?} reading: {error}"))?;
+        let metadata_bytes = self
+            .metadata
+            .map(hex::decode)
+            .transpose()
+            .map_err(|error| anyhow::anyhow!("Object `{}` metadata: {error}", self.identifier))?;
+        let debug_info = self
+            .debug_info
+            .map(hex::decode)
+            .transpose()
+            .map_err(|error| anyhow::anyhow!("Object `{}` debug info: {error}", self.identifier))?;
+
+        let mut dependencies = solx_codegen_evm::Dependencies::new(self.identifier.as_str());
+        dependencies.inner = self.dependencies;
+
+        Ok(ContractObject::new(
+            self.identifier,
+            contract_name,
+            None,
+            Some(bytecode),
+            debug_info,
+            None,
+            None,
+            None,
+            None,
+            self.via_ir,
+            code_segment,
+            self.immutables,
+            metadata_bytes,
+            dependencies,
+            false,
+            Vec::new(),
+            Vec::new(),
+        ))
+    }
+}
@@ -10,8 +10,8 @@ use crate::process::channel::FrameRead;
 use crate::process::channel::FrameWrite;
 use crate::process::job::Job;
 use crate::process::output::Output as EVMOutput;
+use crate::process::reply::Reply;
 use crate::process::session::Session;
-use crate::project::contract::Contract;
 
 ///
 /// Runs the worker loop on a dedicated stack-sized thread until `stdin` closes.
@@ -27,37 +27,17 @@ pub fn run() -> anyhow::Result<()> {
 
             inkwell::support::error_handling::install_stack_error_handler(evm_stack_error_handler);
             inkwell::support::error_handling::install_fatal_error_handler(llvm_fatal_error_handler);
+            solx_codegen_evm::Profiler::install_stage_observer(stage_observer);
 
             while let Some(job) = stdin.recv::<Job>()? {
-                solx_codegen_evm::IS_SIZE_FALLBACK.store(
-                    job.optimizer_settings.is_fallback_to_size_active(),
-                    Ordering::Relaxed,
-                );
-                let result = Contract::compile_to_evm(
-                    session.language,
-                    session.solc_version.clone(),
-                    job.contract_name.clone(),
-                    job.contract_ir,
-                    job.code_segment,
-                    session.evm_version,
-                    job.debug_info,
-                    &session.output_selection,
-                    job.immutables,
-                    job.metadata_bytes,
-                    job.optimizer_settings,
-                    session.llvm_options.clone(),
-                    session.output_config.clone(),
-                )
-                .map(EVMOutput::new)
-                .map_err(|error| match error {
-                    Error::Generic(error) => solx_standard_json::OutputError::new_error_contract(
-                        Some(job.contract_name.path.as_str()),
-                        error,
-                    )
-                    .into(),
+                let path = job.contract_name.path.clone();
+                let result = job.run(&session).map_err(|error| match error {
+                    error @ (Error::Generic(_) | Error::Diagnostic(_)) => {
+                        error.into_standard_json(Some(path.as_str())).into()
+                    }
                     error => error,
                 });
-                std::io::stdout().send(&result)?;
+                std::io::stdout().send(&Reply::Result(result))?;
             }
 
             unsafe { inkwell::support::shutdown_llvm() };
@@ -68,6 +48,15 @@ pub fn run() -> anyhow::Result<()> {
         .expect("Threading error")
 }
 
+///
+/// Reports the start of a pipeline stage to the parent, which names it if a limit is exceeded.
+///
+/// A failed write is ignored, as the subsequent result write reports it.
+///
+fn stage_observer(description: &str) {
+    let _ = std::io::stdout().send(&Reply::Stage(description.to_owned()));
+}
+
 ///
 /// Handles LLVM fatal errors, e.g. failed stackification of a recursive function.
 ///
@@ -78,9 +67,13 @@ pub fn run() -> anyhow::Result<()> {
 ///
 extern "C" fn llvm_fatal_error_handler(message: *const std::ffi::c_char) {
     let message = unsafe { std::ffi::CStr::from_ptr(message) }.to_string_lossy();
-    let result: crate::Result<EVMOutput> = Err(Error::Generic(format!("LLVM error: {message}")));
+    let result: crate::Result<EVMOutput> = Err(Error::Diagnostic(solx_utils::Diagnostic::new(
+        solx_utils::DiagnosticCode::LLVMFatalError,
+        solx_utils::DiagnosticComponent::LLVM,
+        format!("LLVM error: {message}"),
+    )));
     std::io::stdout()
-        .send(&result)
+        .send(&Reply::Result(result))
         .unwrap_or_else(|error| panic!("LLVM fatal error response writing error: {error}"));
     std::process::exit(solx_utils::EXIT_CODE_SUCCESS);
 }
@@ -99,7 +92,7 @@ unsafe extern "C" fn evm_stack_error_handler(spill_area_size: u64) {
         solx_codegen_evm::IS_SIZE_FALLBACK.load(Ordering::Relaxed),
     ));
     std::io::stdout()
-        .send(&result)
+        .send(&Reply::Result(result))
         .unwrap_or_else(|error| panic!("Stack-too-deep response writing error: {error}"));
     unsafe { inkwell::support::shutdown_llvm() };
     std::process::exit(solx_utils::EXIT_CODE_SUCCESS);
@@ -4,7 +4,11 @@
 
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
+use std::sync::atomic::Ordering;
 
+use crate::process::output::Output as EVMOutput;
+use crate::process::session::Session;
+use crate::project::contract::Contract;
 use crate::project::contract::ir::IR as ContractIR;
 
 ///
@@ -53,4 +57,30 @@ impl Job {
             optimizer_settings,
         }
     }
+
+    ///
+    /// Compiles the job in the current process with the `session` data.
+    ///
+    pub fn run(self, session: &Session) -> crate::Result<EVMOutput> {
+        solx_codegen_evm::IS_SIZE_FALLBACK.store(
+            self.optimizer_settings.is_fallback_to_size_active(),
+            Ordering::Relaxed,
+        );
+        Contract::compile_to_evm(
+            session.language,
+            session.solc_version.clone(),
+            self.contract_name,
+            self.contract_ir,
+            self.code_segment,
+            session.evm_version,
+            self.debug_info,
+            &session.output_selection,
+            self.immutables,
+            self.metadata_bytes,
+            self.optimizer_settings,
+            session.llvm_options.clone(),
+            session.output_config.clone(),
+        )
+        .map(EVMOutput::new)
+    }
 }
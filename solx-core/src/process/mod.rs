@@ -7,11 +7,29 @@ pub mod child;
 pub mod job;
 pub mod output;
 pub mod pool;
+pub mod reply;
+pub mod reproducer;
 pub mod session;
+pub mod transport;
 pub mod worker;
 
 use std::path::PathBuf;
 use std::sync::OnceLock;
 
+use self::transport::address::Address;
+
 /// The overridden executable name used when the compiler is run as a library.
 pub static EXECUTABLE: OnceLock<PathBuf> = OnceLock::new();
+
+/// The remote worker addresses jobs are dispatched to instead of local subprocesses.
+pub static REMOTE_WORKERS: OnceLock<Vec<Address>> = OnceLock::new();
+
+///
+/// Returns the worker executable path.
+///
+pub fn executable() -> PathBuf {
+    EXECUTABLE
+        .get()
+        .cloned()
+        .unwrap_or_else(|| std::env::current_exe().expect("Current executable path getting error"))
+}
@@ -4,11 +4,15 @@
 
 use std::path::PathBuf;
 use std::sync::Mutex;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
 
 use crate::error::Error;
 use crate::process::job::Job;
 use crate::process::output::Output as EVMOutput;
+use crate::process::reproducer::Reproducer;
 use crate::process::session::Session;
+use crate::process::transport::address::Address;
 use crate::process::worker::Worker;
 
 /// The lock-poisoning invariant shared by the idle-pool accessors.
@@ -20,13 +24,23 @@ const POISON: &str = "lock is never poisoned because worker threads do not panic
 /// A worker returns to the idle list after every job it survives — a success or a per-unit
 /// compile error alike — and is retired only by a transport failure or a `StackTooDeep` or
 /// LLVM-fatal reply, after which the child exits. The number of live workers never exceeds
-/// the number of dispatching threads.
+/// the number of dispatching threads. A worker exceeding the per-job resource limits is killed
+/// and retired as well, while the remaining jobs continue on other workers.
+///
+/// If remote worker addresses are configured, new workers are connected to them in turn
+/// instead of being spawned locally.
 ///
 pub struct Pool {
     /// The worker executable path.
     executable: PathBuf,
+    /// The remote worker addresses.
+    remote_workers: Vec<Address>,
+    /// The index of the remote worker address to connect to next.
+    next_remote_worker: AtomicUsize,
     /// The project-wide data sent to every spawned worker.
     session: Session,
+    /// The per-job resource limits.
+    limits: solx_standard_json::InputLimits,
     /// The idle workers available for checkout.
     idle: Mutex<Vec<Worker>>,
 }
@@ -35,16 +49,18 @@ impl Pool {
     ///
     /// Creates a pool that dispatches jobs of `session` to worker subprocesses.
     ///
-    pub fn new(session: Session) -> anyhow::Result<Self> {
-        let executable = crate::process::EXECUTABLE
-            .get()
-            .cloned()
-            .unwrap_or_else(|| {
-                std::env::current_exe().expect("Current executable path getting error")
-            });
+    /// Every job is constrained by `limits`.
+    ///
+    pub fn new(session: Session, limits: solx_standard_json::InputLimits) -> anyhow::Result<Self> {
         Ok(Self {
-            executable,
+            executable: crate::process::executable(),
+            remote_workers: crate::process::REMOTE_WORKERS
+                .get()
+                .cloned()
+                .unwrap_or_default(),
+            next_remote_worker: AtomicUsize::new(0),
             session,
+            limits,
             idle: Mutex::new(Vec::new()),
         })
     }
@@ -54,13 +70,22 @@ impl Pool {
     ///
     /// A worker that survives the job rejoins the pool, including after a per-unit compile error.
     /// A transport failure or a `StackTooDeep` or LLVM-fatal reply retires it instead.
+    /// LLVM fatal errors and worker failures also write a crash reproducer bundle, whose path
+    /// is appended to the error message.
     ///
     pub fn execute(&self, job: &Job) -> crate::Result<EVMOutput> {
         let mut worker = match self.idle.lock().expect(POISON).pop() {
             Some(worker) => worker,
-            None => Worker::spawn(self.executable.as_path(), &self.session)?,
+            None if self.remote_workers.is_empty() => {
+                Worker::spawn(self.executable.as_path(), &self.session)?
+            }
+            None => {
+                let index = self.next_remote_worker.fetch_add(1, Ordering::Relaxed);
+                let address = &self.remote_workers[index % self.remote_workers.len()];
+                Worker::connect(address, &self.session)?
+            }
         };
-        match worker.execute(job) {
+        match worker.execute(job, &self.limits) {
             Ok(output) => {
                 self.idle.lock().expect(POISON).push(worker);
                 Ok(output)
@@ -69,8 +94,44 @@ impl Pool {
                 if matches!(error, Error::StandardJson(_)) {
                     self.idle.lock().expect(POISON).push(worker);
                 }
-                Err(error)
+                Err(self.write_reproducer(job, error))
+            }
+        }
+    }
+
+    ///
+    /// Writes a crash reproducer bundle if `error` is an LLVM fatal error or a worker failure.
+    ///
+    /// The bundle path, or the reason it could not be written, is appended to the error message.
+    ///
+    fn write_reproducer(&self, job: &Job, error: Error) -> Error {
+        let is_crash = match error {
+            Error::Diagnostic(ref diagnostic) => {
+                diagnostic.code == solx_utils::DiagnosticCode::LLVMFatalError
+            }
+            Error::Generic(_) => true,
+            Error::StackTooDeep(_) | Error::StandardJson(_) => false,
+        };
+        if !is_crash {
+            return error;
+        }
+
+        let note = match Reproducer::write(&self.session, job, error.to_string().as_str()) {
+            Ok(reproducer) => format!(
+                "A crash reproducer has been written to {:?}.",
+                reproducer.path
+            ),
+            Err(reproducer_error) => {
+                format!("A crash reproducer could not be written: {reproducer_error}")
+            }
+        };
+        match error {
+            Error::Diagnostic(mut diagnostic) => {
+                diagnostic.message = format!("{}\n{note}", diagnostic.message);
+                Error::Diagnostic(diagnostic)
             }
+            Error::Generic(message) => Error::Generic(format!("{message}\n{note}")),
+            error => error,
         }
     }
 }
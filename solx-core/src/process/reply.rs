@@ -0,0 +1,18 @@
+//!
+//! The worker reply frame.
+//!
+
+use crate::process::output::Output as EVMOutput;
+
+///
+/// The worker reply frame.
+///
+/// A job is answered with any number of stage notifications followed by exactly one result.
+///
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub enum Reply {
+    /// A pipeline stage of the current job has started.
+    Stage(String),
+    /// The current job has finished.
+    Result(crate::Result<EVMOutput>),
+}
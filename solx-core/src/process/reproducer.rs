@@ -0,0 +1,185 @@
+//!
+//! The crash reproducer bundle.
+//!
+
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+use std::thread::Builder;
+
+use crate::process::job::Job;
+use crate::process::session::Session;
+
+///
+/// The crash reproducer bundle.
+///
+/// A directory with everything needed to rerun a single translation unit that crashed
+/// a worker: the session and job frames, the compiler version, and a README with the
+/// replay command line. The job is replayed in-process, so it can be run under a debugger.
+///
+#[derive(Debug)]
+pub struct Reproducer {
+    /// The bundle directory.
+    pub path: PathBuf,
+}
+
+impl Reproducer {
+    /// The crash directory environment variable.
+    pub const CRASH_DIRECTORY_ENV: &'static str = "SOLX_CRASH_DIR";
+
+    /// The default crash directory name under the temporary directory.
+    pub const DEFAULT_CRASH_DIRECTORY_NAME: &'static str = "solx-crashes";
+
+    /// The session frame file name.
+    pub const SESSION_FILE_NAME: &'static str = "session.cbor";
+
+    /// The job frame file name.
+    pub const JOB_FILE_NAME: &'static str = "job.cbor";
+
+    /// The compiler version file name.
+    pub const VERSION_FILE_NAME: &'static str = "VERSION";
+
+    /// The instructions file name.
+    pub const README_FILE_NAME: &'static str = "README.md";
+
+    ///
+    /// Writes a reproducer bundle for `job` that crashed with `error`.
+    ///
+    /// The bundle is created under `SOLX_CRASH_DIR`, or under the temporary directory if the
+    /// variable is not set.
+    ///
+    pub fn write(session: &Session, job: &Job, error: &str) -> anyhow::Result<Self> {
+        let crash_directory = std::env::var_os(Self::CRASH_DIRECTORY_ENV)
+            .map(PathBuf::from)
+            .unwrap_or_else(|| std::env::temp_dir().join(Self::DEFAULT_CRASH_DIRECTORY_NAME));
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|duration| duration.as_nanos())
+            .unwrap_or_default();
+        let path = crash_directory.join(format!(
+            "{}.{}.{timestamp}",
+            solx_utils::ContractName::sanitize_path(job.contract_name.full_path.as_str()),
+            job.code_segment,
+        ));
+        std::fs::create_dir_all(path.as_path())
+            .map_err(|error| anyhow::anyhow!("Crash directory {path:?} creating: {error}"))?;
+
+        Self::write_cbor(path.join(Self::SESSION_FILE_NAME).as_path(), session)?;
+        Self::write_cbor(path.join(Self::JOB_FILE_NAME).as_path(), job)?;
+        Self::write_file(
+            path.join(Self::VERSION_FILE_NAME).as_path(),
+            crate::Compiler::version(),
+        )?;
+        let readme = format!(
+            r#"# {executable} crash reproducer
+
+Contract: `{contract}` ({code_segment} code)
+Version: {executable} v{version}
+
+## Error
+
+```
+{error}
+```
+
+## Replay
+
+The job is compiled in-process, so it can be run under a debugger:
+
+```shell
+{executable} --replay '{path}'
+gdb --args {executable} --replay '{path}'
+```
+
+The bundle contains the full input IR of the contract. Check that it may be shared
+before attaching it to a bug report.
+"#,
+            executable = crate::DEFAULT_EXECUTABLE_NAME,
+            contract = job.contract_name.full_path,
+            code_segment = job.code_segment,
+            version = crate::Compiler::version(),
+            error = error.trim(),
+            path = path.to_string_lossy(),
+        );
+        Self::write_file(path.join(Self::README_FILE_NAME).as_path(), readme)?;
+
+        Ok(Self { path })
+    }
+
+    ///
+    /// Replays the job from the bundle at `path` in the current process.
+    ///
+    pub fn replay(path: &Path) -> anyhow::Result<()> {
+        let version = std::fs::read_to_string(path.join(Self::VERSION_FILE_NAME))
+            .map_err(|error| anyhow::anyhow!("Reproducer {path:?} reading: {error}"))?;
+        if version.trim() != crate::Compiler::version() {
+            writeln!(
+                std::io::stderr(),
+                "Warning: The reproducer was written by {executable} v{}, but {executable} v{} is replaying it.",
+                version.trim(),
+                crate::Compiler::version(),
+                executable = crate::DEFAULT_EXECUTABLE_NAME,
+            )?;
+        }
+        let session: Session = Self::read_cbor(path.join(Self::SESSION_FILE_NAME).as_path())?;
+        let job: Job = Self::read_cbor(path.join(Self::JOB_FILE_NAME).as_path())?;
+        let contract = format!(
+            "{} ({} code)",
+            job.contract_name.full_path, job.code_segment
+        );
+
+        let result = Builder::new()
+            .stack_size(crate::WORKER_THREAD_STACK_SIZE)
+            .spawn(move || job.run(&session).map(|_| ()))
+            .expect("Threading error")
+            .join()
+            .expect("Threading error");
+        match result {
+            Ok(()) => {
+                writeln!(
+                    std::io::stderr(),
+                    "Replay of `{contract}` finished without errors."
+                )?;
+                Ok(())
+            }
+            Err(error) => anyhow::bail!("Replay of `{contract}` failed: {error}"),
+        }
+    }
+
+    ///
+    /// Serializes `value` into a CBOR file.
+    ///
+    fn write_cbor<T>(path: &Path, value: &T) -> anyhow::Result<()>
+    where
+        T: serde::Serialize,
+    {
+        let mut buffer = Vec::with_capacity(crate::DEFAULT_SERDE_BUFFER_SIZE);
+        ciborium::into_writer(value, &mut buffer)
+            .map_err(|error| anyhow::anyhow!("Reproducer {path:?} serializing: {error}"))?;
+        Self::write_file(path, buffer)
+    }
+
+    ///
+    /// Deserializes a CBOR file.
+    ///
+    fn read_cbor<T>(path: &Path) -> anyhow::Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let buffer = std::fs::read(path)
+            .map_err(|error| anyhow::anyhow!("Reproducer {path:?} reading: {error}"))?;
+        ciborium::de::from_reader_with_recursion_limit(buffer.as_slice(), usize::MAX)
+            .map_err(|error| anyhow::anyhow!("Reproducer {path:?} deserializing: {error}"))
+    }
+
+    ///
+    /// Writes `contents` to a file.
+    ///
+    fn write_file<C>(path: &Path, contents: C) -> anyhow::Result<()>
+    where
+        C: AsRef<[u8]>,
+    {
+        std::fs::write(path, contents)
+            .map_err(|error| anyhow::anyhow!("Reproducer {path:?} writing: {error}"))
+    }
+}
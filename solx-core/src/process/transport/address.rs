@@ -0,0 +1,74 @@
+//!
+//! The worker socket address.
+//!
+
+use std::path::PathBuf;
+use std::str::FromStr;
+
+///
+/// The worker socket address.
+///
+/// Written as `tcp://<host>:<port>` or `unix://<path>`.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Address {
+    /// The TCP socket address in the `<host>:<port>` format.
+    TCP(String),
+    /// The Unix domain socket path.
+    Unix(PathBuf),
+}
+
+impl Address {
+    /// The TCP address scheme.
+    pub const TCP_SCHEME: &'static str = "tcp://";
+
+    /// The Unix domain socket address scheme.
+    pub const UNIX_SCHEME: &'static str = "unix://";
+}
+
+impl FromStr for Address {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        if let Some(address) = string.strip_prefix(Self::TCP_SCHEME) {
+            if address
+                .rsplit_once(':')
+                .is_none_or(|(host, port)| host.is_empty() || port.parse::<u16>().is_err())
+            {
+                anyhow::bail!(
+                    "Invalid worker address `{string}`: expected `{}<host>:<port>`.",
+                    Self::TCP_SCHEME
+                );
+            }
+            return Ok(Self::TCP(address.to_owned()));
+        }
+        if let Some(path) = string.strip_prefix(Self::UNIX_SCHEME) {
+            if !cfg!(unix) {
+                anyhow::bail!(
+                    "Invalid worker address `{string}`: Unix domain sockets are not supported on this platform."
+                );
+            }
+            if path.is_empty() {
+                anyhow::bail!(
+                    "Invalid worker address `{string}`: expected `{}<path>`.",
+                    Self::UNIX_SCHEME
+                );
+            }
+            return Ok(Self::Unix(PathBuf::from(path)));
+        }
+        anyhow::bail!(
+            "Invalid worker address `{string}`: expected `{}<host>:<port>` or `{}<path>`.",
+            Self::TCP_SCHEME,
+            Self::UNIX_SCHEME
+        )
+    }
+}
+
+impl std::fmt::Display for Address {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TCP(address) => write!(f, "{}{address}", Self::TCP_SCHEME),
+            Self::Unix(path) => write!(f, "{}{}", Self::UNIX_SCHEME, path.display()),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+//!
+//! The worker socket handshake.
+//!
+
+///
+/// The worker socket handshake.
+///
+/// Exchanged by both sides right after a socket connection is established, before the session
+/// frame. Pipe workers are spawned from the same executable and do not need it.
+///
+#[derive(Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Handshake {
+    /// The worker protocol version.
+    pub protocol_version: u32,
+    /// The compiler version.
+    pub compiler_version: String,
+}
+
+impl Handshake {
+    /// The current worker protocol version.
+    ///
+    /// Must be bumped on every change of the session, job, or reply frame format.
+    pub const PROTOCOL_VERSION: u32 = 1;
+
+    ///
+    /// Returns the handshake of the running compiler.
+    ///
+    pub fn current() -> Self {
+        Self {
+            protocol_version: Self::PROTOCOL_VERSION,
+            compiler_version: crate::Compiler::version().to_owned(),
+        }
+    }
+
+    ///
+    /// Checks that the peer handshake is compatible with the running compiler.
+    ///
+    pub fn check(&self) -> anyhow::Result<()> {
+        let current = Self::current();
+        if *self != current {
+            anyhow::bail!(
+                "Incompatible peer: {executable} v{} with protocol version {}, expected {executable} v{} with protocol version {}.",
+                self.compiler_version,
+                self.protocol_version,
+                current.compiler_version,
+                current.protocol_version,
+                executable = crate::DEFAULT_EXECUTABLE_NAME,
+            );
+        }
+        Ok(())
+    }
+}
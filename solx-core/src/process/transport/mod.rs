@@ -0,0 +1,8 @@
+//!
+//! The socket transport for remote worker processes.
+//!
+
+pub mod address;
+pub mod handshake;
+pub mod server;
+pub mod stream;
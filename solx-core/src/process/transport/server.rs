@@ -0,0 +1,149 @@
+//!
+//! The worker socket server.
+//!
+
+use std::io::BufReader;
+use std::io::Write;
+use std::net::TcpListener;
+#[cfg(unix)]
+use std::os::unix::net::UnixListener;
+use std::process::Command;
+use std::process::Stdio;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use crate::process::channel::FrameRead;
+use crate::process::channel::FrameWrite;
+
+use super::address::Address;
+use super::handshake::Handshake;
+use super::stream::Stream;
+
+/// The lock-poisoning invariant of the connection subprocess handle.
+const POISON: &str = "lock is never poisoned because relay threads do not panic";
+
+///
+/// The worker socket listener.
+///
+enum Listener {
+    /// The TCP listener.
+    TCP(TcpListener),
+    /// The Unix domain socket listener.
+    #[cfg(unix)]
+    Unix(UnixListener),
+}
+
+impl Listener {
+    ///
+    /// Binds the listener to `address`.
+    ///
+    fn bind(address: &Address) -> anyhow::Result<Self> {
+        let listener = match address {
+            Address::TCP(socket_address) => {
+                TcpListener::bind(socket_address.as_str()).map(Self::TCP)
+            }
+            #[cfg(unix)]
+            Address::Unix(path) => UnixListener::bind(path).map(Self::Unix),
+            #[cfg(not(unix))]
+            Address::Unix(_) => unreachable!("Rejected by the address parser"),
+        };
+        listener.map_err(|error| anyhow::anyhow!("Worker `{address}` binding error: {error}"))
+    }
+
+    ///
+    /// Accepts a connection.
+    ///
+    fn accept(&self) -> std::io::Result<Stream> {
+        match self {
+            Self::TCP(listener) => {
+                let (stream, _) = listener.accept()?;
+                stream.set_nodelay(true)?;
+                Ok(Stream::TCP(stream))
+            }
+            #[cfg(unix)]
+            Self::Unix(listener) => listener.accept().map(|(stream, _)| Stream::Unix(stream)),
+        }
+    }
+}
+
+///
+/// Accepts connections at `address` until the process is terminated.
+///
+/// Every connection is served by its own pipe worker subprocess, so that an LLVM fatal error
+/// or an exceeded limit only terminates that connection, and a single server can accept as
+/// many connections as the machine has cores to compile them.
+///
+/// The connections are neither authenticated nor encrypted, and every connection may run
+/// the compiler with arbitrary input, so a TCP listener must only be bound to an interface
+/// reachable from trusted hosts, for example, `tcp://127.0.0.1:<port>` behind an SSH tunnel
+/// or a private network interface.
+///
+pub fn run(address: &Address) -> anyhow::Result<()> {
+    let listener = Listener::bind(address)?;
+    writeln!(std::io::stderr(), "Listening for jobs at `{address}`.")?;
+    loop {
+        let stream = match listener.accept() {
+            Ok(stream) => stream,
+            Err(error) => {
+                let _ = writeln!(
+                    std::io::stderr(),
+                    "Worker connection accepting error: {error}"
+                );
+                continue;
+            }
+        };
+        std::thread::spawn(move || {
+            if let Err(error) = serve(stream) {
+                let _ = writeln!(std::io::stderr(), "Worker connection error: {error}");
+            }
+        });
+    }
+}
+
+///
+/// Checks the handshake and relays frames between the connection and a pipe worker subprocess.
+///
+/// The subprocess is killed as soon as the connection is closed, which is how the pool cancels
+/// a job that exceeded its time limit.
+///
+fn serve(stream: Stream) -> anyhow::Result<()> {
+    let mut writer = stream.try_clone()?;
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let handshake: Handshake = reader
+        .recv()?
+        .ok_or_else(|| anyhow::anyhow!("The peer sent no handshake"))?;
+    writer.send(&Handshake::current())?;
+    handshake.check()?;
+
+    let executable = crate::process::executable();
+    let mut child = Command::new(executable.as_path())
+        .arg("--recursive-process")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .spawn()
+        .map_err(|error| anyhow::anyhow!("{executable:?} subprocess spawning error: {error:?}"))?;
+    let mut child_stdin = child
+        .stdin
+        .take()
+        .expect("The worker stdin is always piped");
+    let mut child_stdout = child
+        .stdout
+        .take()
+        .expect("The worker stdout is always piped");
+    let child = Arc::new(Mutex::new(child));
+
+    let requests = {
+        let child = child.clone();
+        std::thread::spawn(move || {
+            let _ = std::io::copy(&mut reader, &mut child_stdin);
+            drop(child_stdin);
+            let _ = child.lock().expect(POISON).kill();
+        })
+    };
+    let _ = std::io::copy(&mut child_stdout, &mut writer);
+    stream.shutdown();
+    let _ = requests.join();
+    child.lock().expect(POISON).wait()?;
+    Ok(())
+}
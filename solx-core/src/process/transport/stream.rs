@@ -0,0 +1,91 @@
+//!
+//! The worker socket stream.
+//!
+
+use std::io::Read;
+use std::io::Write;
+use std::net::Shutdown;
+use std::net::TcpStream;
+#[cfg(unix)]
+use std::os::unix::net::UnixStream;
+
+use super::address::Address;
+
+///
+/// The worker socket stream.
+///
+#[derive(Debug)]
+pub enum Stream {
+    /// The TCP stream.
+    TCP(TcpStream),
+    /// The Unix domain socket stream.
+    #[cfg(unix)]
+    Unix(UnixStream),
+}
+
+impl Stream {
+    ///
+    /// Connects to the worker listening at `address`.
+    ///
+    pub fn connect(address: &Address) -> anyhow::Result<Self> {
+        let stream = match address {
+            Address::TCP(socket_address) => TcpStream::connect(socket_address.as_str())
+                .and_then(|stream| stream.set_nodelay(true).map(|()| Self::TCP(stream))),
+            #[cfg(unix)]
+            Address::Unix(path) => UnixStream::connect(path).map(Self::Unix),
+            #[cfg(not(unix))]
+            Address::Unix(_) => unreachable!("Rejected by the address parser"),
+        };
+        stream.map_err(|error| anyhow::anyhow!("Worker `{address}` connecting error: {error}"))
+    }
+
+    ///
+    /// Returns another handle to the same socket, used as its read half.
+    ///
+    pub fn try_clone(&self) -> std::io::Result<Self> {
+        match self {
+            Self::TCP(stream) => stream.try_clone().map(Self::TCP),
+            #[cfg(unix)]
+            Self::Unix(stream) => stream.try_clone().map(Self::Unix),
+        }
+    }
+
+    ///
+    /// Shuts down both halves of the socket, unblocking all handles.
+    ///
+    pub fn shutdown(&self) {
+        let _ = match self {
+            Self::TCP(stream) => stream.shutdown(Shutdown::Both),
+            #[cfg(unix)]
+            Self::Unix(stream) => stream.shutdown(Shutdown::Both),
+        };
+    }
+}
+
+impl Read for Stream {
+    fn read(&mut self, buffer: &mut [u8]) -> std::io::Result<usize> {
+        match self {
+            Self::TCP(stream) => stream.read(buffer),
+            #[cfg(unix)]
+            Self::Unix(stream) => stream.read(buffer),
+        }
+    }
+}
+
+impl Write for Stream {
+    fn write(&mut self, buffer: &[u8]) -> std::io::Result<usize> {
+        match self {
+            Self::TCP(stream) => stream.write(buffer),
+            #[cfg(unix)]
+            Self::Unix(stream) => stream.write(buffer),
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        match self {
+            Self::TCP(stream) => stream.flush(),
+            #[cfg(unix)]
+            Self::Unix(stream) => stream.flush(),
+        }
+    }
+}
@@ -1,35 +1,64 @@
 //!
-//! A persistent worker subprocess owned by the pool.
+//! A persistent worker owned by the pool.
 //!
 
 use std::io::BufReader;
+use std::io::Read;
+use std::io::Write;
 use std::path::Path;
 use std::process::Child;
-use std::process::ChildStdout;
 use std::process::Command;
 use std::process::Stdio;
+use std::sync::mpsc::Receiver;
+use std::sync::mpsc::RecvTimeoutError;
+use std::thread::JoinHandle;
+use std::time::Duration;
+use std::time::Instant;
 
 use crate::error::Error;
 use crate::process::channel::FrameRead;
 use crate::process::channel::FrameWrite;
 use crate::process::job::Job;
 use crate::process::output::Output as EVMOutput;
+use crate::process::reply::Reply;
 use crate::process::session::Session;
+use crate::process::transport::address::Address;
+use crate::process::transport::handshake::Handshake;
+use crate::process::transport::stream::Stream;
 
 ///
-/// A persistent worker subprocess with its framed I/O channel.
+/// The worker endpoint.
 ///
-/// Its `stderr` is inherited, so subprocess diagnostics stream directly to the parent.
-/// Dropping a worker closes its `stdin`, which the worker loop treats as a shutdown request.
+enum Endpoint {
+    /// The local worker subprocess.
+    Local(Child),
+    /// The socket connected to a remote worker server, and its address.
+    Remote(Stream, Address),
+}
+
+///
+/// A persistent worker with its framed I/O channel.
+///
+/// A local worker is a subprocess whose `stderr` is inherited, so subprocess diagnostics stream
+/// directly to the parent. A remote worker is a socket connection to a worker server.
+/// The replies are drained by a reader thread, so that they can be awaited with a deadline.
+/// Dropping a worker closes its request stream, which the worker loop treats as a shutdown request.
 ///
 pub struct Worker {
-    /// The worker subprocess handle.
-    child: Child,
-    /// The buffered response stream.
-    stdout: BufReader<ChildStdout>,
+    /// The worker endpoint.
+    endpoint: Endpoint,
+    /// The request stream.
+    requests: Option<Box<dyn Write + Send>>,
+    /// The reply frames forwarded by the reader thread.
+    replies: Receiver<anyhow::Result<Option<Reply>>>,
+    /// The reader thread handle.
+    reader: Option<JoinHandle<()>>,
 }
 
 impl Worker {
+    /// The interval between resource limit checks.
+    const LIMIT_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
     ///
     /// Spawns a worker subprocess and sends it the session frame.
     ///
@@ -43,42 +72,250 @@ impl Worker {
         let mut child = command.spawn().map_err(|error| {
             anyhow::anyhow!("{executable:?} subprocess spawning error: {error:?}")
         })?;
-        child
+        let stdin = child
             .stdin
-            .as_mut()
-            .expect("The worker stdin is always piped")
-            .send(session)?;
-        let stdout = BufReader::new(
-            child
-                .stdout
-                .take()
-                .expect("The worker stdout is always piped"),
-        );
-        Ok(Self { child, stdout })
+            .take()
+            .expect("The worker stdin is always piped");
+        let stdout = child
+            .stdout
+            .take()
+            .expect("The worker stdout is always piped");
+        Self::new(
+            Endpoint::Local(child),
+            Box::new(stdin),
+            Box::new(stdout),
+            session,
+        )
+    }
+
+    ///
+    /// Connects to a remote worker server, exchanges handshakes, and sends it the session frame.
+    ///
+    pub fn connect(address: &Address, session: &Session) -> anyhow::Result<Self> {
+        let stream = Stream::connect(address)?;
+        let mut requests = stream.try_clone()?;
+        let mut replies = BufReader::new(stream.try_clone()?);
+        requests.send(&Handshake::current())?;
+        let handshake: Handshake = replies.recv()?.ok_or_else(|| {
+            anyhow::anyhow!("Worker `{address}` closed the connection during the handshake")
+        })?;
+        handshake
+            .check()
+            .map_err(|error| anyhow::anyhow!("Worker `{address}`: {error}"))?;
+        Self::new(
+            Endpoint::Remote(stream, address.to_owned()),
+            Box::new(requests),
+            Box::new(replies),
+            session,
+        )
+    }
+
+    ///
+    /// Sends the session frame and starts the reader thread.
+    ///
+    fn new(
+        endpoint: Endpoint,
+        mut requests: Box<dyn Write + Send>,
+        replies: Box<dyn Read + Send>,
+        session: &Session,
+    ) -> anyhow::Result<Self> {
+        requests.send(session)?;
+        let mut replies_reader = BufReader::new(replies);
+        let (sender, replies) = std::sync::mpsc::channel();
+        let reader = std::thread::spawn(move || {
+            loop {
+                let reply = replies_reader.recv::<Reply>();
+                let is_finished = !matches!(reply, Ok(Some(_)));
+                if sender.send(reply).is_err() || is_finished {
+                    break;
+                }
+            }
+        });
+        Ok(Self {
+            endpoint,
+            requests: Some(requests),
+            replies,
+            reader: Some(reader),
+        })
     }
 
     ///
     /// Sends `job` to the worker and returns the compilation result it replies with.
     ///
-    pub fn execute(&mut self, job: &Job) -> crate::Result<EVMOutput> {
-        self.child
-            .stdin
+    /// If the job exceeds any of `limits`, the worker is killed and a diagnostic naming the
+    /// last pipeline stage it reported is returned. The worker must not be reused afterwards.
+    /// The memory limit is only enforced for local workers.
+    ///
+    pub fn execute(
+        &mut self,
+        job: &Job,
+        limits: &solx_standard_json::InputLimits,
+    ) -> crate::Result<EVMOutput> {
+        self.requests
             .as_mut()
-            .expect("The worker stdin is always piped")
+            .expect("The request stream is only closed on drop")
             .send(job)?;
-        match self.stdout.recv::<crate::Result<EVMOutput>>()? {
-            Some(result) => result,
-            None => Err(Error::Generic(format!(
-                "The worker exited without replying: {}",
-                self.child.wait()?
-            ))),
+
+        let start_time = Instant::now();
+        let mut stage: Option<String> = None;
+        loop {
+            let reply = if limits.is_empty() {
+                self.replies.recv().ok()
+            } else {
+                match self.replies.recv_timeout(Self::LIMIT_CHECK_INTERVAL) {
+                    Ok(reply) => Some(reply),
+                    Err(RecvTimeoutError::Timeout) => {
+                        self.enforce_limits(job, limits, start_time, stage.as_deref())?;
+                        continue;
+                    }
+                    Err(RecvTimeoutError::Disconnected) => None,
+                }
+            };
+            match reply.transpose()?.flatten() {
+                Some(Reply::Stage(description)) => {
+                    stage = Some(description);
+                    self.enforce_limits(job, limits, start_time, stage.as_deref())?;
+                }
+                Some(Reply::Result(result)) => return result,
+                None => {
+                    return Err(Error::Generic(match self.endpoint {
+                        Endpoint::Local(ref mut child) => {
+                            format!("The worker exited without replying: {}", child.wait()?)
+                        }
+                        Endpoint::Remote(_, ref address) => {
+                            format!("Worker `{address}` closed the connection without replying")
+                        }
+                    }));
+                }
+            }
+        }
+    }
+
+    ///
+    /// Kills the worker and returns a diagnostic if the running job has exceeded any of `limits`.
+    ///
+    /// Called both on every reply and between replies, so a job reporting stages more often
+    /// than the check interval is still checked.
+    ///
+    fn enforce_limits(
+        &mut self,
+        job: &Job,
+        limits: &solx_standard_json::InputLimits,
+        start_time: Instant,
+        stage: Option<&str>,
+    ) -> crate::Result<()> {
+        if let Some(diagnostic) = self.check_limits(job, limits, start_time.elapsed(), stage) {
+            self.kill();
+            return Err(Error::Diagnostic(diagnostic));
+        }
+        Ok(())
+    }
+
+    ///
+    /// Returns a diagnostic if the running job has exceeded any of `limits`.
+    ///
+    fn check_limits(
+        &self,
+        job: &Job,
+        limits: &solx_standard_json::InputLimits,
+        elapsed: Duration,
+        stage: Option<&str>,
+    ) -> Option<solx_utils::Diagnostic> {
+        let stage = stage.unwrap_or("Initialization");
+        if let Some(timeout) = limits.timeout()
+            && elapsed > timeout
+        {
+            return Some(solx_utils::Diagnostic::new(
+                solx_utils::DiagnosticCode::TimeLimit,
+                solx_utils::DiagnosticComponent::Worker,
+                format!(
+                    "Contract `{}` ({} code) exceeded the time limit of {}s at stage `{stage}`.",
+                    job.contract_name.full_path,
+                    job.code_segment,
+                    timeout.as_secs(),
+                ),
+            ));
+        }
+        if let Some(memory_limit) = limits.memory_bytes()
+            && let Some(memory) = self.resident_memory()
+            && memory > memory_limit
+        {
+            return Some(solx_utils::Diagnostic::new(
+                solx_utils::DiagnosticCode::MemoryLimit,
+                solx_utils::DiagnosticComponent::Worker,
+                format!(
+                    "Contract `{}` ({} code) exceeded the memory limit of {}MB with {}MB at stage `{stage}`.",
+                    job.contract_name.full_path,
+                    job.code_segment,
+                    limits.memory.unwrap_or_default(),
+                    memory / (1024 * 1024),
+                ),
+            ));
+        }
+        None
+    }
+
+    ///
+    /// Kills a local worker subprocess, or closes the connection to a remote one,
+    /// which makes its server kill the subprocess serving it.
+    ///
+    fn kill(&mut self) {
+        match self.endpoint {
+            Endpoint::Local(ref mut child) => {
+                let _ = child.kill();
+            }
+            Endpoint::Remote(ref stream, _) => stream.shutdown(),
+        }
+    }
+
+    ///
+    /// Returns the resident set size of a local worker in bytes, if it can be measured.
+    ///
+    /// Reads `/proc` on Linux and falls back to `ps` on other Unix systems.
+    ///
+    fn resident_memory(&self) -> Option<u64> {
+        let Endpoint::Local(ref child) = self.endpoint else {
+            return None;
+        };
+        let pid = child.id();
+        if cfg!(target_os = "linux") {
+            let status = std::fs::read_to_string(format!("/proc/{pid}/status")).ok()?;
+            let kilobytes = status
+                .lines()
+                .find_map(|line| line.strip_prefix("VmRSS:"))?
+                .trim()
+                .trim_end_matches("kB")
+                .trim()
+                .parse::<u64>()
+                .ok()?;
+            Some(kilobytes * 1024)
+        } else if cfg!(unix) {
+            let output = Command::new("ps")
+                .args(["-o", "rss=", "-p", pid.to_string().as_str()])
+                .output()
+                .ok()?;
+            let kilobytes = String::from_utf8_lossy(output.stdout.as_slice())
+                .trim()
+                .parse::<u64>()
+                .ok()?;
+            Some(kilobytes * 1024)
+        } else {
+            None
         }
     }
 }
 
 impl Drop for Worker {
     fn drop(&mut self) {
-        drop(self.child.stdin.take());
-        let _ = self.child.wait();
+        drop(self.requests.take());
+        match self.endpoint {
+            Endpoint::Local(ref mut child) => {
+                let _ = child.wait();
+            }
+            Endpoint::Remote(ref stream, _) => stream.shutdown(),
+        }
+        if let Some(reader) = self.reader.take() {
+            let _ = reader.join();
+        }
     }
 }
@@ -210,7 +210,8 @@ impl Contract {
                 );
                 yul.object.declare(&mut context)?;
                 yul.object.into_llvm(&mut context).map_err(|error| {
-                    anyhow::anyhow!("{code_segment} code LLVM IR generator: {error}")
+                    Error::from(error)
+                        .with_context(format!("{code_segment} code LLVM IR generator"))
                 })?;
                 run_yul_lowering.borrow_mut().finish();
                 if output_selection.check_selection(
@@ -357,7 +358,8 @@ impl Contract {
                 );
                 code.assembly.declare(&mut context)?;
                 code.assembly.into_llvm(&mut context).map_err(|error| {
-                    anyhow::anyhow!("{code_segment} code LLVM IR generator: {error}")
+                    Error::from(error)
+                        .with_context(format!("{code_segment} code LLVM IR generator"))
                 })?;
                 run_evm_assembly_lowering.borrow_mut().finish();
                 if output_selection.check_selection(
@@ -526,6 +526,7 @@ impl Project {
         optimizer_settings: solx_codegen_evm::OptimizerSettings,
         llvm_options: Vec<String>,
         output_config: Option<solx_codegen_evm::OutputConfig>,
+        limits: solx_standard_json::InputLimits,
     ) -> anyhow::Result<EVMBuild> {
         let Self {
             language,
@@ -535,14 +536,17 @@ impl Project {
             libraries: _,
             debug_info,
         } = self;
-        let pool = EVMProcessPool::new(EVMProcessSession::new(
-            language,
-            solc_version.clone(),
-            evm_version,
-            output_selection.clone(),
-            llvm_options.clone(),
-            output_config,
-        ))?;
+        let pool = EVMProcessPool::new(
+            EVMProcessSession::new(
+                language,
+                solc_version.clone(),
+                evm_version,
+                output_selection.clone(),
+                llvm_options.clone(),
+                output_config,
+            ),
+            limits,
+        )?;
 
         let mut contracts: Vec<(String, Contract)> = contracts.into_iter().collect();
         contracts.sort_unstable_by(|(_, left), (_, right)| {
@@ -780,6 +784,9 @@ impl Project {
                 solx_utils::MetadataHashType::IPFS => {
                     Some(solx_utils::IPFSHash::from_slice(metadata.as_bytes()).to_vec())
                 }
+                solx_utils::MetadataHashType::Swarm => {
+                    Some(solx_utils::SwarmHash::from_slice(metadata.as_bytes()).to_vec())
+                }
             });
 
         let mut cbor_version_parts = Vec::with_capacity(2);
@@ -803,7 +810,7 @@ impl Project {
         match metadata_hash {
             Some(hash) => {
                 let cbor = solx_utils::CBOR::new(
-                    Some((solx_utils::MetadataHashType::IPFS, hash.as_slice())),
+                    Some((metadata_hash_type, hash.as_slice())),
                     cbor_data.0,
                     cbor_data.1,
                 );
@@ -837,9 +844,14 @@ impl Project {
                         job.optimizer_settings.switch_to_size_fallback();
                         stack_too_deep_retries = 0;
                     } else if stack_too_deep_retries == Self::STACK_TOO_DEEP_RETRY_LIMIT {
-                        break Err(solx_standard_json::OutputError::new_error_contract(
+                        break Err(solx_standard_json::OutputError::new_diagnostic(
                             Some(job.contract_name.path.as_str()),
-                            stack_too_deep,
+                            &solx_utils::Diagnostic::new(
+                                solx_utils::DiagnosticCode::StackTooDeep,
+                                solx_utils::DiagnosticComponent::Codegen,
+                                stack_too_deep,
+                            ),
+                            None,
                         )
                         .into());
                     } else {
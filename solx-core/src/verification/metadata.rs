@@ -0,0 +1,221 @@
+//!
+//! The contract metadata consumed by the verification mode.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::path::Path;
+use std::path::PathBuf;
+
+///
+/// The contract metadata consumed by the verification mode.
+///
+/// Only the fields required to reconstruct the standard JSON input are read.
+/// The `solx` section is only present in the metadata hashed into the CBOR trailer, so the
+/// optimizer settings fall back to the CLI ones if it is missing.
+///
+#[derive(Debug, serde::Deserialize)]
+pub struct Metadata {
+    /// The frontend compiler data.
+    pub compiler: Compiler,
+    /// The source code language.
+    pub language: solx_standard_json::InputLanguage,
+    /// The compilation settings.
+    pub settings: Settings,
+    /// The source code files.
+    pub sources: BTreeMap<String, Source>,
+    /// The data inserted by `solx`.
+    #[serde(default, alias = "solx-core")]
+    pub solx: Option<Solx>,
+}
+
+///
+/// The frontend compiler data.
+///
+#[derive(Debug, serde::Deserialize)]
+pub struct Compiler {
+    /// The long version string.
+    pub version: String,
+}
+
+///
+/// The compilation settings.
+///
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Settings {
+    /// The contract the metadata belongs to, as a single-entry path-to-name mapping.
+    pub compilation_target: BTreeMap<String, String>,
+    /// The target EVM version.
+    pub evm_version: Option<solx_utils::EVMVersion>,
+    /// The linked library addresses, keyed by `<path>:<name>`.
+    #[serde(default)]
+    pub libraries: BTreeMap<String, String>,
+    /// The metadata settings.
+    #[serde(default)]
+    pub metadata: SettingsMetadata,
+    /// The `solc` optimizer settings.
+    #[serde(default)]
+    pub optimizer: solx_standard_json::InputOptimizer,
+    /// The sorted list of remappings.
+    #[serde(default)]
+    pub remappings: BTreeSet<String>,
+    /// Whether to compile Solidity via IR.
+    #[serde(default, rename = "viaIR")]
+    pub via_ir: bool,
+}
+
+///
+/// The metadata settings.
+///
+#[derive(Debug, Default, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SettingsMetadata {
+    /// Whether the metadata contains the literal content of the source files.
+    #[serde(default)]
+    pub use_literal_content: bool,
+}
+
+///
+/// The source code file.
+///
+#[derive(Debug, serde::Deserialize)]
+pub struct Source {
+    /// The `keccak256` hash of the source code.
+    pub keccak256: String,
+    /// The literal source code, if `useLiteralContent` was enabled.
+    pub content: Option<String>,
+}
+
+///
+/// The data inserted by `solx`.
+///
+#[derive(Debug, serde::Deserialize)]
+pub struct Solx {
+    /// `solx` compiler version.
+    pub solx_version: semver::Version,
+    /// The LLVM compiler optimizer settings.
+    pub optimizer_settings: solx_codegen_evm::OptimizerSettings,
+    /// The LLVM extra arguments.
+    #[serde(default)]
+    pub llvm_options: Vec<String>,
+}
+
+impl Metadata {
+    ///
+    /// Reads the metadata JSON from the file system.
+    ///
+    pub fn try_from_path(path: &Path) -> anyhow::Result<Self> {
+        let metadata = std::fs::read_to_string(path)
+            .map_err(|error| anyhow::anyhow!("Metadata file {path:?} reading: {error}"))?;
+        solx_utils::deserialize_from_str::<Self>(metadata.as_str())
+            .map_err(|error| anyhow::anyhow!("Metadata file {path:?} parsing: {error}"))
+    }
+
+    ///
+    /// Returns the path and name of the contract the metadata belongs to.
+    ///
+    pub fn compilation_target(&self) -> anyhow::Result<solx_utils::ContractName> {
+        let mut targets = self.settings.compilation_target.iter();
+        match (targets.next(), targets.next()) {
+            (Some((path, name)), None) => Ok(solx_utils::ContractName::new(
+                path.to_owned(),
+                Some(name.to_owned()),
+            )),
+            _ => anyhow::bail!("The metadata must have exactly one compilation target."),
+        }
+    }
+
+    ///
+    /// Returns the `solc` version the metadata was produced with.
+    ///
+    pub fn solc_version(&self) -> anyhow::Result<semver::Version> {
+        let mut version =
+            semver::Version::parse(self.compiler.version.as_str()).map_err(|error| {
+                anyhow::anyhow!(
+                    "Invalid compiler version `{}` in the metadata: {error}",
+                    self.compiler.version
+                )
+            })?;
+        version.build = semver::BuildMetadata::EMPTY;
+        Ok(version)
+    }
+
+    ///
+    /// Reconstructs the standard JSON input the contract was compiled from.
+    ///
+    /// Sources without literal content are read from the file system, relative to `base_path`
+    /// if it is set. Every source must match its `keccak256` hash from the metadata.
+    /// The `solx` optimizer settings and LLVM options are resolved by the caller.
+    ///
+    pub fn try_into_input(
+        self,
+        base_path: Option<&str>,
+        output_selection: &solx_standard_json::InputSelection,
+        metadata_hash_type: solx_utils::MetadataHashType,
+        append_cbor: bool,
+        optimizer_settings: &solx_codegen_evm::OptimizerSettings,
+        llvm_options: Vec<String>,
+    ) -> anyhow::Result<solx_standard_json::Input> {
+        if self.language != solx_standard_json::InputLanguage::Solidity {
+            anyhow::bail!("Only Solidity contracts can be verified.");
+        }
+
+        let mut sources = BTreeMap::new();
+        for (path, source) in self.sources.into_iter() {
+            let content = match source.content {
+                Some(content) => content,
+                None => {
+                    let mut full_path = base_path.map(PathBuf::from).unwrap_or_default();
+                    full_path.push(path.as_str());
+                    std::fs::read_to_string(full_path.as_path()).map_err(|error| {
+                        anyhow::anyhow!("Source file {full_path:?} reading: {error}")
+                    })?
+                }
+            };
+            let hash = solx_utils::Keccak256Hash::from_slice(content.as_bytes());
+            if hash.as_str() != source.keccak256.as_str() {
+                anyhow::bail!(
+                    "Source `{path}` does not match the metadata: expected hash {}, found {hash}",
+                    source.keccak256,
+                );
+            }
+            sources.insert(
+                path,
+                solx_standard_json::InputSource {
+                    content: Some(content),
+                    urls: None,
+                    keccak256: None,
+                },
+            );
+        }
+
+        let libraries = self
+            .settings
+            .libraries
+            .into_iter()
+            .map(|(path, address)| format!("{path}={address}"))
+            .collect::<Vec<String>>();
+        let libraries = solx_utils::Libraries::try_from(libraries.as_slice())?;
+
+        let mut optimizer = self.settings.optimizer;
+        optimizer.mode = Some(optimizer_settings.middle_end_as_char());
+        optimizer.size_fallback = Some(optimizer_settings.is_fallback_to_size_enabled());
+
+        solx_standard_json::Input::try_from_solidity_sources(
+            sources,
+            libraries,
+            self.settings.remappings,
+            optimizer,
+            self.settings.evm_version,
+            self.settings.via_ir,
+            output_selection,
+            solx_standard_json::InputMetadata::new(
+                self.settings.metadata.use_literal_content,
+                append_cbor,
+                metadata_hash_type,
+            ),
+            llvm_options,
+        )
+    }
+}
@@ -0,0 +1,119 @@
+//!
+//! The deployed bytecode verification.
+//!
+
+pub mod metadata;
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+///
+/// The deployed bytecode verification result.
+///
+/// Follows the Sourcify terminology, where a partial match means that the executable code is
+/// identical, but the CBOR metadata trailer differs.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verification {
+    /// The bytecode matches including the CBOR metadata.
+    FullMatch,
+    /// The bytecode matches with the CBOR metadata excluded.
+    PartialMatch,
+    /// The bytecode does not match.
+    Mismatch,
+}
+
+impl Verification {
+    ///
+    /// Compares the `deployed` runtime bytecode with the `compiled` one.
+    ///
+    /// The deployed values of `immutables` are masked out, as they are only known at deploy time
+    /// and are left zeroed in the compiled runtime code.
+    ///
+    pub fn compare(
+        deployed: &[u8],
+        compiled: &[u8],
+        immutables: Option<&BTreeMap<String, BTreeSet<u64>>>,
+    ) -> Self {
+        let mut deployed = deployed.to_vec();
+        let mut compiled = compiled.to_vec();
+        for offset in immutables
+            .into_iter()
+            .flat_map(|immutables| immutables.values())
+            .flatten()
+        {
+            let start = *offset as usize;
+            let end = start + solx_utils::BYTE_LENGTH_FIELD;
+            for bytecode in [&mut deployed, &mut compiled] {
+                if let Some(range) = bytecode.get_mut(start..end) {
+                    range.fill(0);
+                }
+            }
+        }
+
+        if deployed == compiled {
+            return Self::FullMatch;
+        }
+        if Self::split_cbor(deployed.as_slice()).0 == Self::split_cbor(compiled.as_slice()).0 {
+            return Self::PartialMatch;
+        }
+        Self::Mismatch
+    }
+
+    ///
+    /// Splits the bytecode into the executable code and the CBOR metadata payload, if the latter
+    /// is present.
+    ///
+    /// The payload is recognized by the two-byte big-endian length suffix, the CBOR map header,
+    /// and the mandatory compiler version key.
+    ///
+    pub fn split_cbor(bytecode: &[u8]) -> (&[u8], Option<&[u8]>) {
+        let Some(length_offset) = bytecode.len().checked_sub(2) else {
+            return (bytecode, None);
+        };
+        let length =
+            u16::from_be_bytes([bytecode[length_offset], bytecode[length_offset + 1]]) as usize;
+        let Some(payload_offset) = length_offset.checked_sub(length) else {
+            return (bytecode, None);
+        };
+        let payload = &bytecode[payload_offset..length_offset];
+        let is_cbor_map = payload
+            .first()
+            .is_some_and(|header| (0xA1..=0xA3).contains(header));
+        let has_version_key = payload
+            .windows(crate::r#const::SOLC_METADATA_TAG.len())
+            .any(|window| window == crate::r#const::SOLC_METADATA_TAG.as_bytes());
+        if !is_cbor_map || !has_version_key {
+            return (bytecode, None);
+        }
+        (&bytecode[..payload_offset], Some(payload))
+    }
+
+    ///
+    /// Detects the metadata hash type from the CBOR metadata payload.
+    ///
+    pub fn metadata_hash_type(cbor_payload: &[u8]) -> solx_utils::MetadataHashType {
+        [
+            solx_utils::MetadataHashType::IPFS,
+            solx_utils::MetadataHashType::Swarm,
+        ]
+        .into_iter()
+        .find(|hash_type| {
+            let key = hash_type.to_string();
+            cbor_payload
+                .windows(key.len())
+                .any(|window| window == key.as_bytes())
+        })
+        .unwrap_or(solx_utils::MetadataHashType::None)
+    }
+}
+
+impl std::fmt::Display for Verification {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::FullMatch => write!(f, "full match"),
+            Self::PartialMatch => write!(f, "partial match (the CBOR metadata differs)"),
+            Self::Mismatch => write!(f, "mismatch"),
+        }
+    }
+}
@@ -0,0 +1,44 @@
+//!
+//! `solx-tester fuzz` arguments.
+//!
+
+use std::path::PathBuf;
+
+use clap::Args;
+
+///
+/// `solx-tester fuzz` arguments.
+///
+#[derive(Args)]
+pub struct Fuzz {
+    /// Path to the Solidity contract to fuzz. The last contract in the file is deployed.
+    pub contract: PathBuf,
+
+    /// Path to the reference `solc` executable.
+    #[arg(long, default_value = "solc")]
+    pub reference_compiler: PathBuf,
+
+    /// Fuzz the Yul IR pipeline instead of the EVM assembly one.
+    #[arg(long)]
+    pub via_ir: bool,
+
+    /// The `solx` optimization level: `1`, `2`, `3`, `s`, or `z`.
+    #[arg(short = 'O', long, default_value_t = '3')]
+    pub optimization: char,
+
+    /// Number of call sequences to generate.
+    #[arg(long, default_value_t = 1000)]
+    pub iterations: usize,
+
+    /// Maximum number of calls in each sequence after the deployment.
+    #[arg(long, default_value_t = 4)]
+    pub calls: usize,
+
+    /// Seed of the input generator. A random seed is chosen and printed if not set.
+    #[arg(long)]
+    pub seed: Option<u64>,
+
+    /// Directory to save the diverging inputs to as Matter Labs-format tests.
+    #[arg(long, default_value = "tests/solidity/simple/fuzzed")]
+    pub output_dir: PathBuf,
+}
@@ -2,14 +2,25 @@
 //! `solx-tester` arguments.
 //!
 
+pub mod fuzz;
+pub mod reduce;
+pub mod report;
+pub mod trace_diff;
+
 use std::path::PathBuf;
 
 use clap::Parser;
+use clap::Subcommand;
 
 use solx_benchmark_converter::OutputFormat;
 
 use crate::workflow::Workflow;
 
+pub use self::fuzz::Fuzz;
+pub use self::reduce::Reduce;
+pub use self::report::Report;
+pub use self::trace_diff::TraceDiff;
+
 ///
 /// `solx-tester` arguments passed through to the solx-tester binary.
 ///
@@ -52,10 +63,18 @@ pub struct SolxTester {
     #[arg(short, long)]
     pub benchmark: Option<PathBuf>,
 
-    /// Benchmark output format: `json` or `xlsx`.
+    /// Benchmark output format: `json`, `xlsx`, or `html`.
     #[arg(long = "benchmark-format", value_enum, ignore_case = true)]
     pub benchmark_format: Option<OutputFormat>,
 
+    /// Report to write after running the tests: `junit=<path>` or `sarif=<path>`. Repeatable.
+    #[arg(long)]
+    pub report: Vec<Report>,
+
+    /// LCOV code coverage output path. Requires `solx`, as the coverage is mapped with its debug information.
+    #[arg(long)]
+    pub coverage: Option<PathBuf>,
+
     /// Number of threads for concurrent test execution.
     #[arg(short, long)]
     pub threads: Option<usize>,
@@ -84,4 +103,21 @@ pub struct SolxTester {
     /// Honors CARGO_TARGET_DIR if set.
     #[arg(long, hide = true)]
     pub binary: Option<PathBuf>,
+
+    /// An alternative workflow to running the test collection.
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+///
+/// `solx-tester` alternative workflows.
+///
+#[derive(Subcommand)]
+pub enum Command {
+    /// Run differential fuzzing of a contract against the reference `solc`.
+    Fuzz(Fuzz),
+    /// Reduce a failing test to a minimal one that still fails the same way.
+    Reduce(Reduce),
+    /// Diff the EIP-3155 traces of a failing case between solx and solc.
+    TraceDiff(TraceDiff),
 }
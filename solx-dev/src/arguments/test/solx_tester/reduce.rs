@@ -0,0 +1,42 @@
+//!
+//! `solx-tester reduce` arguments.
+//!
+
+use std::path::PathBuf;
+
+use clap::Args;
+
+///
+/// `solx-tester reduce` arguments.
+///
+#[derive(Args)]
+pub struct Reduce {
+    /// Path to the Matter Labs-format Solidity test to reduce.
+    pub test: PathBuf,
+
+    /// Name of the test case to preserve, whose `solx` outcomes must keep diverging from the
+    /// reference `solc` ones. Defaults to the first failing case.
+    #[arg(long, conflicts_with = "error_pattern")]
+    pub case: Option<String>,
+
+    /// Preserve a compilation failure whose message contains the pattern, such as an LLVM
+    /// fatal error, instead of a failing test case.
+    #[arg(long)]
+    pub error_pattern: Option<String>,
+
+    /// Path to the reference `solc` executable.
+    #[arg(long, default_value = "solc")]
+    pub reference_compiler: PathBuf,
+
+    /// Compile via the Yul IR pipeline instead of the EVM assembly one.
+    #[arg(long)]
+    pub via_ir: bool,
+
+    /// The `solx` optimization level: `1`, `2`, `3`, `s`, or `z`.
+    #[arg(short = 'O', long, default_value_t = '3')]
+    pub optimization: char,
+
+    /// Path to write the reduced test to. Defaults to `<test>.reduced.sol` next to the test.
+    #[arg(long)]
+    pub output: Option<PathBuf>,
+}
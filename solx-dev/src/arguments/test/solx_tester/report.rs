@@ -0,0 +1,46 @@
+//!
+//! `solx-tester` report argument.
+//!
+
+use std::path::PathBuf;
+use std::str::FromStr;
+
+///
+/// The report to write after running the tests, in the `<format>=<path>` form.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Report {
+    /// The JUnit XML report with a test case per summary element.
+    JUnit(PathBuf),
+    /// The SARIF report of the compiler errors.
+    Sarif(PathBuf),
+}
+
+impl FromStr for Report {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let Some((format, path)) = string.split_once('=') else {
+            anyhow::bail!("Invalid report `{string}`. Expected `<format>=<path>`");
+        };
+        if path.is_empty() {
+            anyhow::bail!("Invalid report `{string}`: the path is empty");
+        }
+        match format {
+            "junit" => Ok(Self::JUnit(PathBuf::from(path))),
+            "sarif" => Ok(Self::Sarif(PathBuf::from(path))),
+            format => {
+                anyhow::bail!("Unknown report format `{format}`. Supported formats: junit, sarif")
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for Report {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::JUnit(path) => write!(f, "junit={}", path.display()),
+            Self::Sarif(path) => write!(f, "sarif={}", path.display()),
+        }
+    }
+}
@@ -0,0 +1,36 @@
+//!
+//! `solx-tester trace-diff` arguments.
+//!
+
+use std::path::PathBuf;
+
+use clap::Args;
+
+///
+/// `solx-tester trace-diff` arguments.
+///
+#[derive(Args)]
+pub struct TraceDiff {
+    /// Path to the Matter Labs-format test with the failing case.
+    pub test: PathBuf,
+
+    /// Name of the test case to trace. Defaults to the first case failing with `solx`.
+    #[arg(long)]
+    pub case: Option<String>,
+
+    /// Path to the reference `solc` executable.
+    #[arg(long, default_value = "solc")]
+    pub reference_compiler: PathBuf,
+
+    /// Compile via the Yul IR pipeline instead of the EVM assembly one.
+    #[arg(long)]
+    pub via_ir: bool,
+
+    /// The `solx` optimization level: `1`, `2`, `3`, `s`, or `z`.
+    #[arg(short = 'O', long, default_value_t = '3')]
+    pub optimization: char,
+
+    /// Number of opcodes to print before and after the divergence.
+    #[arg(long, default_value_t = 8)]
+    pub context: usize,
+}
@@ -17,6 +17,11 @@ pub mod workflow;
 
 pub use self::arguments::Arguments;
 pub use self::arguments::test::SolxTester as SolxTesterArguments;
+pub use self::arguments::test::solx_tester::Command as SolxTesterCommand;
+pub use self::arguments::test::solx_tester::Fuzz as SolxTesterFuzzArguments;
+pub use self::arguments::test::solx_tester::Reduce as SolxTesterReduceArguments;
+pub use self::arguments::test::solx_tester::Report as SolxTesterReport;
+pub use self::arguments::test::solx_tester::TraceDiff as SolxTesterTraceDiffArguments;
 pub use self::build_type::BuildType;
 pub use self::ccache_variant::CcacheVariant;
 pub use self::llvm::build as llvm_build;
@@ -6,6 +6,7 @@ use std::path::PathBuf;
 use std::process::Command;
 
 use crate::arguments::test::SolxTester as Arguments;
+use crate::arguments::test::solx_tester::Command as SolxTesterCommand;
 
 ///
 /// Returns the default path to the solx-tester binary.
@@ -64,6 +65,12 @@ pub fn run(arguments: Arguments) -> anyhow::Result<()> {
             .arg("--benchmark-format")
             .arg(benchmark_format.to_string());
     }
+    if let Some(coverage) = &arguments.coverage {
+        command.arg("--coverage").arg(coverage);
+    }
+    for report in &arguments.report {
+        command.arg("--report").arg(report.to_string());
+    }
     if let Some(threads) = arguments.threads {
         command.arg("--threads").arg(threads.to_string());
     }
@@ -85,5 +92,64 @@ pub fn run(arguments: Arguments) -> anyhow::Result<()> {
         command.arg("--llvm-debug-logging");
     }
 
+    match &arguments.command {
+        Some(SolxTesterCommand::Fuzz(fuzz)) => {
+            command.arg("fuzz").arg(&fuzz.contract);
+            command
+                .arg("--reference-compiler")
+                .arg(&fuzz.reference_compiler);
+            if fuzz.via_ir {
+                command.arg("--via-ir");
+            }
+            command
+                .arg("--optimization")
+                .arg(fuzz.optimization.to_string());
+            command.arg("--iterations").arg(fuzz.iterations.to_string());
+            command.arg("--calls").arg(fuzz.calls.to_string());
+            if let Some(seed) = fuzz.seed {
+                command.arg("--seed").arg(seed.to_string());
+            }
+            command.arg("--output-dir").arg(&fuzz.output_dir);
+        }
+        Some(SolxTesterCommand::Reduce(reduce)) => {
+            command.arg("reduce").arg(&reduce.test);
+            if let Some(case) = &reduce.case {
+                command.arg("--case").arg(case);
+            }
+            if let Some(error_pattern) = &reduce.error_pattern {
+                command.arg("--error-pattern").arg(error_pattern);
+            }
+            command
+                .arg("--reference-compiler")
+                .arg(&reduce.reference_compiler);
+            if reduce.via_ir {
+                command.arg("--via-ir");
+            }
+            command
+                .arg("--optimization")
+                .arg(reduce.optimization.to_string());
+            if let Some(output) = &reduce.output {
+                command.arg("--output").arg(output);
+            }
+        }
+        Some(SolxTesterCommand::TraceDiff(trace_diff)) => {
+            command.arg("trace-diff").arg(&trace_diff.test);
+            if let Some(case) = &trace_diff.case {
+                command.arg("--case").arg(case);
+            }
+            command
+                .arg("--reference-compiler")
+                .arg(&trace_diff.reference_compiler);
+            if trace_diff.via_ir {
+                command.arg("--via-ir");
+            }
+            command
+                .arg("--optimization")
+                .arg(trace_diff.optimization.to_string());
+            command.arg("--context").arg(trace_diff.context.to_string());
+        }
+        None => {}
+    }
+
     crate::utils::command(&mut command, "Running solx-tester")
 }
@@ -593,6 +593,21 @@ impl Name {
             Self::RecursiveReturn { .. } => "RECURSIVE_RETURN",
         }
     }
+
+    ///
+    /// Returns the EVM opcode if the instruction is only available since some hard fork.
+    ///
+    pub fn evm_opcode(&self) -> Option<solx_utils::EVMOpcode> {
+        match self {
+            Self::TLOAD => Some(solx_utils::EVMOpcode::TLoad),
+            Self::TSTORE => Some(solx_utils::EVMOpcode::TStore),
+            Self::MCOPY => Some(solx_utils::EVMOpcode::MCopy),
+            Self::BLOBHASH => Some(solx_utils::EVMOpcode::BlobHash),
+            Self::BLOBBASEFEE => Some(solx_utils::EVMOpcode::BlobBaseFee),
+            Self::CLZ => Some(solx_utils::EVMOpcode::Clz),
+            _ => None,
+        }
+    }
 }
 
 impl std::fmt::Display for Name {
@@ -17,6 +17,7 @@ use twox_hash::XxHash3_64;
 use solx_codegen_evm::IContext;
 
 use crate::ethereal_ir::EtherealIR;
+use crate::ethereal_ir::function::Function;
 use crate::extra_metadata::ExtraMetadata;
 
 use self::data::Data;
@@ -354,6 +355,20 @@ impl solx_codegen_evm::WriteLLVM for Assembly {
                 output_config.output_ethir
             });
 
+        let (output_cfg_ethir, output_cfg_evmla) =
+            context
+                .output_config()
+                .map_or((false, false), |output_config| {
+                    (
+                        output_config
+                            .output_cfg
+                            .contains(&solx_codegen_evm::CFGType::EthIR),
+                        output_config
+                            .output_cfg
+                            .contains(&solx_codegen_evm::CFGType::EVMLA),
+                    )
+                });
+
         if output_evmla {
             let evmla_string = self.to_string();
             match context.output_config() {
@@ -379,8 +394,8 @@ impl solx_codegen_evm::WriteLLVM for Assembly {
             context.evmla().expect("Always exists").version.to_owned(),
             self.extra_metadata.unwrap_or_default(),
             code_segment,
-            blocks,
-            output_ethir,
+            &blocks,
+            output_ethir || output_cfg_ethir,
         )?;
         if output_ethir {
             let ethir_string = ethereal_ir.to_string();
@@ -395,6 +410,30 @@ impl solx_codegen_evm::WriteLLVM for Assembly {
             }
         }
 
+        if let Some(output_config) = context.output_config() {
+            if output_cfg_ethir {
+                output_config.dump_cfg(
+                    contract_path.as_str(),
+                    solx_codegen_evm::CFGType::EthIR,
+                    ethereal_ir.functions().map(Function::ethir_cfg).collect(),
+                    is_size_fallback,
+                    spill_area_size,
+                )?;
+            }
+            if output_cfg_evmla {
+                output_config.dump_cfg(
+                    contract_path.as_str(),
+                    solx_codegen_evm::CFGType::EVMLA,
+                    ethereal_ir
+                        .functions()
+                        .map(|function| function.evmla_cfg(&blocks))
+                        .collect(),
+                    is_size_fallback,
+                    spill_area_size,
+                )?;
+            }
+        }
+
         let mut entry = solx_codegen_evm::EntryFunction::new(ethereal_ir);
         entry.declare(context)?;
         entry.into_llvm(context)?;
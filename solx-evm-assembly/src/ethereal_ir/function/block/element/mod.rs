@@ -56,6 +56,26 @@ impl Element {
         }
     }
 
+    ///
+    /// Returns the captured stack state: the untouched part of the stack, followed by the
+    /// instruction inputs and outputs.
+    ///
+    pub fn stack_state(&self) -> String {
+        let mut stack = self.stack.to_owned();
+        for _ in 0..self.stack_output.len() {
+            let _ = stack.pop();
+        }
+
+        let mut state = stack.to_string();
+        if !self.stack_input.is_empty() {
+            state.push_str(format!(" - {}", self.stack_input).as_str());
+        }
+        if !self.stack_output.is_empty() {
+            state.push_str(format!(" + {}", self.stack_output).as_str());
+        }
+        state
+    }
+
     ///
     /// Pops the specified number of arguments, converted into their LLVM values.
     ///
@@ -94,6 +114,13 @@ impl solx_codegen_evm::WriteLLVM for Element {
             solidity_data.set_debug_info_solc_location(solc_location);
         }
 
+        if let Some(opcode) = self.instruction.name.evm_opcode() {
+            context
+                .evm_version()
+                .check_opcode(opcode, solx_utils::DiagnosticComponent::EVMLA)
+                .map_err(|diagnostic| diagnostic.with_location(context.diagnostic_location()))?;
+        }
+
         let result = match self.instruction.name.clone() {
             InstructionName::PUSH0 => Ok(Some(context.field_const(0).as_basic_value_enum())),
             InstructionName::PUSH
@@ -1070,17 +1097,38 @@ impl solx_codegen_evm::WriteLLVM for Element {
                     && std::env::var(solx_utils::ENV_DISABLE_UNSAFE_MEMORY_ASM_STACK_TOO_DEEP_CHECK)
                         .is_err()
                 {
-                    anyhow::bail!(solx_utils::ERROR_UNSAFE_MEMORY_ASM_STACK_TOO_DEEP);
+                    anyhow::bail!(
+                        solx_utils::Diagnostic::new(
+                            solx_utils::DiagnosticCode::UnsafeMemoryAssembly,
+                            solx_utils::DiagnosticComponent::EVMLA,
+                            solx_utils::ERROR_UNSAFE_MEMORY_ASM_STACK_TOO_DEEP,
+                        )
+                        .with_location(context.diagnostic_location())
+                    );
                 }
                 Ok(None)
             }
 
             InstructionName::CALLCODE => {
                 let mut _arguments = self.pop_arguments_llvm(context)?;
-                anyhow::bail!("The `CALLCODE` instruction is not supported");
+                anyhow::bail!(
+                    solx_utils::Diagnostic::new(
+                        solx_utils::DiagnosticCode::UnsupportedInstruction,
+                        solx_utils::DiagnosticComponent::EVMLA,
+                        "The `CALLCODE` instruction is not supported",
+                    )
+                    .with_location(context.diagnostic_location())
+                );
             }
             InstructionName::PC => {
-                anyhow::bail!("The `PC` instruction is not supported");
+                anyhow::bail!(
+                    solx_utils::Diagnostic::new(
+                        solx_utils::DiagnosticCode::UnsupportedInstruction,
+                        solx_utils::DiagnosticComponent::EVMLA,
+                        "The `PC` instruction is not supported",
+                    )
+                    .with_location(context.diagnostic_location())
+                );
             }
             InstructionName::SELFDESTRUCT => {
                 let arguments = self.pop_arguments_llvm(context)?;
@@ -1192,18 +1240,11 @@ impl solx_codegen_evm::WriteLLVM for Element {
 
 impl std::fmt::Display for Element {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let mut stack = self.stack.to_owned();
-        for _ in 0..self.stack_output.len() {
-            let _ = stack.pop();
-        }
-
-        write!(f, "{:80}{}", self.instruction.to_string(), stack)?;
-        if !self.stack_input.is_empty() {
-            write!(f, " - {}", self.stack_input)?;
-        }
-        if !self.stack_output.is_empty() {
-            write!(f, " + {}", self.stack_output)?;
-        }
-        Ok(())
+        write!(
+            f,
+            "{:80}{}",
+            self.instruction.to_string(),
+            self.stack_state()
+        )
     }
 }
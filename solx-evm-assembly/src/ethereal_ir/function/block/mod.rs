@@ -103,6 +103,46 @@ impl Block {
     pub fn insert_predecessor(&mut self, key: solx_codegen_evm::BlockKey, instance: usize) {
         self.predecessors.insert((key, instance));
     }
+
+    ///
+    /// Describes how the block transfers control to the `target` block.
+    ///
+    /// Returns the kind of the transfer, and whether its destination was resolved from the stack
+    /// rather than pushed right before the jump.
+    ///
+    pub fn control_transfer(&self, target: &solx_codegen_evm::BlockKey) -> (String, bool) {
+        if let Some(Element {
+            instruction:
+                Instruction {
+                    name: InstructionName::Tag,
+                    value: Some(tag),
+                    ..
+                },
+            ..
+        }) = self.elements.last()
+            && tag.parse::<u64>().ok() == Some(target.tag)
+        {
+            return ("fallthrough".to_owned(), false);
+        }
+
+        for (index, element) in self.elements.iter().enumerate().rev() {
+            let kind = match element.instruction.name {
+                InstructionName::RecursiveCall { ref name, .. } => {
+                    return (format!("call {name}"), false);
+                }
+                InstructionName::JUMP => "jump",
+                InstructionName::JUMPI => "jumpi",
+                _ => continue,
+            };
+            let is_dynamic = index
+                .checked_sub(1)
+                .and_then(|index| self.elements.get(index))
+                .is_none_or(|previous| previous.instruction.name != InstructionName::PUSH_Tag);
+            return (kind.to_owned(), is_dynamic);
+        }
+
+        ("jump".to_owned(), true)
+    }
 }
 
 impl solx_codegen_evm::WriteLLVM for Block {
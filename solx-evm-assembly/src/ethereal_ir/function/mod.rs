@@ -8,6 +8,7 @@ pub mod r#type;
 pub mod visited_element;
 
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 
 use inkwell::types::BasicType;
 use inkwell::values::BasicValue;
@@ -1407,6 +1408,110 @@ impl Function {
         ))
     }
 
+    ///
+    /// Builds the Ethereal IR control-flow graph, with a node per block instance annotated with
+    /// the stack state after each instruction.
+    ///
+    pub fn ethir_cfg(&self) -> solx_codegen_evm::DotGraph {
+        let mut graph = solx_codegen_evm::DotGraph::new(self.name.clone());
+        for blocks in self.blocks.values() {
+            for block in blocks.iter() {
+                let identifier = format!("{}/{}", block.key, block.instance.unwrap_or_default());
+                let mut lines = Vec::with_capacity(block.elements.len() + 1);
+                lines.push(format!("block_{identifier}:"));
+                lines.extend(
+                    block.elements.iter().map(|element| {
+                        format!("{}  {}", element.instruction, element.stack_state())
+                    }),
+                );
+                graph.add_node(identifier.clone(), lines);
+
+                for (predecessor_key, predecessor_instance) in block.predecessors.iter() {
+                    let Some(predecessor) =
+                        self.block_instance(predecessor_key, *predecessor_instance)
+                    else {
+                        continue;
+                    };
+                    let (kind, is_dynamic) = predecessor.control_transfer(&block.key);
+                    graph.add_edge(
+                        format!("{predecessor_key}/{predecessor_instance}"),
+                        identifier.clone(),
+                        Some(Self::cfg_edge_label(kind, is_dynamic)),
+                        is_dynamic,
+                    );
+                }
+            }
+        }
+        graph
+    }
+
+    ///
+    /// Builds the EVM legacy assembly control-flow graph, with a node per original block.
+    ///
+    /// The edges are resolved by the Ethereal IR traversal, so the dynamic jumps are included.
+    ///
+    pub fn evmla_cfg(
+        &self,
+        blocks: &FxHashMap<solx_codegen_evm::BlockKey, Block>,
+    ) -> solx_codegen_evm::DotGraph {
+        let mut graph = solx_codegen_evm::DotGraph::new(self.name.clone());
+        let mut edges = BTreeSet::new();
+        for (key, instances) in self.blocks.iter() {
+            let mut lines = vec![format!("block_{key}:")];
+            if let Some(block) = blocks.get(key) {
+                lines.extend(
+                    block
+                        .elements
+                        .iter()
+                        .map(|element| element.instruction.to_string()),
+                );
+            }
+            graph.add_node(key.to_string(), lines);
+
+            for block in instances.iter() {
+                for (predecessor_key, predecessor_instance) in block.predecessors.iter() {
+                    let Some(predecessor) =
+                        self.block_instance(predecessor_key, *predecessor_instance)
+                    else {
+                        continue;
+                    };
+                    let (kind, is_dynamic) = predecessor.control_transfer(key);
+                    edges.insert((
+                        predecessor_key.to_string(),
+                        key.to_string(),
+                        Self::cfg_edge_label(kind, is_dynamic),
+                        is_dynamic,
+                    ));
+                }
+            }
+        }
+        for (from, to, label, is_dynamic) in edges.into_iter() {
+            graph.add_edge(from, to, Some(label), is_dynamic);
+        }
+        graph
+    }
+
+    ///
+    /// Returns the block instance by its key and instance index.
+    ///
+    fn block_instance(&self, key: &solx_codegen_evm::BlockKey, instance: usize) -> Option<&Block> {
+        self.blocks
+            .get(key)?
+            .iter()
+            .find(|block| block.instance == Some(instance))
+    }
+
+    ///
+    /// Formats the control-flow graph edge label.
+    ///
+    fn cfg_edge_label(kind: String, is_dynamic: bool) -> String {
+        if is_dynamic {
+            format!("{kind} (dynamic)")
+        } else {
+            kind
+        }
+    }
+
     ///
     /// Finalizes the function data.
     ///
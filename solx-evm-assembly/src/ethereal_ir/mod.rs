@@ -46,7 +46,7 @@ impl EtherealIR {
         solc_version: semver::Version,
         extra_metadata: ExtraMetadata,
         code_segment: solx_utils::CodeSegment,
-        blocks: FxHashMap<solx_codegen_evm::BlockKey, Block>,
+        blocks: &FxHashMap<solx_codegen_evm::BlockKey, Block>,
         capture_stacks: bool,
     ) -> anyhow::Result<Self> {
         let mut entry_function = Function::new(
@@ -58,7 +58,7 @@ impl EtherealIR {
         let mut defined_functions = BTreeMap::new();
         let mut visited_functions = FxHashSet::default();
         entry_function.traverse(
-            &blocks,
+            blocks,
             &mut defined_functions,
             &extra_metadata,
             &mut visited_functions,
@@ -70,6 +70,13 @@ impl EtherealIR {
         })
     }
 
+    ///
+    /// Returns the entry function followed by the defined ones.
+    ///
+    pub fn functions(&self) -> impl Iterator<Item = &Function> {
+        std::iter::once(&self.entry_function).chain(self.defined_functions.values())
+    }
+
     ///
     /// Gets blocks for the specified type of the contract code.
     ///
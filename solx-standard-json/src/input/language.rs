@@ -15,3 +15,13 @@ pub enum Language {
     #[serde(rename = "LLVM IR")]
     LLVMIR,
 }
+
+impl std::fmt::Display for Language {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Solidity => write!(f, "Solidity"),
+            Self::Yul => write!(f, "Yul"),
+            Self::LLVMIR => write!(f, "LLVM IR"),
+        }
+    }
+}
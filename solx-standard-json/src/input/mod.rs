@@ -5,6 +5,7 @@
 pub mod language;
 pub mod settings;
 pub mod source;
+pub mod validator;
 
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
@@ -17,10 +18,13 @@ use rayon::iter::ParallelIterator;
 use crate::input::settings::metadata::Metadata as InputSettingsMetadata;
 use crate::input::settings::optimizer::Optimizer as InputSettingsOptimizer;
 use crate::input::settings::selection::Selection as InputSettingsSelection;
+use crate::output::error::Error as OutputError;
 
 use self::language::Language;
 use self::settings::Settings;
 use self::source::Source;
+use self::source::resolver::Resolver as SourceResolver;
+use self::validator::Validator;
 
 ///
 /// The `solc --standard-json` input.
@@ -43,6 +47,47 @@ impl Input {
     /// If the `path` is `None`, the input is read from the stdin.
     ///
     pub fn try_from(path: Option<&Path>) -> anyhow::Result<Self> {
+        let input_json = Self::read(path)?;
+        solx_utils::deserialize_from_str::<Self>(input_json.as_str())
+            .map_err(|error| anyhow::anyhow!("Standard JSON parsing: {error}"))
+    }
+
+    ///
+    /// A shortcut constructor, which validates the input before deserializing it.
+    ///
+    /// Every problem found is pushed to `messages` separately. Returns `None` if any of them is
+    /// an error, as the input cannot be deserialized then.
+    ///
+    /// If the `path` is `None`, the input is read from the stdin.
+    ///
+    pub fn try_from_validated(
+        path: Option<&Path>,
+        messages: &mut Vec<OutputError>,
+    ) -> anyhow::Result<Option<Self>> {
+        let input_json = Self::read(path)?;
+        let input_json = solx_utils::deserialize_from_str::<serde_json::Value>(input_json.as_str())
+            .map_err(|error| anyhow::anyhow!("Standard JSON parsing: {error}"))?;
+
+        let validation_messages = Validator::validate(&input_json);
+        let is_valid = validation_messages
+            .iter()
+            .all(|message| message.severity != "error");
+        messages.extend(validation_messages);
+        if !is_valid {
+            return Ok(None);
+        }
+
+        serde_json::from_value::<Self>(input_json)
+            .map(Some)
+            .map_err(|error| anyhow::anyhow!("Standard JSON parsing: {error}"))
+    }
+
+    ///
+    /// Reads the raw input JSON.
+    ///
+    /// If the `path` is `None`, the input is read from the stdin.
+    ///
+    fn read(path: Option<&Path>) -> anyhow::Result<String> {
         let input_json = match path {
             Some(path) if path.to_string_lossy() == Source::STDIN_INPUT_IDENTIFIER => {
                 std::io::read_to_string(std::io::stdin())
@@ -58,8 +103,7 @@ impl Input {
                 anyhow::anyhow!("Standard JSON input debug file `{output_path}` writing: {error}")
             })?;
         }
-        solx_utils::deserialize_from_str::<Self>(input_json.as_str())
-            .map_err(|error| anyhow::anyhow!("Standard JSON parsing: {error}"))
+        Ok(input_json)
     }
 
     ///
@@ -207,4 +251,19 @@ impl Input {
         }
         Ok(())
     }
+
+    ///
+    /// Resolves the sources with `resolvers`, trying each URL of a source in order.
+    ///
+    pub fn resolve_sources_with(
+        &mut self,
+        resolvers: &[&dyn SourceResolver],
+    ) -> anyhow::Result<()> {
+        for (path, source) in self.sources.iter_mut() {
+            source
+                .try_resolve_with(resolvers)
+                .map_err(|error| anyhow::anyhow!("Source `{path}` resolving: {error}"))?;
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,55 @@
+//!
+//! The `solc --standard-json` input settings resource limits.
+//!
+
+use std::time::Duration;
+
+///
+/// The `solc --standard-json` input settings resource limits.
+///
+/// Applied by solx to every translation unit compiled in a worker subprocess.
+///
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Limits {
+    /// The wall-clock time limit in seconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<u64>,
+    /// The resident memory limit in megabytes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory: Option<u64>,
+}
+
+impl Limits {
+    /// The number of bytes in a megabyte.
+    const BYTES_PER_MEGABYTE: u64 = 1024 * 1024;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(timeout: Option<u64>, memory: Option<u64>) -> Self {
+        Self { timeout, memory }
+    }
+
+    ///
+    /// Whether no limits are set.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.timeout.is_none() && self.memory.is_none()
+    }
+
+    ///
+    /// Returns the wall-clock time limit.
+    ///
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout.map(Duration::from_secs)
+    }
+
+    ///
+    /// Returns the resident memory limit in bytes.
+    ///
+    pub fn memory_bytes(&self) -> Option<u64> {
+        self.memory
+            .map(|memory| memory.saturating_mul(Self::BYTES_PER_MEGABYTE))
+    }
+}
@@ -3,16 +3,20 @@
 //!
 
 pub mod debug;
+pub mod limits;
 pub mod metadata;
 pub mod optimizer;
 pub mod selection;
+pub mod warnings;
 
 use std::collections::BTreeSet;
 
 use self::debug::Debug;
+use self::limits::Limits;
 use self::metadata::Metadata;
 use self::optimizer::Optimizer;
 use self::selection::Selection;
+use self::warnings::Warnings;
 
 ///
 /// The `solc --standard-json` input settings.
@@ -56,6 +60,12 @@ pub struct Settings {
     /// The extra LLVM options.
     #[serde(default, skip_serializing)]
     pub llvm_options: Vec<String>,
+    /// The solx warning suppression and promotion rules.
+    #[serde(default, skip_serializing)]
+    pub warnings: Warnings,
+    /// The solx per-translation-unit resource limits.
+    #[serde(default, skip_serializing)]
+    pub limits: Limits,
 }
 
 impl Settings {
@@ -93,6 +103,8 @@ impl Settings {
 
             debug,
             llvm_options,
+            warnings: Warnings::default(),
+            limits: Limits::default(),
         }
     }
 
@@ -23,6 +23,12 @@ pub struct Optimizer {
         skip_serializing_if = "Option::is_none"
     )]
     pub size_fallback: Option<bool>,
+    /// The expected number of contract executions, trading deploy size for runtime gas.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub runs: Option<u64>,
+    /// Whether low `runs` switch the default mode to optimizing for size.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size_for_low_runs: Option<bool>,
 }
 
 impl Default for Optimizer {
@@ -31,6 +37,8 @@ impl Default for Optimizer {
             enabled: None,
             mode: Self::default_mode(),
             size_fallback: Self::default_size_fallback(),
+            runs: None,
+            size_for_low_runs: None,
         }
     }
 }
@@ -0,0 +1,72 @@
+//!
+//! The `solc --standard-json` input settings warning rules.
+//!
+
+pub mod rule;
+
+use self::rule::Rule;
+
+///
+/// The `solc --standard-json` input settings warning rules.
+///
+/// Only applies to solx diagnostics. The `solc` warnings are controlled by `solc` itself.
+///
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Warnings {
+    /// The rules silencing matching warnings.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ignore: Vec<Rule>,
+    /// The rules promoting matching warnings to errors.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub error: Vec<Rule>,
+}
+
+impl Warnings {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(ignore: Vec<Rule>, error: Vec<Rule>) -> Self {
+        Self { ignore, error }
+    }
+
+    ///
+    /// Whether no rules are set.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.ignore.is_empty() && self.error.is_empty()
+    }
+
+    ///
+    /// Applies the rules to a diagnostic raised for `contract_name`.
+    ///
+    /// Returns `None` if the warning is silenced. Suppression takes precedence over promotion.
+    /// Diagnostics that are not warnings are returned as is.
+    ///
+    pub fn apply(
+        &self,
+        diagnostic: solx_utils::Diagnostic,
+        contract_name: &solx_utils::ContractName,
+    ) -> Option<solx_utils::Diagnostic> {
+        if diagnostic.severity() != solx_utils::DiagnosticSeverity::Warning {
+            return Some(diagnostic);
+        }
+
+        let code = diagnostic.code.code();
+        if self
+            .ignore
+            .iter()
+            .any(|rule| rule.matches(code, contract_name))
+        {
+            return None;
+        }
+        if self
+            .error
+            .iter()
+            .any(|rule| rule.matches(code, contract_name))
+        {
+            return Some(diagnostic.with_severity(solx_utils::DiagnosticSeverity::Error));
+        }
+        Some(diagnostic)
+    }
+}
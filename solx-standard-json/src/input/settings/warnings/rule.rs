@@ -0,0 +1,118 @@
+//!
+//! The `solc --standard-json` input settings warning rule.
+//!
+
+use std::str::FromStr;
+
+///
+/// The `solc --standard-json` input settings warning rule.
+///
+/// The string representation is `<code>[@<path-glob>]`, where `<code>` is either a numeric
+/// diagnostic code or `*` matching any code, and the optional glob restricts the rule to
+/// contracts whose path or full path matches it.
+///
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Rule {
+    /// The diagnostic code. `None` matches any code.
+    pub code: Option<isize>,
+    /// The contract path pattern. `None` matches any contract.
+    pub path: Option<glob::Pattern>,
+}
+
+impl Rule {
+    /// The wildcard matching any diagnostic code.
+    pub const ANY_CODE: &'static str = "*";
+
+    /// The code and path separator.
+    pub const PATH_SEPARATOR: char = '@';
+
+    ///
+    /// Whether the rule matches the diagnostic `code` raised for `contract_name`.
+    ///
+    pub fn matches(&self, code: isize, contract_name: &solx_utils::ContractName) -> bool {
+        self.code.is_none_or(|rule_code| rule_code == code)
+            && self.path.as_ref().is_none_or(|pattern| {
+                pattern.matches(contract_name.path.as_str())
+                    || pattern.matches(contract_name.full_path.as_str())
+            })
+    }
+}
+
+impl FromStr for Rule {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let (code, path) = match string.split_once(Self::PATH_SEPARATOR) {
+            Some((code, path)) => (code, Some(path)),
+            None => (string, None),
+        };
+
+        let code = match code.trim() {
+            Self::ANY_CODE => None,
+            code => Some(code.parse::<isize>().map_err(|error| {
+                anyhow::anyhow!("Invalid warning code `{code}` in rule `{string}`: {error}")
+            })?),
+        };
+        let path = path
+            .map(|path| {
+                glob::Pattern::new(path).map_err(|error| {
+                    anyhow::anyhow!("Invalid path pattern `{path}` in rule `{string}`: {error}")
+                })
+            })
+            .transpose()?;
+
+        Ok(Self { code, path })
+    }
+}
+
+impl TryFrom<String> for Rule {
+    type Error = anyhow::Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::from_str(value.as_str())
+    }
+}
+
+impl From<Rule> for String {
+    fn from(rule: Rule) -> Self {
+        rule.to_string()
+    }
+}
+
+impl std::fmt::Display for Rule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.code {
+            Some(code) => write!(f, "{code}")?,
+            None => write!(f, "{}", Self::ANY_CODE)?,
+        }
+        if let Some(path) = self.path.as_ref() {
+            write!(f, "{}{path}", Self::PATH_SEPARATOR)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::Rule;
+
+    #[test]
+    fn scoped_rule_matches_path_glob() {
+        let rule = Rule::from_str("5574@src/big/*.sol").expect("Always valid");
+        let matching = solx_utils::ContractName::new("src/big/Token.sol".to_owned(), None);
+        let other = solx_utils::ContractName::new("src/small/Token.sol".to_owned(), None);
+
+        assert!(rule.matches(5574, &matching));
+        assert!(!rule.matches(3860, &matching));
+        assert!(!rule.matches(5574, &other));
+        assert_eq!(rule.to_string(), "5574@src/big/*.sol");
+    }
+
+    #[test]
+    fn invalid_code() {
+        assert!(Rule::from_str("size@*.sol").is_err());
+    }
+}
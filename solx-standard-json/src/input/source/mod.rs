@@ -2,8 +2,15 @@
 //! The `solc --standard-json` input source.
 //!
 
+pub mod resolver;
+pub mod url;
+
 use std::path::Path;
-use std::path::PathBuf;
+use std::str::FromStr;
+
+use self::resolver::Resolver;
+use self::resolver::file_system::FileSystem as FileSystemResolver;
+use self::url::URL;
 
 ///
 /// The `solc --standard-json` input source.
@@ -17,6 +24,9 @@ pub struct Source {
     /// The source file URLs.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub urls: Option<Vec<String>>,
+    /// The expected `keccak256` hash of the source code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keccak256: Option<String>,
 }
 
 impl Source {
@@ -42,40 +52,49 @@ impl Source {
         Ok(Self {
             content: Some(content),
             urls: None,
+            keccak256: None,
         })
     }
 
     ///
-    /// Tries to resolve the source code.
-    ///
-    /// At the moment only one URL pointing to the file system is supported.
+    /// Tries to resolve the source code from the file system.
     ///
     pub fn try_resolve(&mut self) -> anyhow::Result<()> {
+        self.try_resolve_with(&[&FileSystemResolver])
+    }
+
+    ///
+    /// Tries to resolve the source code with `resolvers`.
+    ///
+    /// The URLs are tried in order, each with the first resolver supporting it. The first
+    /// content matching the `keccak256` hash, if the latter is set, is taken.
+    ///
+    pub fn try_resolve_with(&mut self, resolvers: &[&dyn Resolver]) -> anyhow::Result<()> {
         match (self.content.as_ref(), self.urls.as_ref()) {
             (Some(_), None) => Ok(()),
             (None, Some(urls)) => {
                 let mut errors = Vec::with_capacity(urls.len());
                 for url in urls.iter() {
-                    let url_path = PathBuf::from(url);
-                    match Source::try_from_path(url_path.as_path()) {
-                        Ok(resolved) => {
-                            *self = resolved;
-                            break;
+                    match self.try_resolve_url(url.as_str(), resolvers) {
+                        Ok(content) => {
+                            self.content = Some(content);
+                            self.urls = None;
+                            return Ok(());
                         }
                         Err(error) => errors.push(error),
                     }
                 }
-                if !errors.is_empty() {
-                    anyhow::bail!(
-                        "{}",
-                        errors
-                            .into_iter()
-                            .map(|error| error.to_string())
-                            .collect::<Vec<String>>()
-                            .join("\n")
-                    );
+                if errors.is_empty() {
+                    anyhow::bail!("No URLs to resolve the source from.");
                 }
-                Ok(())
+                anyhow::bail!(
+                    "{}",
+                    errors
+                        .into_iter()
+                        .map(|error| error.to_string())
+                        .collect::<Vec<String>>()
+                        .join("\n")
+                );
             }
             (Some(_), Some(_)) => anyhow::bail!("Both `content` and `urls` cannot be set."),
             (None, None) => anyhow::bail!("Either `content` or `urls` must be set."),
@@ -95,6 +114,33 @@ impl Source {
     pub fn content(&self) -> Option<&str> {
         self.content.as_deref()
     }
+
+    ///
+    /// Resolves a single URL and checks the content hash.
+    ///
+    fn try_resolve_url(&self, url: &str, resolvers: &[&dyn Resolver]) -> anyhow::Result<String> {
+        let url = URL::from_str(url)?;
+        let resolver = resolvers
+            .iter()
+            .find(|resolver| resolver.supports(&url))
+            .ok_or_else(|| anyhow::anyhow!("No resolver is configured for URL `{url}`."))?;
+        let content = resolver.resolve(&url)?;
+
+        if let Some(expected) = self.keccak256.as_deref() {
+            let actual = solx_utils::Keccak256Hash::from_slice(content.as_bytes());
+            if !actual
+                .as_str()
+                .trim_start_matches("0x")
+                .eq_ignore_ascii_case(expected.trim_start_matches("0x"))
+            {
+                anyhow::bail!(
+                    "Source content from URL `{url}` does not match the `keccak256` hash: expected {expected}, found {actual}."
+                );
+            }
+        }
+
+        Ok(content)
+    }
 }
 
 impl From<&Path> for Source {
@@ -102,6 +148,7 @@ impl From<&Path> for Source {
         Self {
             content: None,
             urls: Some(vec![path.to_string_lossy().to_string()]),
+            keccak256: None,
         }
     }
 }
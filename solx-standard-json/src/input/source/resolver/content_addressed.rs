@@ -0,0 +1,69 @@
+//!
+//! The content-addressed directory source resolver.
+//!
+
+use std::path::PathBuf;
+
+use crate::input::source::url::URL;
+
+use super::Resolver;
+
+///
+/// The content-addressed directory source resolver.
+///
+/// Resolves `ipfs://<CIDv0>` URLs to `<directory>/ipfs/<CIDv0>` and `bzz-raw://<hash>` URLs
+/// to `<directory>/bzz-raw/<hash>`. The file content is rejected unless it hashes to the
+/// address it is stored under, so an archive can be trusted as much as the network it mirrors.
+///
+#[derive(Debug, Clone)]
+pub struct ContentAddressed {
+    /// The archive root directory.
+    pub directory: PathBuf,
+}
+
+impl ContentAddressed {
+    /// The IPFS subdirectory name.
+    pub const DIRECTORY_IPFS: &'static str = "ipfs";
+
+    /// The Swarm subdirectory name.
+    pub const DIRECTORY_SWARM: &'static str = "bzz-raw";
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(directory: PathBuf) -> Self {
+        Self { directory }
+    }
+}
+
+impl Resolver for ContentAddressed {
+    fn supports(&self, url: &URL) -> bool {
+        matches!(url, URL::IPFS(_) | URL::Swarm(_))
+    }
+
+    fn resolve(&self, url: &URL) -> anyhow::Result<String> {
+        let (subdirectory, address, hash): (&str, &str, fn(&[u8]) -> String) = match url {
+            URL::IPFS(address) => (Self::DIRECTORY_IPFS, address.as_str(), |content| {
+                solx_utils::IPFSHash::from_slice(content)
+                    .as_base58()
+                    .to_owned()
+            }),
+            URL::Swarm(address) => (Self::DIRECTORY_SWARM, address.as_str(), |content| {
+                solx_utils::SwarmHash::from_slice(content).to_string()
+            }),
+            URL::File(_) => anyhow::bail!("URL `{url}` is not content-addressed."),
+        };
+
+        let mut path = self.directory.join(subdirectory);
+        path.push(address);
+        let content = std::fs::read_to_string(path.as_path())
+            .map_err(|error| anyhow::anyhow!("File {path:?} reading: {error}"))?;
+
+        let actual = hash(content.as_bytes());
+        if !actual.eq_ignore_ascii_case(address) {
+            anyhow::bail!("File {path:?} content hash `{actual}` does not match URL `{url}`.");
+        }
+
+        Ok(content)
+    }
+}
@@ -0,0 +1,32 @@
+//!
+//! The file system source resolver.
+//!
+
+use crate::input::source::Source;
+use crate::input::source::url::URL;
+
+use super::Resolver;
+
+///
+/// The file system source resolver.
+///
+/// Reads bare paths and `file://` URLs relative to the current working directory,
+/// as well as `stdin` if the path is `-`.
+///
+#[derive(Debug, Default, Clone, Copy)]
+pub struct FileSystem;
+
+impl Resolver for FileSystem {
+    fn supports(&self, url: &URL) -> bool {
+        matches!(url, URL::File(_))
+    }
+
+    fn resolve(&self, url: &URL) -> anyhow::Result<String> {
+        let URL::File(path) = url else {
+            anyhow::bail!("URL `{url}` does not point to the file system.");
+        };
+        Ok(Source::try_from_path(path.as_path())?
+            .take_content()
+            .expect("Always exists"))
+    }
+}
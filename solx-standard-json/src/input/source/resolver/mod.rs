@@ -0,0 +1,26 @@
+//!
+//! The `solc --standard-json` input source resolver.
+//!
+
+pub mod content_addressed;
+pub mod file_system;
+
+use super::url::URL;
+
+///
+/// The `solc --standard-json` input source resolver.
+///
+/// Resolvers are tried in order for each URL of a source, so a resolver only needs to
+/// handle the URL kinds it supports.
+///
+pub trait Resolver: Sync {
+    ///
+    /// Whether the resolver is able to handle `url`.
+    ///
+    fn supports(&self, url: &URL) -> bool;
+
+    ///
+    /// Reads the source code `url` points to.
+    ///
+    fn resolve(&self, url: &URL) -> anyhow::Result<String>;
+}
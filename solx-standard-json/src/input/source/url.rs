@@ -0,0 +1,69 @@
+//!
+//! The `solc --standard-json` input source URL.
+//!
+
+use std::path::PathBuf;
+use std::str::FromStr;
+
+///
+/// The `solc --standard-json` input source URL.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum URL {
+    /// The file system path, either bare or with the `file://` scheme.
+    File(PathBuf),
+    /// The IPFS CIDv0, with the `ipfs://` or `dweb:/ipfs/` scheme.
+    IPFS(String),
+    /// The `bzzr1` Swarm hash, with the `bzz-raw://` scheme.
+    Swarm(String),
+}
+
+impl URL {
+    /// The file system scheme.
+    pub const SCHEME_FILE: &'static str = "file://";
+
+    /// The IPFS scheme.
+    pub const SCHEME_IPFS: &'static str = "ipfs://";
+
+    /// The IPFS scheme used by `solc` metadata.
+    pub const SCHEME_IPFS_DWEB: &'static str = "dweb:/ipfs/";
+
+    /// The Swarm scheme.
+    pub const SCHEME_SWARM: &'static str = "bzz-raw://";
+
+    /// The generic scheme separator.
+    const SCHEME_SEPARATOR: &'static str = "://";
+}
+
+impl FromStr for URL {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        if let Some(path) = string.strip_prefix(Self::SCHEME_FILE) {
+            return Ok(Self::File(PathBuf::from(path)));
+        }
+        if let Some(hash) = string
+            .strip_prefix(Self::SCHEME_IPFS)
+            .or_else(|| string.strip_prefix(Self::SCHEME_IPFS_DWEB))
+        {
+            return Ok(Self::IPFS(hash.to_owned()));
+        }
+        if let Some(hash) = string.strip_prefix(Self::SCHEME_SWARM) {
+            return Ok(Self::Swarm(hash.trim_start_matches("0x").to_owned()));
+        }
+        if let Some((scheme, _)) = string.split_once(Self::SCHEME_SEPARATOR) {
+            anyhow::bail!("Unsupported URL scheme `{scheme}` in `{string}`.");
+        }
+        Ok(Self::File(PathBuf::from(string)))
+    }
+}
+
+impl std::fmt::Display for URL {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::File(path) => write!(f, "{}", path.to_string_lossy()),
+            Self::IPFS(hash) => write!(f, "{}{hash}", Self::SCHEME_IPFS),
+            Self::Swarm(hash) => write!(f, "{}{hash}", Self::SCHEME_SWARM),
+        }
+    }
+}
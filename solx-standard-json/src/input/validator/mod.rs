@@ -0,0 +1,345 @@
+//!
+//! The `solc --standard-json` input validator.
+//!
+
+pub mod schema;
+
+use serde::Deserialize;
+
+use crate::input::language::Language;
+use crate::output::error::Error as OutputError;
+
+use self::schema::Schema;
+use self::schema::Support;
+
+///
+/// The `solc --standard-json` input validator.
+///
+/// Reports every unknown or unsupported key as a warning, and every malformed value as an error,
+/// each located by its JSON pointer.
+///
+#[derive(Debug)]
+pub struct Validator {
+    /// The input language, if it is valid.
+    language: Option<Language>,
+    /// The problems found so far.
+    messages: Vec<OutputError>,
+}
+
+impl Validator {
+    ///
+    /// Validates the raw input JSON.
+    ///
+    pub fn validate(input_json: &serde_json::Value) -> Vec<OutputError> {
+        let language = input_json
+            .get("language")
+            .and_then(|language| Language::deserialize(language).ok());
+        let mut validator = Self {
+            language,
+            messages: Vec::new(),
+        };
+        validator.visit(String::new(), input_json, &Schema::input());
+        validator.messages
+    }
+
+    ///
+    /// Validates `value` located at `pointer` against `schema`.
+    ///
+    fn visit(&mut self, pointer: String, value: &serde_json::Value, schema: &Schema) {
+        match (schema, value) {
+            (Schema::Value(check), value) => {
+                if let Err(error) = check(value) {
+                    self.error(pointer.as_str(), error);
+                }
+            }
+            (Schema::Object(fields), serde_json::Value::Object(object)) => {
+                for (key, value) in object.iter() {
+                    let pointer = Self::child(pointer.as_str(), key.as_str());
+                    match fields.iter().find(|field| field.key == key.as_str()) {
+                        Some(field) => match (&field.support, self.language) {
+                            (Support::Supported(schema), _)
+                            | (Support::Solidity(schema), None | Some(Language::Solidity)) => {
+                                self.visit(pointer, value, schema)
+                            }
+                            (Support::Solidity(_), Some(language)) => self.warning(
+                                pointer.as_str(),
+                                format!(
+                                    "only supported for Solidity input, ignored for {language} input"
+                                ),
+                            ),
+                            (Support::Unsupported, _) => {
+                                self.warning(pointer.as_str(), "not supported by solx, ignored")
+                            }
+                        },
+                        None => self.warning(pointer.as_str(), "unknown key, ignored"),
+                    }
+                }
+            }
+            (Schema::Map(schema), serde_json::Value::Object(object)) => {
+                for (key, value) in object.iter() {
+                    self.visit(Self::child(pointer.as_str(), key.as_str()), value, schema);
+                }
+            }
+            (Schema::Array(schema), serde_json::Value::Array(array)) => {
+                for (index, value) in array.iter().enumerate() {
+                    self.visit(
+                        Self::child(pointer.as_str(), index.to_string().as_str()),
+                        value,
+                        schema,
+                    );
+                }
+            }
+            (Schema::Object(_) | Schema::Map(_), value) => self.error(
+                pointer.as_str(),
+                format!("expected an object, found {}", Self::kind(value)),
+            ),
+            (Schema::Array(_), value) => self.error(
+                pointer.as_str(),
+                format!("expected an array, found {}", Self::kind(value)),
+            ),
+        }
+    }
+
+    ///
+    /// Pushes an error located at `pointer`.
+    ///
+    fn error<S>(&mut self, pointer: &str, message: S)
+    where
+        S: std::fmt::Display,
+    {
+        self.messages
+            .push(OutputError::new_error(Self::message(pointer, message)));
+    }
+
+    ///
+    /// Pushes a warning located at `pointer`.
+    ///
+    fn warning<S>(&mut self, pointer: &str, message: S)
+    where
+        S: std::fmt::Display,
+    {
+        self.messages
+            .push(OutputError::new_warning(Self::message(pointer, message)));
+    }
+
+    ///
+    /// Formats a message located at `pointer`.
+    ///
+    fn message<S>(pointer: &str, message: S) -> String
+    where
+        S: std::fmt::Display,
+    {
+        let pointer = if pointer.is_empty() { "/" } else { pointer };
+        format!("Standard JSON input `{pointer}`: {message}.")
+    }
+
+    ///
+    /// Appends `key` to `pointer`, escaped as per RFC 6901.
+    ///
+    fn child(pointer: &str, key: &str) -> String {
+        format!("{pointer}/{}", key.replace('~', "~0").replace('/', "~1"))
+    }
+
+    ///
+    /// Returns the JSON type name of `value`.
+    ///
+    fn kind(value: &serde_json::Value) -> &'static str {
+        match value {
+            serde_json::Value::Null => "null",
+            serde_json::Value::Bool(_) => "a boolean",
+            serde_json::Value::Number(_) => "a number",
+            serde_json::Value::String(_) => "a string",
+            serde_json::Value::Array(_) => "an array",
+            serde_json::Value::Object(_) => "an object",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::collections::BTreeSet;
+    use std::str::FromStr;
+
+    use crate::input::Input;
+    use crate::input::language::Language;
+    use crate::input::settings::Settings;
+    use crate::input::settings::debug::Debug;
+    use crate::input::settings::limits::Limits;
+    use crate::input::settings::metadata::Metadata;
+    use crate::input::settings::optimizer::Optimizer;
+    use crate::input::settings::selection::Selection;
+    use crate::input::settings::selection::selector::Selector;
+    use crate::input::settings::warnings::Warnings;
+    use crate::input::settings::warnings::rule::Rule;
+    use crate::input::source::Source;
+
+    use super::Validator;
+
+    fn messages(input_json: serde_json::Value) -> Vec<(String, String)> {
+        Validator::validate(&input_json)
+            .into_iter()
+            .map(|message| (message.severity, message.message))
+            .collect()
+    }
+
+    #[test]
+    fn valid() {
+        let input_json = serde_json::json!({
+            "language": "Solidity",
+            "sources": { "contracts/A.sol": { "content": "contract A {}" } },
+            "settings": {
+                "optimizer": { "mode": "3", "runs": 200 },
+                "viaIR": true,
+                "outputSelection": { "*": { "*": ["abi", "evm.bytecode.object"] } },
+            },
+        });
+        assert!(messages(input_json).is_empty());
+    }
+
+    /// Every field of the input types must be known to the schema, so a fully populated input
+    /// must pass without messages. The solx-only settings are not forwarded to solc, so they are
+    /// not serialized with the rest and are added by hand.
+    #[test]
+    fn fully_populated_input() {
+        let source = Source {
+            content: Some("contract A {}".to_owned()),
+            urls: Some(vec!["contracts/A.sol".to_owned()]),
+            keccak256: Some("0x00".to_owned()),
+        };
+        let mut libraries = solx_utils::Libraries::default();
+        libraries.inner.insert(
+            "contracts/L.sol".to_owned(),
+            BTreeMap::from([(
+                "L".to_owned(),
+                "0x0000000000000000000000000000000000000001".to_owned(),
+            )]),
+        );
+        let mut settings = Settings::new(
+            Optimizer {
+                enabled: Some(true),
+                mode: Some('z'),
+                size_fallback: Some(true),
+                runs: Some(1_000),
+                size_for_low_runs: Some(true),
+            },
+            libraries,
+            BTreeSet::from(["@a/=lib/a/".to_owned()]),
+            Some(solx_utils::EVMVersion::Cancun),
+            true,
+            Selection::new(BTreeSet::from([
+                Selector::AST,
+                Selector::ABI,
+                Selector::BytecodeObject,
+            ])),
+            Metadata::new(true, false, solx_utils::MetadataHashType::IPFS),
+            Some(Debug {
+                revert_strings: Some("strip".to_owned()),
+            }),
+            vec!["-some-llvm-option".to_owned()],
+        );
+        settings.warnings = Warnings::new(
+            vec![Rule::from_str("5574@src/*.sol").expect("Always valid")],
+            vec![Rule::from_str("*").expect("Always valid")],
+        );
+        settings.limits = Limits::new(Some(60), Some(1024));
+        let input = Input {
+            language: Language::Solidity,
+            sources: BTreeMap::from([("contracts/A.sol".to_owned(), source)]),
+            settings,
+        };
+
+        let mut input_json = serde_json::to_value(&input).expect("Always valid");
+        let settings_json = input_json["settings"]
+            .as_object_mut()
+            .expect("Always an object");
+        settings_json.insert(
+            "llvmOptions".to_owned(),
+            serde_json::to_value(&input.settings.llvm_options).expect("Always valid"),
+        );
+        settings_json.insert(
+            "warnings".to_owned(),
+            serde_json::to_value(&input.settings.warnings).expect("Always valid"),
+        );
+        settings_json.insert(
+            "limits".to_owned(),
+            serde_json::to_value(&input.settings.limits).expect("Always valid"),
+        );
+        settings_json["metadata"]["bytecodeHash"] =
+            serde_json::to_value(input.settings.metadata.bytecode_hash).expect("Always valid");
+
+        assert!(messages(input_json).is_empty());
+    }
+
+    #[test]
+    fn unknown_and_unsupported_keys() {
+        let input_json = serde_json::json!({
+            "language": "Solidity",
+            "sources": { "contracts/A.sol": { "content": "contract A {}" } },
+            "settings": {
+                "optimizer": { "details": { "yul": true } },
+                "modelChecker": {},
+                "foo": 1,
+            },
+        });
+        assert_eq!(
+            messages(input_json),
+            vec![
+                (
+                    "warning".to_owned(),
+                    "Standard JSON input `/settings/foo`: unknown key, ignored.".to_owned()
+                ),
+                (
+                    "warning".to_owned(),
+                    "Standard JSON input `/settings/modelChecker`: not supported by solx, ignored."
+                        .to_owned()
+                ),
+                (
+                    "warning".to_owned(),
+                    "Standard JSON input `/settings/optimizer/details`: not supported by solx, ignored."
+                        .to_owned()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn solidity_only_keys() {
+        let input_json = serde_json::json!({
+            "language": "LLVM IR",
+            "sources": { "A.ll": { "content": "" } },
+            "settings": { "viaIR": true },
+        });
+        let messages = messages(input_json);
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].0, "warning");
+        assert!(messages[0].1.contains("`/settings/viaIR`"));
+    }
+
+    #[test]
+    fn type_mismatches() {
+        let input_json = serde_json::json!({
+            "language": "Solidity",
+            "sources": { "contracts/A.sol": { "content": 42 } },
+            "settings": {
+                "optimizer": { "runs": "200" },
+                "outputSelection": { "*": { "*": ["abi", "evm.unknown"] } },
+                "remappings": {},
+            },
+        });
+        let messages = messages(input_json);
+        assert!(messages.iter().all(|(severity, _)| severity == "error"));
+        assert!(messages[0].1.contains("`/settings/optimizer/runs`"));
+        assert!(messages[1].1.contains("`/settings/outputSelection/*/*/1`"));
+        assert!(
+            messages[2]
+                .1
+                .contains("`/settings/remappings`: expected an array")
+        );
+        assert!(
+            messages[3]
+                .1
+                .contains("`/sources/contracts~1A.sol/content`")
+        );
+    }
+}
@@ -0,0 +1,189 @@
+//!
+//! The `solc --standard-json` input schema.
+//!
+
+use crate::input::language::Language;
+use crate::input::settings::selection::selector::Selector;
+use crate::input::settings::warnings::rule::Rule;
+
+///
+/// The `solc --standard-json` input schema.
+///
+/// Mirrors the input types, so that problems can be reported at their exact location before
+/// the input is deserialized.
+///
+#[derive(Debug)]
+pub enum Schema {
+    /// A leaf value, checked by deserializing it into the corresponding input type.
+    Value(fn(&serde_json::Value) -> Result<(), serde_json::Error>),
+    /// An object with a fixed set of keys.
+    Object(Vec<Field>),
+    /// An object with arbitrary keys and values of the same schema.
+    Map(Box<Schema>),
+    /// An array of values of the same schema.
+    Array(Box<Schema>),
+}
+
+///
+/// The object field schema.
+///
+#[derive(Debug)]
+pub struct Field {
+    /// The field key.
+    pub key: &'static str,
+    /// The field support by solx.
+    pub support: Support,
+}
+
+///
+/// The object field support by solx.
+///
+#[derive(Debug)]
+pub enum Support {
+    /// The field is supported for all input languages.
+    Supported(Schema),
+    /// The field is only used for Solidity input.
+    Solidity(Schema),
+    /// The field is accepted by solc, but ignored by solx.
+    Unsupported,
+}
+
+impl Schema {
+    ///
+    /// The schema of the whole input.
+    ///
+    pub fn input() -> Self {
+        Self::Object(vec![
+            Field::supported("language", Self::value::<Language>()),
+            Field::supported("sources", Self::Map(Box::new(Self::source()))),
+            Field::supported("settings", Self::settings()),
+        ])
+    }
+
+    ///
+    /// The schema of a source.
+    ///
+    fn source() -> Self {
+        Self::Object(vec![
+            Field::supported("content", Self::value::<Option<String>>()),
+            Field::supported("urls", Self::value::<Option<Vec<String>>>()),
+            Field::supported("keccak256", Self::value::<Option<String>>()),
+        ])
+    }
+
+    ///
+    /// The schema of the settings.
+    ///
+    fn settings() -> Self {
+        Self::Object(vec![
+            Field::supported(
+                "optimizer",
+                Self::Object(vec![
+                    Field::supported("enabled", Self::value::<Option<bool>>()),
+                    Field::supported("mode", Self::value::<Option<char>>()),
+                    Field::supported("sizeFallback", Self::value::<Option<bool>>()),
+                    Field::supported("runs", Self::value::<Option<u64>>()),
+                    Field::supported("sizeForLowRuns", Self::value::<Option<bool>>()),
+                    Field::unsupported("details"),
+                ]),
+            ),
+            Field::supported(
+                "libraries",
+                Self::Map(Box::new(Self::Map(Box::new(Self::value::<String>())))),
+            ),
+            Field::solidity("remappings", Self::Array(Box::new(Self::value::<String>()))),
+            Field::supported(
+                "evmVersion",
+                Self::value::<Option<solx_utils::EVMVersion>>(),
+            ),
+            Field::unsupported("eofVersion"),
+            Field::solidity("viaIR", Self::value::<bool>()),
+            Field::supported(
+                "outputSelection",
+                Self::Map(Box::new(Self::Map(Box::new(Self::Array(Box::new(
+                    Self::value::<Selector>(),
+                )))))),
+            ),
+            Field::supported(
+                "metadata",
+                Self::Object(vec![
+                    Field::supported("useLiteralContent", Self::value::<bool>()),
+                    Field::supported("appendCBOR", Self::value::<bool>()),
+                    Field::supported(
+                        "bytecodeHash",
+                        Self::value::<solx_utils::MetadataHashType>(),
+                    ),
+                ]),
+            ),
+            Field::solidity(
+                "debug",
+                Self::Object(vec![
+                    Field::supported("revertStrings", Self::value::<Option<String>>()),
+                    Field::unsupported("debugInfo"),
+                ]),
+            ),
+            Field::unsupported("modelChecker"),
+            Field::unsupported("stopAfter"),
+            Field::supported(
+                "llvmOptions",
+                Self::Array(Box::new(Self::value::<String>())),
+            ),
+            Field::supported(
+                "warnings",
+                Self::Object(vec![
+                    Field::supported("ignore", Self::Array(Box::new(Self::value::<Rule>()))),
+                    Field::supported("error", Self::Array(Box::new(Self::value::<Rule>()))),
+                ]),
+            ),
+            Field::supported(
+                "limits",
+                Self::Object(vec![
+                    Field::supported("timeout", Self::value::<Option<u64>>()),
+                    Field::supported("memory", Self::value::<Option<u64>>()),
+                ]),
+            ),
+        ])
+    }
+
+    ///
+    /// A leaf value of type `T`.
+    ///
+    fn value<T>() -> Self
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        Self::Value(|value| T::deserialize(value).map(|_| ()))
+    }
+}
+
+impl Field {
+    ///
+    /// A field supported for all input languages.
+    ///
+    fn supported(key: &'static str, schema: Schema) -> Self {
+        Self {
+            key,
+            support: Support::Supported(schema),
+        }
+    }
+
+    ///
+    /// A field only used for Solidity input.
+    ///
+    fn solidity(key: &'static str, schema: Schema) -> Self {
+        Self {
+            key,
+            support: Support::Solidity(schema),
+        }
+    }
+
+    ///
+    /// A field accepted by solc, but ignored by solx.
+    ///
+    fn unsupported(key: &'static str) -> Self {
+        Self {
+            key,
+            support: Support::Unsupported,
+        }
+    }
+}
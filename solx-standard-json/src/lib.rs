@@ -17,11 +17,18 @@ pub use self::input::Input;
 pub use self::input::language::Language as InputLanguage;
 pub use self::input::settings::Settings as InputSettings;
 pub use self::input::settings::debug::Debug as InputDebug;
+pub use self::input::settings::limits::Limits as InputLimits;
 pub use self::input::settings::metadata::Metadata as InputMetadata;
 pub use self::input::settings::optimizer::Optimizer as InputOptimizer;
 pub use self::input::settings::selection::Selection as InputSelection;
 pub use self::input::settings::selection::selector::Selector as InputSelector;
+pub use self::input::settings::warnings::Warnings as InputWarnings;
+pub use self::input::settings::warnings::rule::Rule as InputWarningRule;
 pub use self::input::source::Source as InputSource;
+pub use self::input::source::resolver::Resolver as InputSourceResolver;
+pub use self::input::source::resolver::content_addressed::ContentAddressed as InputSourceContentAddressedResolver;
+pub use self::input::source::resolver::file_system::FileSystem as InputSourceFileSystemResolver;
+pub use self::input::source::url::URL as InputSourceURL;
 pub use self::output::Output;
 pub use self::output::contract::Contract as OutputContract;
 pub use self::output::contract::evm::EVM as OutputContractEVM;
@@ -29,6 +36,8 @@ pub use self::output::contract::evm::bytecode::Bytecode as OutputContractEVMByte
 pub use self::output::contract::evm::legacy_assembly::LegacyAssembly as OutputContractEVMLegacyAssembly;
 pub use self::output::error::Error as OutputError;
 pub use self::output::error::collectable::Collectable as CollectableError;
+pub use self::output::stream::Stream as OutputStream;
+pub use self::output::stream::format::Format as OutputStreamFormat;
 pub use self::version::Version;
 
 /// The standard JSON debug environment variable.
@@ -4,6 +4,9 @@
 
 pub mod evm;
 
+use crate::input::settings::selection::Selection as InputSettingsSelection;
+use crate::input::settings::selection::selector::Selector as InputSettingsSelector;
+
 use self::evm::EVM;
 
 ///
@@ -62,6 +65,43 @@ impl Contract {
         }
     }
 
+    ///
+    /// Removes the data not requested by `output_selection`, and the empty EVM sections.
+    ///
+    pub fn prune(&mut self, path: &str, name: &str, output_selection: &InputSettingsSelection) {
+        if !output_selection.check_selection(path, Some(name), InputSettingsSelector::Yul) {
+            self.ir = None;
+        }
+        if let Some(evm) = self.evm.as_mut() {
+            if !output_selection.check_selection(
+                path,
+                Some(name),
+                InputSettingsSelector::EVMLegacyAssembly,
+            ) {
+                evm.legacy_assembly = None;
+            }
+            if evm
+                .bytecode
+                .as_ref()
+                .map(|bytecode| bytecode.is_empty())
+                .unwrap_or(true)
+            {
+                evm.bytecode = None;
+            }
+            if evm
+                .deployed_bytecode
+                .as_ref()
+                .map(|bytecode| bytecode.is_empty())
+                .unwrap_or(true)
+            {
+                evm.deployed_bytecode = None;
+            }
+        }
+        if self.evm.as_ref().map(|evm| evm.is_empty()).unwrap_or(true) {
+            self.evm = None;
+        }
+    }
+
     ///
     /// Checks if all fields are unset or empty.
     ///
@@ -171,6 +171,48 @@ impl Error {
             sources,
         )
     }
+
+    ///
+    /// Creates a new error or warning from a catalogued solx diagnostic.
+    ///
+    /// The diagnostic location takes precedence over the contract `path`.
+    ///
+    pub fn new_diagnostic(
+        path: Option<&str>,
+        diagnostic: &solx_utils::Diagnostic,
+        sources: Option<&BTreeMap<String, InputSource>>,
+    ) -> Self {
+        let (path, source_location) = match diagnostic.location.as_ref() {
+            Some(location) => (
+                None,
+                Some(SourceLocation::new(
+                    location.path.as_str(),
+                    location.start,
+                    location.end,
+                )),
+            ),
+            None => (
+                path,
+                path.map(|path| {
+                    SourceLocation::new(
+                        path,
+                        SourceLocation::UNKNOWN_OFFSET,
+                        SourceLocation::UNKNOWN_OFFSET,
+                    )
+                }),
+            ),
+        };
+        let mut error = Self::new(
+            path,
+            diagnostic.severity().to_string().as_str(),
+            Some(diagnostic.code.code()),
+            diagnostic.message.as_str(),
+            source_location,
+            sources,
+        );
+        error.component = diagnostic.component.to_string();
+        error
+    }
 }
 
 impl std::fmt::Display for Error {
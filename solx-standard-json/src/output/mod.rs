@@ -5,6 +5,7 @@
 pub mod contract;
 pub mod error;
 pub mod source;
+pub mod stream;
 
 use std::collections::BTreeMap;
 use std::collections::HashMap;
@@ -76,10 +77,33 @@ impl Output {
         }
     }
 
+    ///
+    /// Moves the collected `messages` to the output, before the errors already in it.
+    ///
+    /// Is used to keep the messages when the output is written before they reach the build.
+    ///
+    pub fn take_messages(&mut self, messages: &Mutex<Vec<JsonOutputError>>) {
+        let mut errors: Vec<JsonOutputError> = messages.lock_sync().drain(..).collect();
+        errors.append(&mut self.errors);
+        self.errors = errors;
+    }
+
     ///
     /// Prunes the output JSON and prints it to stdout.
     ///
     pub fn write_and_exit(mut self, output_selection: &InputSettingsSelection) -> ! {
+        self.prune(output_selection);
+
+        let mut stdout = std::io::stdout().lock();
+        serde_json::to_writer(&mut stdout, &self).expect("Stdout writing error");
+        std::io::Write::flush(&mut stdout).expect("Stdout flush error");
+        std::process::exit(solx_utils::EXIT_CODE_SUCCESS);
+    }
+
+    ///
+    /// Removes the data not requested by `output_selection`, and the contracts left empty.
+    ///
+    pub fn prune(&mut self, output_selection: &InputSettingsSelection) {
         for (path, source) in self.sources.iter_mut() {
             if !output_selection.check_selection(path.as_str(), None, InputSettingsSelector::AST) {
                 source.ast = None;
@@ -87,46 +111,7 @@ impl Output {
         }
         for (path, file) in self.contracts.iter_mut() {
             for (name, contract) in file.iter_mut() {
-                if !output_selection.check_selection(
-                    path.as_str(),
-                    Some(name.as_str()),
-                    InputSettingsSelector::Yul,
-                ) {
-                    contract.ir = None;
-                }
-                if let Some(evm) = contract.evm.as_mut() {
-                    if !output_selection.check_selection(
-                        path.as_str(),
-                        Some(name.as_str()),
-                        InputSettingsSelector::EVMLegacyAssembly,
-                    ) {
-                        evm.legacy_assembly = None;
-                    }
-                    if evm
-                        .bytecode
-                        .as_ref()
-                        .map(|bytecode| bytecode.is_empty())
-                        .unwrap_or(true)
-                    {
-                        evm.bytecode = None;
-                    }
-                    if evm
-                        .deployed_bytecode
-                        .as_ref()
-                        .map(|bytecode| bytecode.is_empty())
-                        .unwrap_or(true)
-                    {
-                        evm.deployed_bytecode = None;
-                    }
-                }
-                if contract
-                    .evm
-                    .as_ref()
-                    .map(|evm| evm.is_empty())
-                    .unwrap_or(true)
-                {
-                    contract.evm = None;
-                }
+                contract.prune(path.as_str(), name.as_str(), output_selection);
             }
         }
 
@@ -134,11 +119,6 @@ impl Output {
             contracts.retain(|_, contract| !contract.is_empty());
             !contracts.is_empty()
         });
-
-        let mut stdout = std::io::stdout().lock();
-        serde_json::to_writer(&mut stdout, &self).expect("Stdout writing error");
-        std::io::Write::flush(&mut stdout).expect("Stdout flush error");
-        std::process::exit(solx_utils::EXIT_CODE_SUCCESS);
     }
 
     ///
@@ -0,0 +1,40 @@
+//!
+//! The streaming standard JSON output format.
+//!
+
+use std::str::FromStr;
+
+///
+/// The streaming standard JSON output format.
+///
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// A single standard JSON output object.
+    #[default]
+    JSON,
+    /// Newline-delimited output fragments, which yield the standard JSON output if merged.
+    NDJSON,
+}
+
+impl FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string {
+            "json" => Ok(Self::JSON),
+            "ndjson" => Ok(Self::NDJSON),
+            string => anyhow::bail!(
+                "Invalid streaming output format `{string}`: expected `json` or `ndjson`."
+            ),
+        }
+    }
+}
+
+impl std::fmt::Display for Format {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::JSON => write!(f, "json"),
+            Self::NDJSON => write!(f, "ndjson"),
+        }
+    }
+}
@@ -0,0 +1,279 @@
+//!
+//! The streaming `solc --standard-json` output writer.
+//!
+
+pub mod format;
+
+use std::io::Write;
+
+use crate::input::settings::selection::Selection as InputSettingsSelection;
+
+use super::Output;
+use super::contract::Contract;
+
+use self::format::Format;
+
+///
+/// The streaming `solc --standard-json` output writer.
+///
+/// Writes every contract as soon as it is passed, so that the whole output never has to be
+/// kept in memory. The contracts must be passed grouped by path.
+///
+/// In the JSON format, the result is the same standard JSON object as in the non-streaming mode,
+/// up to the order of keys. In the NDJSON format, every contract is written as a separate line
+/// `{"contracts":{"<path>":{"<name>":{...}}}}`, and the last line contains the remaining output
+/// fields, so that merging all lines yields the standard JSON output.
+///
+pub struct Stream<W: Write> {
+    /// The underlying writer.
+    writer: W,
+    /// The output format.
+    format: Format,
+    /// The path of the contracts group being written, if the `contracts` object is open.
+    current_path: Option<String>,
+}
+
+impl<W: Write> Stream<W> {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(writer: W, format: Format) -> Self {
+        Self {
+            writer,
+            format,
+            current_path: None,
+        }
+    }
+
+    ///
+    /// Prunes the contract according to `output_selection` and writes it, unless it is empty.
+    ///
+    pub fn write_contract(
+        &mut self,
+        path: &str,
+        name: &str,
+        mut contract: Contract,
+        output_selection: &InputSettingsSelection,
+    ) -> std::io::Result<()> {
+        contract.prune(path, name, output_selection);
+        if contract.is_empty() {
+            return Ok(());
+        }
+
+        match self.format {
+            Format::JSON => {
+                match self.current_path.as_deref() {
+                    None => {
+                        self.writer.write_all(b"{\"contracts\":{")?;
+                        self.write_key(path)?;
+                        self.writer.write_all(b"{")?;
+                    }
+                    Some(current_path) if current_path == path => {
+                        self.writer.write_all(b",")?;
+                    }
+                    Some(_) => {
+                        self.writer.write_all(b"},")?;
+                        self.write_key(path)?;
+                        self.writer.write_all(b"{")?;
+                    }
+                }
+                self.current_path = Some(path.to_owned());
+                self.write_key(name)?;
+                serde_json::to_writer(&mut self.writer, &contract)?;
+            }
+            Format::NDJSON => {
+                self.writer.write_all(b"{\"contracts\":{")?;
+                self.write_key(path)?;
+                self.writer.write_all(b"{")?;
+                self.write_key(name)?;
+                serde_json::to_writer(&mut self.writer, &contract)?;
+                self.writer.write_all(b"}}}\n")?;
+            }
+        }
+        self.writer.flush()
+    }
+
+    ///
+    /// Prunes the rest of the output according to `output_selection`, writes it, and finishes
+    /// the stream.
+    ///
+    /// The contracts of `output` are expected to be already written.
+    ///
+    pub fn finish(
+        mut self,
+        mut output: Output,
+        output_selection: &InputSettingsSelection,
+    ) -> std::io::Result<()> {
+        output.contracts.clear();
+        output.prune(output_selection);
+
+        match self.format {
+            Format::JSON => {
+                let mut is_object_open = self.current_path.is_some();
+                if is_object_open {
+                    self.writer.write_all(b"}}")?;
+                }
+                if !output.sources.is_empty() {
+                    self.write_field(&mut is_object_open, "sources", &output.sources)?;
+                }
+                if !output.errors.is_empty() {
+                    self.write_field(&mut is_object_open, "errors", &output.errors)?;
+                }
+                if !output.benchmarks.is_empty() {
+                    self.write_field(&mut is_object_open, "benchmarks", &output.benchmarks)?;
+                }
+                if !is_object_open {
+                    self.writer.write_all(b"{")?;
+                }
+                self.writer.write_all(b"}")?;
+            }
+            Format::NDJSON => {
+                serde_json::to_writer(&mut self.writer, &output)?;
+                self.writer.write_all(b"\n")?;
+            }
+        }
+        self.writer.flush()
+    }
+
+    ///
+    /// Writes a top-level output field, opening the output object if necessary.
+    ///
+    fn write_field<T>(
+        &mut self,
+        is_object_open: &mut bool,
+        key: &str,
+        value: &T,
+    ) -> std::io::Result<()>
+    where
+        T: serde::Serialize,
+    {
+        self.writer
+            .write_all(if *is_object_open { b"," } else { b"{" })?;
+        *is_object_open = true;
+        self.write_key(key)?;
+        serde_json::to_writer(&mut self.writer, value)?;
+        Ok(())
+    }
+
+    ///
+    /// Writes a JSON object key with the colon.
+    ///
+    fn write_key(&mut self, key: &str) -> std::io::Result<()> {
+        serde_json::to_writer(&mut self.writer, key)?;
+        self.writer.write_all(b":")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::collections::BTreeSet;
+
+    use crate::input::settings::selection::Selection;
+    use crate::input::settings::selection::selector::Selector;
+    use crate::output::Output;
+    use crate::output::contract::Contract;
+    use crate::output::error::Error as OutputError;
+    use crate::output::source::Source;
+
+    use super::Stream;
+    use super::format::Format;
+
+    fn output() -> Output {
+        let contract = |name: &str| Contract {
+            abi: Some(serde_json::json!([{ "type": "function", "name": name }])),
+            ..Default::default()
+        };
+        Output {
+            contracts: BTreeMap::from([
+                (
+                    "A.sol".to_owned(),
+                    BTreeMap::from([
+                        ("A".to_owned(), contract("a")),
+                        ("B".to_owned(), contract("b")),
+                    ]),
+                ),
+                (
+                    "C.sol".to_owned(),
+                    BTreeMap::from([
+                        ("C".to_owned(), contract("c")),
+                        ("Empty".to_owned(), Contract::default()),
+                    ]),
+                ),
+            ]),
+            sources: BTreeMap::from([
+                ("A.sol".to_owned(), Source::new(0)),
+                ("C.sol".to_owned(), Source::new(1)),
+            ]),
+            errors: vec![OutputError::new_warning("Warning")],
+            benchmarks: Vec::new(),
+        }
+    }
+
+    fn stream(output: Output, format: Format) -> String {
+        let selection = Selection::new(BTreeSet::from([Selector::ABI]));
+        let mut buffer = Vec::new();
+        let mut stream = Stream::new(&mut buffer, format);
+        for (path, contracts) in output.contracts.clone() {
+            for (name, contract) in contracts {
+                stream
+                    .write_contract(path.as_str(), name.as_str(), contract, &selection)
+                    .expect("Always valid");
+            }
+        }
+        stream.finish(output, &selection).expect("Always valid");
+        String::from_utf8(buffer).expect("Always valid")
+    }
+
+    fn merge(target: &mut serde_json::Value, source: serde_json::Value) {
+        match (target, source) {
+            (serde_json::Value::Object(target), serde_json::Value::Object(source)) => {
+                for (key, value) in source {
+                    merge(target.entry(key).or_insert(serde_json::Value::Null), value);
+                }
+            }
+            (target, source) => *target = source,
+        }
+    }
+
+    #[test]
+    fn json_matches_regular_output() {
+        let mut expected = output();
+        expected.prune(&Selection::new(BTreeSet::from([Selector::ABI])));
+
+        let streamed: serde_json::Value =
+            serde_json::from_str(stream(output(), Format::JSON).as_str()).expect("Always valid");
+        assert_eq!(
+            streamed,
+            serde_json::to_value(&expected).expect("Always valid")
+        );
+    }
+
+    #[test]
+    fn json_empty() {
+        let streamed = stream(Output::new(&BTreeMap::new()), Format::JSON);
+        assert_eq!(streamed, "{}");
+    }
+
+    #[test]
+    fn ndjson_merges_into_regular_output() {
+        let mut expected = output();
+        expected.prune(&Selection::new(BTreeSet::from([Selector::ABI])));
+
+        let streamed = stream(output(), Format::NDJSON);
+        let lines = streamed.lines().collect::<Vec<&str>>();
+        assert_eq!(lines.len(), 4);
+
+        let mut merged = serde_json::Value::Object(serde_json::Map::new());
+        for line in lines {
+            merge(
+                &mut merged,
+                serde_json::from_str(line).expect("Every line is valid JSON"),
+            );
+        }
+        assert_eq!(
+            merged,
+            serde_json::to_value(&expected).expect("Always valid")
+        );
+    }
+}
@@ -0,0 +1,74 @@
+//!
+//! The compiler errors with resolved source locations.
+//!
+
+use solx_standard_json::OutputError;
+
+///
+/// The compiler error with its resolved source location.
+///
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    /// The standard JSON error.
+    pub error: OutputError,
+    /// The line-column location, if the error has one.
+    pub location: Option<solx_utils::DebugInfoMappedLocation>,
+}
+
+///
+/// The compiler errors with resolved source locations.
+///
+/// Returned as the compilation error, so that the reports can recover the locations by
+/// downcasting it.
+///
+#[derive(Debug, Clone)]
+pub struct Diagnostics(pub Vec<Diagnostic>);
+
+impl Diagnostics {
+    ///
+    /// Bails with the errors of the standard JSON output, if there are any.
+    ///
+    /// The error locations are resolved against `sources`.
+    ///
+    pub fn check(errors: &[OutputError], sources: &[(String, String)]) -> anyhow::Result<()> {
+        let diagnostics: Vec<Diagnostic> = errors
+            .iter()
+            .filter(|error| error.severity == "error")
+            .map(|error| {
+                let location = error.source_location.as_ref().map(|location| {
+                    let source_code = sources
+                        .iter()
+                        .find(|(path, _)| path == &location.file)
+                        .map(|(_, source_code)| source_code.as_str());
+                    solx_utils::DebugInfoMappedLocation::from_solc_location(
+                        location.file.to_owned(),
+                        location.start,
+                        location.end,
+                        source_code,
+                    )
+                });
+                Diagnostic {
+                    error: error.to_owned(),
+                    location,
+                }
+            })
+            .collect();
+        if diagnostics.is_empty() {
+            return Ok(());
+        }
+        Err(Self(diagnostics).into())
+    }
+}
+
+impl std::fmt::Display for Diagnostics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let messages: Vec<String> = self
+            .0
+            .iter()
+            .map(|diagnostic| diagnostic.error.to_string())
+            .collect();
+        write!(f, "{}", messages.join("\n"))
+    }
+}
+
+impl std::error::Error for Diagnostics {}
@@ -47,6 +47,7 @@ pub fn new_input_for_solc(
                 solx_standard_json::InputSource {
                     content: Some(content),
                     urls: None,
+                    keccak256: None,
                 },
             )
         })
@@ -62,6 +63,8 @@ pub fn new_input_for_solc(
                 enabled: Some(optimizer_enabled),
                 mode: None,
                 size_fallback: None,
+                runs: None,
+                size_for_low_runs: None,
             },
             libraries,
             remappings: remappings.unwrap_or_default(),
@@ -71,6 +74,8 @@ pub fn new_input_for_solc(
             metadata: solx_standard_json::InputMetadata::default(),
             debug,
             llvm_options: Vec::new(),
+            warnings: solx_standard_json::InputWarnings::default(),
+            limits: solx_standard_json::InputLimits::default(),
         },
     }
 }
@@ -147,6 +147,7 @@ impl Compiler for LLVMIRCompiler {
                     solx_standard_json::InputSource {
                         content: Some(source.to_owned()),
                         urls: None,
+                        keccak256: None,
                     },
                 )
             })
@@ -168,6 +169,8 @@ impl Compiler for LLVMIRCompiler {
                         .llvm_optimizer_settings
                         .is_fallback_to_size_enabled,
                 ),
+                runs: None,
+                size_for_low_runs: None,
             },
             &solx_standard_json::InputSelection::new(selectors),
             solx_standard_json::InputMetadata::default(),
@@ -220,7 +223,7 @@ impl Compiler for LLVMIRCompiler {
             }
         }
 
-        Ok(EVMInput::new(builds, None, last_contract))
+        Ok(EVMInput::new(builds, None, last_contract, vec![]))
     }
 
     fn all_modes(&self) -> Vec<Mode> {
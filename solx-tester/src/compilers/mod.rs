@@ -3,6 +3,7 @@
 //!
 
 pub mod cache;
+pub mod diagnostics;
 pub mod input_ext;
 pub mod llvm_ir;
 pub mod mode;
@@ -7,7 +7,6 @@ use std::collections::HashMap;
 
 use solx_standard_json::InputLanguage;
 use solx_standard_json::Output;
-use solx_standard_json::OutputError;
 use solx_standard_json::output::source::Source;
 use solx_utils::ContractName;
 
@@ -139,17 +138,6 @@ pub fn extract_bytecode_builds(
     Ok(builds)
 }
 
-///
-/// Returns errors as Option slice, None if empty.
-///
-pub fn errors_opt(output: &Output) -> Option<&[OutputError]> {
-    if output.errors.is_empty() {
-        None
-    } else {
-        Some(&output.errors)
-    }
-}
-
 ///
 /// Returns the name of the last contract in the AST.
 ///
@@ -2,6 +2,8 @@
 //! DWARF debug information validator for compiled contracts.
 //!
 
+use std::collections::BTreeMap;
+
 use gimli::EndianSlice;
 use gimli::RunTimeEndian;
 use object::Object;
@@ -10,6 +12,11 @@ use object::ObjectSection;
 use solx_standard_json::Output;
 use solx_utils::ContractName;
 
+///
+/// The DWARF line table: source file paths and lines by the code address they start at.
+///
+pub type LineTable = BTreeMap<u64, (String, u64)>;
+
 ///
 /// Validates DWARF debug information embedded in compiled contract bytecodes.
 ///
@@ -105,6 +112,106 @@ impl DwarfValidator {
     fn parse_debug_information_functions(
         debug_information_hex: &str,
     ) -> anyhow::Result<Vec<(String, u64)>> {
+        Self::with_dwarf(debug_information_hex, |dwarf| {
+            let mut functions = Vec::new();
+            let mut units = dwarf.units();
+
+            while let Some(unit_header) = units.next()? {
+                let unit = dwarf.unit(unit_header)?;
+                let mut entries = unit.entries();
+
+                while let Some(entry) = entries.next_dfs()? {
+                    if entry.tag() != gimli::DW_TAG_subprogram
+                        && entry.tag() != gimli::DW_TAG_inlined_subroutine
+                    {
+                        continue;
+                    }
+
+                    let mut name: Option<String> = None;
+                    let mut low_pc: Option<u64> = None;
+
+                    for attribute in entry.attrs().iter() {
+                        match attribute.name() {
+                            gimli::DW_AT_name => {
+                                if let Ok(value) = dwarf.attr_string(&unit, attribute.value()) {
+                                    name = Some(value.to_string_lossy().into_owned());
+                                }
+                            }
+                            gimli::DW_AT_low_pc => {
+                                if let Ok(Some(address)) =
+                                    dwarf.attr_address(&unit, attribute.value())
+                                {
+                                    low_pc = Some(address);
+                                }
+                            }
+                            gimli::DW_AT_linkage_name => {
+                                if name.is_none()
+                                    && let Ok(value) = dwarf.attr_string(&unit, attribute.value())
+                                {
+                                    name = Some(value.to_string_lossy().into_owned());
+                                }
+                            }
+                            _ => {}
+                        }
+                    }
+
+                    if let Some(address) = low_pc {
+                        let function_name = name.unwrap_or_else(|| "<inlined>".to_owned());
+                        functions.push((function_name, address));
+                    }
+                }
+            }
+
+            Ok(functions)
+        })
+    }
+
+    ///
+    /// Parses hex-encoded debug information and extracts its line table.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the hex string cannot be decoded, the ELF cannot
+    /// be parsed, or the DWARF sections are malformed.
+    ///
+    pub fn parse_debug_information_lines(debug_information_hex: &str) -> anyhow::Result<LineTable> {
+        Self::with_dwarf(debug_information_hex, |dwarf| {
+            let mut lines = LineTable::new();
+            let mut units = dwarf.units();
+
+            while let Some(unit_header) = units.next()? {
+                let unit = dwarf.unit(unit_header)?;
+                let Some(program) = unit.line_program.clone() else {
+                    continue;
+                };
+
+                let mut rows = program.rows();
+                while let Some((header, row)) = rows.next_row()? {
+                    if row.end_sequence() {
+                        continue;
+                    }
+                    let (Some(line), Some(file)) = (row.line(), row.file(header)) else {
+                        continue;
+                    };
+                    let path = dwarf
+                        .attr_string(&unit, file.path_name())?
+                        .to_string_lossy()
+                        .into_owned();
+                    lines.insert(row.address(), (path, line.get()));
+                }
+            }
+
+            Ok(lines)
+        })
+    }
+
+    ///
+    /// Decodes hex-encoded debug information, and passes its DWARF sections to `parse`.
+    ///
+    fn with_dwarf<T, F>(debug_information_hex: &str, parse: F) -> anyhow::Result<T>
+    where
+        F: FnOnce(&gimli::Dwarf<EndianSlice<'_, RunTimeEndian>>) -> anyhow::Result<T>,
+    {
         let debug_information_bytes = hex::decode(debug_information_hex)?;
         let object_file = object::File::parse(&*debug_information_bytes)?;
 
@@ -124,55 +231,6 @@ impl DwarfValidator {
 
         let dwarf =
             gimli::Dwarf::load(|section_identifier| load_section(section_identifier.name()))?;
-
-        let mut functions = Vec::new();
-        let mut units = dwarf.units();
-
-        while let Some(unit_header) = units.next()? {
-            let unit = dwarf.unit(unit_header)?;
-            let mut entries = unit.entries();
-
-            while let Some(entry) = entries.next_dfs()? {
-                if entry.tag() != gimli::DW_TAG_subprogram
-                    && entry.tag() != gimli::DW_TAG_inlined_subroutine
-                {
-                    continue;
-                }
-
-                let mut name: Option<String> = None;
-                let mut low_pc: Option<u64> = None;
-
-                for attribute in entry.attrs().iter() {
-                    match attribute.name() {
-                        gimli::DW_AT_name => {
-                            if let Ok(value) = dwarf.attr_string(&unit, attribute.value()) {
-                                name = Some(value.to_string_lossy().into_owned());
-                            }
-                        }
-                        gimli::DW_AT_low_pc => {
-                            if let Ok(Some(address)) = dwarf.attr_address(&unit, attribute.value())
-                            {
-                                low_pc = Some(address);
-                            }
-                        }
-                        gimli::DW_AT_linkage_name => {
-                            if name.is_none()
-                                && let Ok(value) = dwarf.attr_string(&unit, attribute.value())
-                            {
-                                name = Some(value.to_string_lossy().into_owned());
-                            }
-                        }
-                        _ => {}
-                    }
-                }
-
-                if let Some(address) = low_pc {
-                    let function_name = name.unwrap_or_else(|| "<inlined>".to_owned());
-                    functions.push((function_name, address));
-                }
-            }
-        }
-
-        Ok(functions)
+        parse(&dwarf)
     }
 }
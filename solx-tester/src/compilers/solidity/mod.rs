@@ -17,14 +17,18 @@ use std::path::PathBuf;
 
 use itertools::Itertools;
 
+use solx_utils::ContractName;
+
 use crate::compilers::Compiler;
 use crate::compilers::cache::Cache;
+use crate::compilers::diagnostics::Diagnostics;
 use crate::compilers::mode::Mode;
 use crate::compilers::yul::mode::Mode as YulMode;
 use crate::revm::input::Input as EVMInput;
 use crate::toolchain::Toolchain;
 
 use self::cache_key::CacheKey;
+use self::dwarf::LineTable;
 use self::mode::Mode as SolidityMode;
 use self::subprocess::Subprocess;
 
@@ -42,6 +46,8 @@ pub struct SolidityCompiler {
     version: semver::Version,
     /// Cache for compiler outputs.
     cache: Cache<CacheKey, solx_standard_json::Output>,
+    /// Whether to request the debug information for the code coverage.
+    debug_info: bool,
 }
 
 impl SolidityCompiler {
@@ -63,9 +69,20 @@ impl SolidityCompiler {
             executable_path: Some(executable_path),
             version,
             cache: Cache::new(),
+            debug_info: false,
         })
     }
 
+    ///
+    /// Requests the debug information, so that the Solidity builds carry the DWARF line tables.
+    ///
+    /// Only `solx` emits debug information, so the option has no effect with `solc`.
+    ///
+    pub fn with_debug_info(mut self) -> Self {
+        self.debug_info = true;
+        self
+    }
+
     ///
     /// Returns the toolchain type.
     ///
@@ -73,6 +90,83 @@ impl SolidityCompiler {
         self.toolchain
     }
 
+    ///
+    /// Returns the compiler version.
+    ///
+    pub fn version(&self) -> &semver::Version {
+        &self.version
+    }
+
+    ///
+    /// Compiles the Solidity sources with `solx` requesting debug information, and returns the
+    /// DWARF line tables of every contract code segment.
+    ///
+    pub fn line_tables(
+        &self,
+        sources: &[(String, String)],
+        mode: &Mode,
+    ) -> anyhow::Result<Vec<(String, solx_utils::CodeSegment, LineTable)>> {
+        if self.toolchain != Toolchain::Solx
+            || self.language != solx_standard_json::InputLanguage::Solidity
+        {
+            anyhow::bail!("Debug information is only available for Solidity compiled with solx");
+        }
+
+        let allow_path = Path::new(Self::ALLOW_PATHS)
+            .canonicalize()
+            .expect("Always valid")
+            .to_string_lossy()
+            .to_string();
+        let input = Self::create_solx_solidity_input(
+            sources,
+            &solx_utils::Libraries::default(),
+            SolidityMode::unwrap(mode),
+            None,
+            vec![],
+            true,
+        )?;
+        let output = self.run_solx(mode, input, &[allow_path.as_str()], None)?;
+        solx_standard_json::CollectableError::check_errors(&output)?;
+
+        Self::extract_line_tables(&output)
+    }
+
+    ///
+    /// Extracts the DWARF line tables of every contract code segment with debug information.
+    ///
+    fn extract_line_tables(
+        output: &solx_standard_json::Output,
+    ) -> anyhow::Result<Vec<(String, solx_utils::CodeSegment, LineTable)>> {
+        let mut line_tables = Vec::new();
+        for (file_path, contracts) in output.contracts.iter() {
+            for (contract_name, contract) in contracts.iter() {
+                let Some(evm) = contract.evm.as_ref() else {
+                    continue;
+                };
+                for (bytecode, code_segment) in [
+                    (&evm.bytecode, solx_utils::CodeSegment::Deploy),
+                    (&evm.deployed_bytecode, solx_utils::CodeSegment::Runtime),
+                ] {
+                    let Some(debug_information) = bytecode
+                        .as_ref()
+                        .and_then(|bytecode| bytecode.debug_info.as_ref())
+                        .filter(|debug_information| !debug_information.is_empty())
+                    else {
+                        continue;
+                    };
+                    line_tables.push((
+                        ContractName::full_path(file_path, contract_name),
+                        code_segment,
+                        self::dwarf::DwarfValidator::parse_debug_information_lines(
+                            debug_information,
+                        )?,
+                    ));
+                }
+            }
+        }
+        Ok(line_tables)
+    }
+
     ///
     /// Gets the compiler version from its executable.
     /// Works for both solx and solc as they have the same version output format.
@@ -206,6 +300,7 @@ impl SolidityCompiler {
         mode: &SolidityMode,
         test_params: Option<&solx_solc_test_adapter::Params>,
         llvm_options: Vec<String>,
+        debug_info: bool,
     ) -> anyhow::Result<solx_standard_json::Input> {
         let llvm_settings = mode
             .llvm_optimizer_settings
@@ -220,6 +315,7 @@ impl SolidityCompiler {
                     solx_standard_json::InputSource {
                         content: Some(source.to_owned()),
                         urls: None,
+                        keccak256: None,
                     },
                 )
             })
@@ -238,6 +334,10 @@ impl SolidityCompiler {
         } else {
             solx_standard_json::InputSelector::EVMLegacyAssembly
         });
+        if debug_info {
+            selectors.insert(solx_standard_json::InputSelector::BytecodeDebugInfo);
+            selectors.insert(solx_standard_json::InputSelector::RuntimeBytecodeDebugInfo);
+        }
 
         solx_standard_json::Input::try_from_solidity_sources(
             sources_json,
@@ -247,6 +347,8 @@ impl SolidityCompiler {
                 enabled: None,
                 mode: Some(llvm_settings.middle_end_as_char()),
                 size_fallback: Some(llvm_settings.is_fallback_to_size_enabled),
+                runs: None,
+                size_for_low_runs: None,
             },
             evm_version,
             mode.via_ir,
@@ -279,6 +381,7 @@ impl SolidityCompiler {
                     solx_standard_json::InputSource {
                         content: Some(source.to_owned()),
                         urls: None,
+                        keccak256: None,
                     },
                 )
             })
@@ -299,6 +402,8 @@ impl SolidityCompiler {
                 enabled: None,
                 mode: Some(llvm_settings.middle_end_as_char()),
                 size_fallback: Some(llvm_settings.is_fallback_to_size_enabled),
+                runs: None,
+                size_for_low_runs: None,
             },
             &solx_standard_json::InputSelection::new(selectors),
             solx_standard_json::InputMetadata::default(),
@@ -426,6 +531,7 @@ impl SolidityCompiler {
                     solidity_mode,
                     test_params,
                     llvm_options,
+                    self.debug_info,
                 )?;
 
                 self.run_solx(
@@ -456,8 +562,13 @@ impl SolidityCompiler {
             }
         };
 
-        solx_standard_json::CollectableError::check_errors(&output)?;
+        Diagnostics::check(output.errors.as_slice(), sources.as_slice())?;
         self::dwarf::DwarfValidator::validate_output(&output)?;
+        let line_tables = if self.debug_info {
+            Self::extract_line_tables(&output)?
+        } else {
+            vec![]
+        };
 
         let method_identifiers = match self.language {
             solx_standard_json::InputLanguage::Solidity => Some(
@@ -486,10 +597,20 @@ impl SolidityCompiler {
                     (key, value)
                 })
                 .collect();
-            return Ok(EVMInput::new(builds, method_identifiers, last_contract));
+            return Ok(EVMInput::new(
+                builds,
+                method_identifiers,
+                last_contract,
+                vec![],
+            ));
         }
 
-        Ok(EVMInput::new(builds, method_identifiers, last_contract))
+        Ok(EVMInput::new(
+            builds,
+            method_identifiers,
+            last_contract,
+            line_tables,
+        ))
     }
 
     ///
@@ -506,21 +627,7 @@ impl SolidityCompiler {
         let output =
             self.compile_solc_cached(test_path, &sources, &libraries, mode, test_params)?;
 
-        if let Some(errors) = crate::compilers::output_ext::errors_opt(&output) {
-            let mut has_errors = false;
-            let mut error_messages = Vec::with_capacity(errors.len());
-
-            for error in errors.iter() {
-                if error.severity.as_str() == "error" {
-                    has_errors = true;
-                    error_messages.push(error.formatted_message.to_owned());
-                }
-            }
-
-            if has_errors {
-                anyhow::bail!("`solc` errors found: {error_messages:?}");
-            }
-        }
+        Diagnostics::check(output.errors.as_slice(), sources.as_slice())?;
 
         let method_identifiers = match self.language {
             solx_standard_json::InputLanguage::Solidity => Some(
@@ -552,10 +659,20 @@ impl SolidityCompiler {
                     (key, value)
                 })
                 .collect();
-            return Ok(EVMInput::new(builds, method_identifiers, last_contract));
+            return Ok(EVMInput::new(
+                builds,
+                method_identifiers,
+                last_contract,
+                vec![],
+            ));
         }
 
-        Ok(EVMInput::new(builds, method_identifiers, last_contract))
+        Ok(EVMInput::new(
+            builds,
+            method_identifiers,
+            last_contract,
+            vec![],
+        ))
     }
 }
 
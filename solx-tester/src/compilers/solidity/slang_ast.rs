@@ -3,10 +3,13 @@
 //!
 
 use std::collections::BTreeMap;
+use std::ops::Range;
 
 use slang_solidity_v2::ast::AbicoderVersion;
+use slang_solidity_v2::ast::ContractMember;
 use slang_solidity_v2::ast::Pragma;
 use slang_solidity_v2::ast::SourceUnitMember;
+use slang_solidity_v2::ast::Statement;
 use slang_solidity_v2::compilation::CompilationBuilder;
 use slang_solidity_v2::compilation::CompilationBuilderConfig;
 use slang_solidity_v2::compilation::CompilationUnit;
@@ -118,6 +121,127 @@ impl SlangAst {
         }
         None
     }
+
+    ///
+    /// The source ranges of the top-level definitions of the file at `path`: everything but
+    /// pragmas and imports.
+    ///
+    pub fn definition_ranges(&self, path: &str) -> Vec<Range<usize>> {
+        self.members(path)
+            .into_iter()
+            .filter(|member| {
+                !matches!(
+                    member,
+                    SourceUnitMember::PragmaDirective(_) | SourceUnitMember::ImportDirective(_)
+                )
+            })
+            .map(|member| member.text_range())
+            .collect()
+    }
+
+    ///
+    /// The source ranges of the members of the contracts, interfaces, and libraries of the file
+    /// at `path`.
+    ///
+    pub fn member_ranges(&self, path: &str) -> Vec<Range<usize>> {
+        let mut ranges = Vec::new();
+        for member in self.members(path).into_iter() {
+            match member {
+                SourceUnitMember::ContractDefinition(definition) => ranges.extend(
+                    definition
+                        .members()
+                        .iter()
+                        .map(|member| member.text_range()),
+                ),
+                SourceUnitMember::InterfaceDefinition(definition) => ranges.extend(
+                    definition
+                        .members()
+                        .iter()
+                        .map(|member| member.text_range()),
+                ),
+                SourceUnitMember::LibraryDefinition(definition) => ranges.extend(
+                    definition
+                        .members()
+                        .iter()
+                        .map(|member| member.text_range()),
+                ),
+                _ => {}
+            }
+        }
+        ranges
+    }
+
+    ///
+    /// The source ranges of the function body statements of the file at `path`, nested
+    /// `depth` levels deep in blocks and control-flow statements.
+    ///
+    /// Statements at the same depth never overlap, so any subset of them can be removed at once.
+    ///
+    pub fn statement_ranges(&self, path: &str, depth: usize) -> Vec<Range<usize>> {
+        let mut bodies = Vec::new();
+        for member in self.members(path).into_iter() {
+            match member {
+                SourceUnitMember::FunctionDefinition(function) => bodies.extend(function.body()),
+                SourceUnitMember::ContractDefinition(definition) => bodies.extend(
+                    definition
+                        .members()
+                        .iter()
+                        .filter_map(|member| match member {
+                            ContractMember::FunctionDefinition(function) => function.body(),
+                            _ => None,
+                        }),
+                ),
+                _ => {}
+            }
+        }
+
+        let mut ranges = Vec::new();
+        for body in bodies.into_iter() {
+            Self::collect_statement_ranges(body.statements().iter().collect(), depth, &mut ranges);
+        }
+        ranges
+    }
+
+    ///
+    /// The members of the file at `path`, or none if the file is not a test source.
+    ///
+    fn members(&self, path: &str) -> Vec<SourceUnitMember> {
+        self.unit
+            .file(&FileId::from(path))
+            .map(|file| file.ast().members().iter().collect())
+            .unwrap_or_default()
+    }
+
+    ///
+    /// Collects the ranges of `statements` and their nested statements `depth` levels deep.
+    ///
+    fn collect_statement_ranges(
+        statements: Vec<Statement>,
+        depth: usize,
+        ranges: &mut Vec<Range<usize>>,
+    ) {
+        if depth == 0 {
+            ranges.extend(statements.iter().map(|statement| statement.text_range()));
+            return;
+        }
+
+        for statement in statements.into_iter() {
+            let nested = match statement {
+                Statement::Block(block) => block.statements().iter().collect(),
+                Statement::UncheckedBlock(block) => block.block().statements().iter().collect(),
+                Statement::IfStatement(statement) => {
+                    let mut nested = vec![statement.body()];
+                    nested.extend(statement.else_branch());
+                    nested
+                }
+                Statement::ForStatement(statement) => vec![statement.body()],
+                Statement::WhileStatement(statement) => vec![statement.body()],
+                Statement::DoWhileStatement(statement) => vec![statement.body()],
+                _ => continue,
+            };
+            Self::collect_statement_ranges(nested, depth - 1, ranges);
+        }
+    }
 }
 
 ///
@@ -0,0 +1,87 @@
+//!
+//! The map from the contract codes of a test to their source lines.
+//!
+
+use std::collections::HashMap;
+
+use solx_utils::CodeSegment;
+
+use crate::compilers::solidity::dwarf::LineTable;
+
+///
+/// The map from the contract codes of a test to their source lines.
+///
+#[derive(Debug)]
+pub struct CodeMap {
+    /// The contract names and deploy codes, used to recognize the contract creations.
+    contracts: Vec<(String, Vec<u8>)>,
+    /// The DWARF line tables by contract index and code segment.
+    line_tables: HashMap<(usize, CodeSegment), LineTable>,
+}
+
+impl CodeMap {
+    ///
+    /// A shortcut constructor.
+    ///
+    /// Returns `None` if there is no debug information, so the test cannot be covered.
+    ///
+    pub fn new(
+        builds: HashMap<String, (Vec<u8>, usize)>,
+        line_tables: Vec<(String, CodeSegment, LineTable)>,
+    ) -> Option<Self> {
+        let mut contracts: Vec<(String, Vec<u8>)> = Vec::with_capacity(builds.len());
+        let mut indexed_line_tables = HashMap::with_capacity(line_tables.len());
+        for (contract, code_segment, line_table) in line_tables.into_iter() {
+            let index = match contracts.iter().position(|(name, _)| name == &contract) {
+                Some(index) => index,
+                None => {
+                    let Some((deploy_code, _)) = builds.get(contract.as_str()) else {
+                        continue;
+                    };
+                    contracts.push((contract, deploy_code.to_owned()));
+                    contracts.len() - 1
+                }
+            };
+            indexed_line_tables.insert((index, code_segment), line_table);
+        }
+        if indexed_line_tables.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            contracts,
+            line_tables: indexed_line_tables,
+        })
+    }
+
+    ///
+    /// Returns the index of the contract created by `init_code`.
+    ///
+    /// The constructor arguments are appended to the deploy code, so the longest deploy code the
+    /// init code starts with is chosen.
+    ///
+    pub fn find_contract(&self, init_code: &[u8]) -> Option<usize> {
+        self.contracts
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, deploy_code))| {
+                !deploy_code.is_empty() && init_code.starts_with(deploy_code.as_slice())
+            })
+            .max_by_key(|(_, (_, deploy_code))| deploy_code.len())
+            .map(|(index, _)| index)
+    }
+
+    ///
+    /// Returns the line table of the contract code segment.
+    ///
+    pub fn line_table(&self, index: usize, code_segment: CodeSegment) -> Option<&LineTable> {
+        self.line_tables.get(&(index, code_segment))
+    }
+
+    ///
+    /// Returns all line tables.
+    ///
+    pub fn line_tables(&self) -> impl Iterator<Item = &LineTable> {
+        self.line_tables.values()
+    }
+}
@@ -0,0 +1,229 @@
+//!
+//! The code coverage of a test run.
+//!
+
+pub mod code_map;
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use crate::revm::coverage::Hits;
+
+use self::code_map::CodeMap;
+
+///
+/// The code coverage of a test run.
+///
+/// The executed program counters are mapped to the Solidity source lines with the DWARF line
+/// tables emitted by `solx`, so the coverage is that of the optimized bytecode.
+///
+#[derive(Debug, Default)]
+pub struct Coverage {
+    /// The execution counts of the lines with code, by source file path and line.
+    files: BTreeMap<String, BTreeMap<u64, u64>>,
+}
+
+impl Coverage {
+    ///
+    /// Wraps data into a thread-safe shared reference.
+    ///
+    pub fn wrap(self) -> Arc<Mutex<Self>> {
+        Arc::new(Mutex::new(self))
+    }
+
+    ///
+    /// Extracts the data from the thread-safe shared reference.
+    ///
+    pub fn unwrap_arc(coverage: Arc<Mutex<Self>>) -> Self {
+        Arc::try_unwrap(coverage)
+            .expect("Last shared reference")
+            .into_inner()
+            .expect("Last shared reference")
+    }
+
+    ///
+    /// Adds the program counter hits of a test case.
+    ///
+    /// All lines of the line tables are added, so that the lines that have not been executed are
+    /// reported as well. The line execution count is the one of its most executed instruction.
+    ///
+    pub fn add(&mut self, code_map: &CodeMap, hits: Hits) {
+        for line_table in code_map.line_tables() {
+            for (path, line) in line_table.values() {
+                if *line == 0 {
+                    continue;
+                }
+                self.files
+                    .entry(path.to_owned())
+                    .or_default()
+                    .entry(*line)
+                    .or_default();
+            }
+        }
+
+        for ((index, code_segment), counts) in hits.into_iter() {
+            let Some(line_table) = code_map.line_table(index, code_segment) else {
+                continue;
+            };
+
+            let mut lines: BTreeMap<(&str, u64), u64> = BTreeMap::new();
+            for (pc, count) in counts.into_iter() {
+                let Some((_address, (path, line))) = line_table.range(..=pc as u64).next_back()
+                else {
+                    continue;
+                };
+                if *line == 0 {
+                    continue;
+                }
+                let line_count = lines.entry((path.as_str(), *line)).or_default();
+                *line_count = (*line_count).max(count);
+            }
+            for ((path, line), count) in lines.into_iter() {
+                *self
+                    .files
+                    .entry(path.to_owned())
+                    .or_default()
+                    .entry(line)
+                    .or_default() += count;
+            }
+        }
+    }
+
+    ///
+    /// Writes the coverage to `path` in the LCOV tracefile format.
+    ///
+    pub fn write_lcov(&self, path: &Path) -> anyhow::Result<()> {
+        std::fs::write(path, self.lcov())
+            .map_err(|error| anyhow::anyhow!("LCOV coverage {path:?} writing: {error}"))
+    }
+
+    ///
+    /// Renders the coverage in the LCOV tracefile format.
+    ///
+    fn lcov(&self) -> String {
+        let mut lcov = String::new();
+        for (source_path, lines) in self.files.iter() {
+            writeln!(lcov, "TN:").expect("Always valid");
+            writeln!(lcov, "SF:{source_path}").expect("Always valid");
+            for (line, count) in lines.iter() {
+                writeln!(lcov, "DA:{line},{count}").expect("Always valid");
+            }
+            writeln!(lcov, "LF:{}", lines.len()).expect("Always valid");
+            writeln!(
+                lcov,
+                "LH:{}",
+                lines.values().filter(|count| **count > 0).count()
+            )
+            .expect("Always valid");
+            writeln!(lcov, "end_of_record").expect("Always valid");
+        }
+        lcov
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use solx_utils::CodeSegment;
+
+    use crate::compilers::solidity::dwarf::LineTable;
+    use crate::revm::coverage::Hits;
+
+    use super::Coverage;
+    use super::code_map::CodeMap;
+
+    /// A code map of a single contract with the runtime line table `lines`.
+    fn code_map(lines: &[(u64, &str, u64)]) -> CodeMap {
+        let line_table: LineTable = lines
+            .iter()
+            .map(|(address, path, line)| (*address, ((*path).to_owned(), *line)))
+            .collect();
+        CodeMap::new(
+            HashMap::from([("a.sol:A".to_owned(), (vec![0x60, 0x80], 0))]),
+            vec![("a.sol:A".to_owned(), CodeSegment::Runtime, line_table)],
+        )
+        .expect("Always has debug information")
+    }
+
+    /// The runtime hits of the only contract.
+    fn hits(counts: &[(usize, u64)]) -> Hits {
+        Hits::from([((0, CodeSegment::Runtime), counts.iter().copied().collect())])
+    }
+
+    /// A program counter must be attributed to the closest preceding line table row, skipping
+    /// the compiler-generated code at line 0, and a line must take its most executed instruction.
+    #[test]
+    fn program_counters_to_lines() {
+        let code_map = code_map(&[
+            (2, "a.sol", 1),
+            (4, "a.sol", 0),
+            (8, "a.sol", 2),
+            (12, "a.sol", 3),
+        ]);
+        let mut coverage = Coverage::default();
+        coverage.add(
+            &code_map,
+            hits(&[(0, 9), (2, 5), (3, 1), (5, 7), (9, 3), (10, 4)]),
+        );
+        coverage.add(&code_map, hits(&[(8, 2)]));
+        assert_eq!(
+            coverage.lcov(),
+            "TN:\nSF:a.sol\nDA:1,5\nDA:2,6\nDA:3,0\nLF:3\nLH:2\nend_of_record\n"
+        );
+    }
+
+    /// The hits of code segments without a line table must be ignored.
+    #[test]
+    fn code_segments_without_line_tables() {
+        let code_map = code_map(&[(0, "a.sol", 1)]);
+        let mut coverage = Coverage::default();
+        coverage.add(
+            &code_map,
+            Hits::from([((0, CodeSegment::Deploy), HashMap::from([(0, 1)]))]),
+        );
+        assert_eq!(
+            coverage.lcov(),
+            "TN:\nSF:a.sol\nDA:1,0\nLF:1\nLH:0\nend_of_record\n"
+        );
+    }
+
+    /// The contract with the longest deploy code the init code starts with must be chosen, as
+    /// the constructor arguments are appended to the deploy code.
+    #[test]
+    fn longest_deploy_code_prefix() {
+        let line_table = || LineTable::from([(0, ("a.sol".to_owned(), 1))]);
+        let code_map = CodeMap::new(
+            HashMap::from([
+                ("a.sol:A".to_owned(), (vec![0x60, 0x80], 0)),
+                ("a.sol:B".to_owned(), (vec![0x60, 0x80, 0x60], 1)),
+                ("a.sol:C".to_owned(), (vec![], 2)),
+            ]),
+            vec![
+                ("a.sol:A".to_owned(), CodeSegment::Deploy, line_table()),
+                ("a.sol:B".to_owned(), CodeSegment::Deploy, line_table()),
+                ("a.sol:C".to_owned(), CodeSegment::Deploy, line_table()),
+            ],
+        )
+        .expect("Always has debug information");
+        assert_eq!(code_map.find_contract(&[0x60, 0x80, 0x60, 0x40]), Some(1));
+        assert_eq!(code_map.find_contract(&[0x60, 0x80, 0x00]), Some(0));
+        assert_eq!(code_map.find_contract(&[0x60]), None);
+        assert_eq!(code_map.find_contract(&[]), None);
+    }
+
+    /// A test without line tables cannot be covered.
+    #[test]
+    fn no_debug_information() {
+        assert!(
+            CodeMap::new(
+                HashMap::from([("a.sol:A".to_owned(), (vec![0x60], 0))]),
+                vec![],
+            )
+            .is_none()
+        );
+    }
+}
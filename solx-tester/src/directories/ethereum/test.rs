@@ -12,6 +12,7 @@ use solx_utils::ContractName;
 
 use crate::compilers::Compiler;
 use crate::compilers::mode::Mode;
+use crate::coverage::code_map::CodeMap;
 use crate::directories::Buildable;
 use crate::filters::Filters;
 use crate::revm::address_iterator::AddressIterator;
@@ -237,21 +238,18 @@ impl Buildable for EthereumTest {
             }
         };
 
-        let evm_input = match compiler
-            .compile_for_evm(
-                self.selector.to_string(),
-                self.test.sources.clone(),
-                libraries,
-                &mode,
-                Some(&self.test.params),
-                vec![],
-                debug_config,
-            )
-            .map_err(|error| anyhow::anyhow!("Failed to compile sources:\n{error}"))
-        {
+        let evm_input = match compiler.compile_for_evm(
+            self.selector.to_string(),
+            self.test.sources.clone(),
+            libraries,
+            &mode,
+            Some(&self.test.params),
+            vec![],
+            debug_config,
+        ) {
             Ok(output) => output,
             Err(error) => {
-                Summary::invalid(summary, test_description, error);
+                Summary::compilation_failed(summary, test_description, error);
                 return None;
             }
         };
@@ -281,6 +279,7 @@ impl Buildable for EthereumTest {
             vec![case],
             mode,
             self.index_entity.group.clone(),
+            CodeMap::new(evm_input.builds, evm_input.line_tables),
         ))
     }
 }
@@ -0,0 +1,18 @@
+//!
+//! The Matter Labs compiler test metadata contract code size budget.
+//!
+
+use serde::Deserialize;
+
+use crate::directories::matter_labs::test::metadata::budget::Budget;
+
+///
+/// The Matter Labs compiler test metadata contract code size budget.
+///
+#[derive(Debug, Clone, Deserialize)]
+pub struct Contract {
+    /// The maximum deploy code size.
+    pub max_size: Option<Budget>,
+    /// The maximum runtime code size.
+    pub max_runtime_size: Option<Budget>,
+}
@@ -0,0 +1,51 @@
+//!
+//! The Matter Labs compiler test metadata gas or code size budget.
+//!
+
+pub mod contract;
+pub mod variant;
+
+use serde::Deserialize;
+
+use crate::compilers::mode::Mode;
+
+use self::variant::Variant;
+
+///
+/// The Matter Labs compiler test metadata gas or code size budget.
+///
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum Budget {
+    /// The single budget variant.
+    Single(Variant),
+    /// The several budget variants, of which the first one matching the mode is checked.
+    Multiple(Vec<Variant>),
+}
+
+impl Budget {
+    ///
+    /// Returns the budget value and tolerance in percent for `mode`, if any variant matches it.
+    ///
+    /// The budgets lock in the `solx` optimization results, so the variants without mode
+    /// filters only match the `solx` modes. Reference `solc` modes must be selected explicitly.
+    ///
+    pub fn select(self, mode: &Mode) -> Option<(u64, f64)> {
+        let variants = match self {
+            Self::Single(variant) => vec![variant],
+            Self::Multiple(variants) => variants,
+        };
+        let is_solx = mode.toolchain() == "solx";
+        variants.into_iter().find_map(|variant| match variant {
+            Variant::Simple(value) if is_solx => Some((value, 0.0)),
+            Variant::Simple(_) => None,
+            Variant::Extended(extended) => {
+                let is_matching = match extended.modes.as_ref() {
+                    Some(filters) => mode.check_extended_filters(filters.as_slice()),
+                    None => is_solx,
+                };
+                is_matching.then_some((extended.value, extended.tolerance))
+            }
+        })
+    }
+}
@@ -0,0 +1,31 @@
+//!
+//! The Matter Labs compiler test metadata budget variant.
+//!
+
+use serde::Deserialize;
+
+///
+/// The Matter Labs compiler test metadata budget variant.
+///
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum Variant {
+    /// The exact value for all `solx` modes.
+    Simple(u64),
+    /// The value with a tolerance and a mode filter.
+    Extended(Extended),
+}
+
+///
+/// The Matter Labs compiler test metadata extended budget variant.
+///
+#[derive(Debug, Clone, Deserialize)]
+pub struct Extended {
+    /// The budget value.
+    pub value: u64,
+    /// The allowed deviation from the value in percent.
+    #[serde(default)]
+    pub tolerance: f64,
+    /// The mode filter. Only the `solx` modes are matched if not set.
+    pub modes: Option<Vec<String>>,
+}
@@ -0,0 +1,15 @@
+//!
+//! The Matter Labs compiler test metadata case input expected account code.
+//!
+
+///
+/// The Matter Labs compiler test metadata case input expected account code.
+///
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Code {
+    /// The code hash, as returned by `EXTCODEHASH`.
+    Hash(String),
+    /// The exact runtime bytecode as a hexadecimal string.
+    Bytecode(String),
+}
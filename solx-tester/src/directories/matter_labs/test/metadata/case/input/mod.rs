@@ -3,15 +3,18 @@
 //!
 
 pub mod calldata;
+pub mod code;
 pub mod expected;
 pub mod storage;
 
 use std::collections::HashMap;
 
 use crate::directories::matter_labs::test::default_caller_address;
+use crate::directories::matter_labs::test::metadata::budget::Budget;
 use crate::directories::matter_labs::test::simple_tests_instance;
 
 use self::calldata::Calldata;
+use self::code::Code;
 use self::expected::Expected;
 use self::storage::Storage;
 
@@ -42,6 +45,16 @@ pub struct Input {
 
     /// The expected return data.
     pub expected: Option<Expected>,
+    /// The expected transient storage at the end of the call transaction.
+    #[serde(default)]
+    pub expected_transient_storage: HashMap<String, Storage>,
+    /// The expected account code after the call.
+    #[serde(default)]
+    pub expected_code: HashMap<String, Code>,
+    /// The expected gas usage.
+    pub expected_gas: Option<Budget>,
+    /// The maximum gas usage.
+    pub max_gas: Option<Budget>,
 }
 
 impl Input {
@@ -59,6 +72,10 @@ impl Input {
             storage: HashMap::new(),
 
             expected: Some(Expected::successful_deployer_expected(instance.clone())),
+            expected_transient_storage: HashMap::new(),
+            expected_code: HashMap::new(),
+            expected_gas: None,
+            max_gas: None,
         }
     }
 }
@@ -2,11 +2,13 @@
 //! The Matter Labs compiler test metadata.
 //!
 
+pub mod budget;
 pub mod case;
 
 use std::collections::BTreeMap;
 use std::str::FromStr;
 
+use self::budget::contract::Contract as ContractBudget;
 use self::case::Case;
 
 ///
@@ -28,6 +30,9 @@ pub struct Metadata {
     /// The test libraries for linking.
     #[serde(default)]
     pub libraries: BTreeMap<String, BTreeMap<String, String>>,
+    /// The contract code size budgets as `instance -> budget`.
+    #[serde(default)]
+    pub budgets: BTreeMap<String, ContractBudget>,
     /// If the entire test file must be ignored.
     #[serde(default)]
     pub ignore: bool,
@@ -18,6 +18,7 @@ use solx_utils::ContractName;
 
 use crate::compilers::Compiler;
 use crate::compilers::mode::Mode;
+use crate::coverage::code_map::CodeMap;
 use crate::directories::Buildable;
 use crate::filters::Filters;
 use crate::revm::address_iterator::AddressIterator;
@@ -194,6 +195,13 @@ impl MatterLabsTest {
         })
     }
 
+    ///
+    /// Returns the test sources.
+    ///
+    pub fn sources(&self) -> &[(String, String)] {
+        self.sources.as_slice()
+    }
+
     ///
     /// Checks if the test is not filtered out.
     ///
@@ -299,21 +307,18 @@ impl Buildable for MatterLabsTest {
             selector: self.selector.clone(),
         };
 
-        let evm_input = match compiler
-            .compile_for_evm(
-                self.selector.path.to_string(),
-                sources,
-                libraries,
-                &mode,
-                None,
-                vec![],
-                debug_config,
-            )
-            .map_err(|error| anyhow::anyhow!("Failed to compile sources:\n{error}"))
-        {
+        let evm_input = match compiler.compile_for_evm(
+            self.selector.path.to_string(),
+            sources,
+            libraries,
+            &mode,
+            None,
+            vec![],
+            debug_config,
+        ) {
             Ok(output) => output,
             Err(error) => {
-                Summary::invalid(summary, test_description, error);
+                Summary::compilation_failed(summary, test_description, error);
                 return None;
             }
         };
@@ -356,6 +361,7 @@ impl Buildable for MatterLabsTest {
                 &mode,
                 &instances,
                 &evm_input.method_identifiers,
+                &self.metadata.budgets,
             )
             .map_err(|error| anyhow::anyhow!("Case `{case_name}` is invalid: {error}"))
             {
@@ -374,6 +380,7 @@ impl Buildable for MatterLabsTest {
             cases,
             mode,
             self.metadata.group.clone(),
+            CodeMap::new(evm_input.builds, evm_input.line_tables),
         ))
     }
 }
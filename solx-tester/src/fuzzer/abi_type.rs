@@ -0,0 +1,322 @@
+//!
+//! The fuzzer ABI type.
+//!
+
+use std::str::FromStr;
+
+use revm::primitives::U256;
+
+use crate::fuzzer::random::Random;
+
+///
+/// The fuzzer ABI type.
+///
+/// Parsed from the canonical types of the method identifier signatures, and used to generate
+/// random ABI-conformant calldata.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ABIType {
+    /// `uint<M>`.
+    Uint(usize),
+    /// `int<M>`.
+    Int(usize),
+    /// `address`.
+    Address,
+    /// `bool`.
+    Bool,
+    /// `bytes<M>`, as well as `function`, which is encoded as `bytes24`.
+    FixedBytes(usize),
+    /// `bytes`.
+    Bytes,
+    /// `string`.
+    String,
+    /// `T[]`.
+    Array(Box<ABIType>),
+    /// `T[k]`.
+    FixedArray(Box<ABIType>, usize),
+    /// `(T1,T2,...)`.
+    Tuple(Vec<ABIType>),
+}
+
+impl ABIType {
+    /// The maximum length of generated dynamic arrays.
+    const ARRAY_LENGTH_MAX: usize = 4;
+    /// The maximum length of generated `bytes` and `string` values.
+    const BYTES_LENGTH_MAX: usize = 80;
+
+    ///
+    /// Parses the parameter types of a canonical function signature, e.g. `f(uint256,bool[])`.
+    ///
+    pub fn parse_signature(signature: &str) -> anyhow::Result<Vec<Self>> {
+        let parameters = signature
+            .find('(')
+            .map(|index| &signature[index..])
+            .ok_or_else(|| anyhow::anyhow!("Signature `{signature}` has no parameter list"))?;
+        match Self::from_str(parameters)? {
+            Self::Tuple(types) => Ok(types),
+            _ => anyhow::bail!("Signature `{signature}` has an invalid parameter list"),
+        }
+    }
+
+    ///
+    /// Generates a random value of the tuple of `types`, and returns its ABI encoding.
+    ///
+    pub fn random_tuple(types: &[Self], random: &mut Random, addresses: &[U256]) -> Vec<u8> {
+        let components = types
+            .iter()
+            .map(|r#type| (r#type.is_dynamic(), r#type.random(random, addresses)))
+            .collect::<Vec<(bool, Vec<u8>)>>();
+
+        let head_size = components
+            .iter()
+            .map(|(is_dynamic, encoded)| {
+                if *is_dynamic {
+                    solx_utils::BYTE_LENGTH_FIELD
+                } else {
+                    encoded.len()
+                }
+            })
+            .sum::<usize>();
+
+        let mut head = Vec::with_capacity(head_size);
+        let mut tail = Vec::new();
+        for (is_dynamic, encoded) in components.into_iter() {
+            if is_dynamic {
+                head.extend(Self::word(U256::from(head_size + tail.len())));
+                tail.extend(encoded);
+            } else {
+                head.extend(encoded);
+            }
+        }
+        head.extend(tail);
+        head
+    }
+
+    ///
+    /// Whether the type is dynamically sized, and is therefore encoded in the tail.
+    ///
+    pub fn is_dynamic(&self) -> bool {
+        match self {
+            Self::Bytes | Self::String | Self::Array(_) => true,
+            Self::FixedArray(r#type, _) => r#type.is_dynamic(),
+            Self::Tuple(types) => types.iter().any(Self::is_dynamic),
+            _ => false,
+        }
+    }
+
+    ///
+    /// Generates a random value of the type, and returns its ABI encoding.
+    ///
+    /// Values are biased towards the boundaries of the type range, and addresses are biased
+    /// towards `addresses` known to the test environment.
+    ///
+    fn random(&self, random: &mut Random, addresses: &[U256]) -> Vec<u8> {
+        match self {
+            Self::Uint(bits) => Self::word(Self::random_uint(*bits, random)),
+            Self::Int(bits) => {
+                let value = Self::random_uint(*bits, random);
+                let sign_bit = U256::from(1) << (*bits - 1);
+                let value = if *bits < 256 && (value & sign_bit) != U256::ZERO {
+                    value | (U256::MAX << *bits)
+                } else {
+                    value
+                };
+                Self::word(value)
+            }
+            Self::Address => {
+                let value = if !addresses.is_empty() && !random.one_in(4) {
+                    *random.choose(addresses)
+                } else {
+                    Self::random_uint(solx_utils::BIT_LENGTH_ETH_ADDRESS, random)
+                };
+                Self::word(value)
+            }
+            Self::Bool => Self::word(U256::from(random.below(2))),
+            Self::FixedBytes(length) => {
+                let mut word = random.bytes(*length);
+                word.resize(solx_utils::BYTE_LENGTH_FIELD, 0);
+                word
+            }
+            Self::Bytes => Self::encode_bytes(random.bytes(Self::random_length(random))),
+            Self::String => {
+                let length = Self::random_length(random);
+                let string = (0..length)
+                    .map(|_| b' ' + random.below(95) as u8)
+                    .collect::<Vec<u8>>();
+                Self::encode_bytes(string)
+            }
+            Self::Array(r#type) => {
+                let length = random.below(Self::ARRAY_LENGTH_MAX + 1);
+                let mut encoded = Self::word(U256::from(length));
+                encoded.extend(Self::random_tuple(
+                    vec![r#type.as_ref().to_owned(); length].as_slice(),
+                    random,
+                    addresses,
+                ));
+                encoded
+            }
+            Self::FixedArray(r#type, length) => Self::random_tuple(
+                vec![r#type.as_ref().to_owned(); *length].as_slice(),
+                random,
+                addresses,
+            ),
+            Self::Tuple(types) => Self::random_tuple(types.as_slice(), random, addresses),
+        }
+    }
+
+    ///
+    /// Generates a random unsigned integer of `bits` width.
+    ///
+    fn random_uint(bits: usize, random: &mut Random) -> U256 {
+        let max = if bits == 256 {
+            U256::MAX
+        } else {
+            (U256::from(1) << bits) - U256::from(1)
+        };
+        let value = match random.below(8) {
+            0 => U256::ZERO,
+            1 => U256::from(1),
+            2 => max,
+            3 => max - U256::from(1),
+            4 => max >> 1,
+            5 => (max >> 1) + U256::from(1),
+            6 => U256::from(random.below(256)),
+            _ => random.word(),
+        };
+        value & max
+    }
+
+    ///
+    /// Returns a random length of `bytes` and `string` values, biased towards word boundaries.
+    ///
+    fn random_length(random: &mut Random) -> usize {
+        match random.below(4) {
+            0 => 0,
+            1 => solx_utils::BYTE_LENGTH_FIELD * (1 + random.below(2)),
+            _ => random.below(Self::BYTES_LENGTH_MAX + 1),
+        }
+    }
+
+    ///
+    /// Encodes a `bytes` or `string` value.
+    ///
+    fn encode_bytes(mut bytes: Vec<u8>) -> Vec<u8> {
+        let mut encoded = Self::word(U256::from(bytes.len()));
+        let padded_length =
+            bytes.len().div_ceil(solx_utils::BYTE_LENGTH_FIELD) * solx_utils::BYTE_LENGTH_FIELD;
+        bytes.resize(padded_length, 0);
+        encoded.extend(bytes);
+        encoded
+    }
+
+    ///
+    /// Encodes a 256-bit word.
+    ///
+    fn word(value: U256) -> Vec<u8> {
+        value
+            .to_be_bytes::<{ solx_utils::BYTE_LENGTH_FIELD }>()
+            .to_vec()
+    }
+
+    ///
+    /// Splits the list of types by the top-level commas.
+    ///
+    fn split_list(list: &str) -> anyhow::Result<Vec<&str>> {
+        let mut elements = Vec::new();
+        let mut depth = 0usize;
+        let mut start = 0;
+        for (index, character) in list.char_indices() {
+            match character {
+                '(' => depth += 1,
+                ')' => {
+                    depth = depth
+                        .checked_sub(1)
+                        .ok_or_else(|| anyhow::anyhow!("Unbalanced parentheses in `{list}`"))?;
+                }
+                ',' if depth == 0 => {
+                    elements.push(&list[start..index]);
+                    start = index + 1;
+                }
+                _ => {}
+            }
+        }
+        if depth != 0 {
+            anyhow::bail!("Unbalanced parentheses in `{list}`");
+        }
+        if !list.is_empty() {
+            elements.push(&list[start..]);
+        }
+        Ok(elements)
+    }
+}
+
+impl FromStr for ABIType {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        if let Some(element) = string.strip_suffix(']') {
+            let (element, length) = element
+                .rsplit_once('[')
+                .ok_or_else(|| anyhow::anyhow!("Invalid array type `{string}`"))?;
+            let element = Box::new(Self::from_str(element)?);
+            return if length.is_empty() {
+                Ok(Self::Array(element))
+            } else {
+                let length = length.parse::<usize>().map_err(|error| {
+                    anyhow::anyhow!("Invalid array length in `{string}`: {error}")
+                })?;
+                Ok(Self::FixedArray(element, length))
+            };
+        }
+
+        if let Some(list) = string
+            .strip_prefix('(')
+            .and_then(|string| string.strip_suffix(')'))
+        {
+            return Ok(Self::Tuple(
+                Self::split_list(list)?
+                    .into_iter()
+                    .map(Self::from_str)
+                    .collect::<anyhow::Result<Vec<Self>>>()?,
+            ));
+        }
+
+        let parse_size = |size: &str, default: usize| -> anyhow::Result<usize> {
+            if size.is_empty() {
+                return Ok(default);
+            }
+            size.parse::<usize>()
+                .map_err(|error| anyhow::anyhow!("Invalid type size in `{string}`: {error}"))
+        };
+
+        Ok(match string {
+            "address" => Self::Address,
+            "bool" => Self::Bool,
+            "bytes" => Self::Bytes,
+            "string" => Self::String,
+            "function" => Self::FixedBytes(24),
+            string if string.starts_with("uint") => {
+                let bits = parse_size(&string["uint".len()..], solx_utils::BIT_LENGTH_FIELD)?;
+                if bits == 0 || bits > solx_utils::BIT_LENGTH_FIELD || bits % 8 != 0 {
+                    anyhow::bail!("Invalid integer type `{string}`");
+                }
+                Self::Uint(bits)
+            }
+            string if string.starts_with("int") => {
+                let bits = parse_size(&string["int".len()..], solx_utils::BIT_LENGTH_FIELD)?;
+                if bits == 0 || bits > solx_utils::BIT_LENGTH_FIELD || bits % 8 != 0 {
+                    anyhow::bail!("Invalid integer type `{string}`");
+                }
+                Self::Int(bits)
+            }
+            string if string.starts_with("bytes") => {
+                let length = parse_size(&string["bytes".len()..], 0)?;
+                if length == 0 || length > solx_utils::BYTE_LENGTH_FIELD {
+                    anyhow::bail!("Invalid fixed bytes type `{string}`");
+                }
+                Self::FixedBytes(length)
+            }
+            string => anyhow::bail!("Unsupported ABI type `{string}`"),
+        })
+    }
+}
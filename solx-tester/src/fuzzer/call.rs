@@ -0,0 +1,51 @@
+//!
+//! The fuzzer contract call.
+//!
+
+use revm::primitives::U256;
+
+use crate::fuzzer::abi_type::ABIType;
+use crate::fuzzer::random::Random;
+
+///
+/// The fuzzer contract call.
+///
+#[derive(Debug, Clone)]
+pub struct Call {
+    /// The function signature.
+    pub signature: String,
+    /// The function selector.
+    pub selector: u32,
+    /// The ABI-encoded arguments.
+    pub arguments: Vec<u8>,
+}
+
+impl Call {
+    ///
+    /// Generates a call of the function with random arguments.
+    ///
+    pub fn random(
+        signature: String,
+        selector: u32,
+        types: &[ABIType],
+        random: &mut Random,
+        addresses: &[U256],
+    ) -> Self {
+        let arguments = ABIType::random_tuple(types, random, addresses);
+        Self {
+            signature,
+            selector,
+            arguments,
+        }
+    }
+
+    ///
+    /// Returns the calldata: the selector followed by the arguments.
+    ///
+    pub fn calldata(&self) -> Vec<u8> {
+        let mut calldata = Vec::with_capacity(solx_utils::BYTE_LENGTH_X32 + self.arguments.len());
+        calldata.extend(self.selector.to_be_bytes());
+        calldata.extend_from_slice(self.arguments.as_slice());
+        calldata
+    }
+}
@@ -0,0 +1,443 @@
+//!
+//! The ABI-driven differential fuzzer.
+//!
+
+pub mod abi_type;
+pub mod call;
+pub mod outcome;
+pub mod random;
+
+use std::collections::BTreeSet;
+use std::path::Path;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use colored::Colorize;
+use revm::context::result::ExecutionResult;
+use revm::primitives::Address;
+use revm::primitives::U256;
+
+use crate::compilers::Compiler;
+use crate::compilers::mode::Mode;
+use crate::compilers::solidity::SolidityCompiler;
+use crate::compilers::solidity::mode::Mode as SolidityMode;
+use crate::directories::matter_labs::test::DEFAULT_CALLER_ADDRESS;
+use crate::directories::matter_labs::test::SIMPLE_TESTS_CONTRACT_NAME;
+use crate::directories::matter_labs::test::SIMPLE_TESTS_INSTANCE;
+use crate::revm::REVM;
+use crate::revm::input::Input as EVMInput;
+use crate::test::case::input::calldata::Calldata;
+use crate::toolchain::Toolchain;
+
+use self::abi_type::ABIType;
+use self::call::Call;
+use self::outcome::Outcome;
+use self::random::Random;
+
+///
+/// The ABI-driven differential fuzzer.
+///
+/// Compiles a contract with `solx` and the reference `solc`, runs random ABI-conformant call
+/// sequences on both builds in REVM, and saves the sequences whose outcomes diverge as
+/// Matter Labs-format tests, with the reference outcomes as the expected data.
+/// Divergences only in storage are reported but not saved, as the format cannot express them.
+///
+pub struct Fuzzer {
+    /// The `solx` compiler.
+    solx: SolidityCompiler,
+    /// The `solx` compilation mode.
+    solx_mode: Mode,
+    /// The reference `solc` compiler.
+    reference: SolidityCompiler,
+    /// The reference `solc` compilation mode.
+    reference_mode: Mode,
+    /// Whether the Yul IR pipeline is fuzzed.
+    via_ir: bool,
+    /// The number of call sequences to generate.
+    iterations: usize,
+    /// The maximum number of calls in a sequence.
+    calls: usize,
+    /// The input generator seed.
+    seed: u64,
+    /// The directory to save the diverging inputs to.
+    output_directory: PathBuf,
+}
+
+///
+/// The compiled contract under fuzzing.
+///
+struct Build {
+    /// The contract name.
+    name: String,
+    /// The deploy code.
+    deploy_code: Vec<u8>,
+    /// The public functions as `(signature, selector, parameter types)`.
+    functions: Vec<(String, u32, Vec<ABIType>)>,
+}
+
+impl Fuzzer {
+    /// The name of the generated test cases.
+    const CASE_NAME: &'static str = "fuzzed";
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        solidity_compiler: PathBuf,
+        reference_compiler: PathBuf,
+        via_ir: bool,
+        optimization: char,
+        iterations: usize,
+        calls: usize,
+        seed: u64,
+        output_directory: PathBuf,
+    ) -> anyhow::Result<Self> {
+        let solx = SolidityCompiler::new(
+            solidity_compiler.clone(),
+            solx_standard_json::InputLanguage::Solidity,
+        )?;
+        if solx.toolchain() != Toolchain::Solx {
+            anyhow::bail!("{solidity_compiler:?} is not a `solx` executable");
+        }
+        let reference = SolidityCompiler::new(
+            reference_compiler.clone(),
+            solx_standard_json::InputLanguage::Solidity,
+        )?;
+        if reference.toolchain() != Toolchain::Solc {
+            anyhow::bail!("{reference_compiler:?} is not a `solc` executable");
+        }
+
+        let solx_mode = SolidityMode::new_solx(
+            solx.version().to_owned(),
+            via_ir,
+            solx_codegen_evm::OptimizerSettings::try_from_cli(optimization)?,
+        )
+        .into();
+        let reference_mode =
+            SolidityMode::new_solc(reference.version().to_owned(), via_ir, true).into();
+
+        Ok(Self {
+            solx,
+            solx_mode,
+            reference,
+            reference_mode,
+            via_ir,
+            iterations,
+            calls,
+            seed,
+            output_directory,
+        })
+    }
+
+    ///
+    /// Fuzzes the last contract of the Solidity file at `path`.
+    ///
+    /// Returns the number of distinct divergences, including the unsaved storage-only ones.
+    ///
+    pub fn run(&self, path: &Path) -> anyhow::Result<usize> {
+        let source_path = crate::utils::path_to_string_normalized(path);
+        let source_code = std::fs::read_to_string(path)
+            .map_err(|error| anyhow::anyhow!("Failed to read {path:?}: {error}"))?;
+        let sources = vec![(source_path.clone(), source_code.clone())];
+
+        let solx_build = self.build(&self.solx, &self.solx_mode, source_path.as_str(), &sources)?;
+        let reference_build = self.build(
+            &self.reference,
+            &self.reference_mode,
+            source_path.as_str(),
+            &sources,
+        )?;
+        if reference_build.functions.is_empty() {
+            anyhow::bail!("The contract in {path:?} has no public functions to fuzz");
+        }
+
+        println!(
+            "     {} {} with seed {} ({} vs reference {})",
+            "Fuzzing".bright_green().bold(),
+            source_path,
+            self.seed,
+            self.solx_mode,
+            self.reference_mode,
+        );
+
+        let (contract_address, _outcomes) =
+            Self::execute(reference_build.deploy_code.as_slice(), &[])?;
+        let caller = Address::from_str(DEFAULT_CALLER_ADDRESS).expect("Always valid");
+        let addresses = [caller, contract_address.unwrap_or_default(), Address::ZERO]
+            .iter()
+            .map(|address| U256::from_be_slice(address.as_slice()))
+            .collect::<Vec<U256>>();
+
+        let mut random = Random::new(self.seed);
+        let mut divergence_keys = BTreeSet::new();
+        for iteration in 0..self.iterations {
+            let calls = (0..=random.below(self.calls.max(1)))
+                .map(|_| {
+                    let (signature, selector, types) =
+                        random.choose(reference_build.functions.as_slice());
+                    Call::random(
+                        signature.to_owned(),
+                        *selector,
+                        types,
+                        &mut random,
+                        addresses.as_slice(),
+                    )
+                })
+                .collect::<Vec<Call>>();
+
+            let (_, solx_outcomes) =
+                Self::execute(solx_build.deploy_code.as_slice(), calls.as_slice())?;
+            let (_, reference_outcomes) =
+                Self::execute(reference_build.deploy_code.as_slice(), calls.as_slice())?;
+
+            let Some((index, differences)) = solx_outcomes
+                .iter()
+                .zip(reference_outcomes.iter())
+                .map(|(solx, reference)| solx.differences(reference))
+                .enumerate()
+                .find(|(_index, differences)| !differences.is_empty())
+            else {
+                continue;
+            };
+
+            let method = match index {
+                0 => "#deployer".to_owned(),
+                index => calls[index - 1].signature.to_owned(),
+            };
+            // The Matter Labs test format cannot express the expected storage, so a test saved
+            // for a storage-only divergence would pass with `solx` and hide the miscompilation.
+            let is_storage_only = differences
+                .iter()
+                .all(|difference| *difference == Outcome::STORAGE_DIFFERENCE);
+            let differences = differences.join(", ");
+            if !divergence_keys.insert((method.clone(), differences.clone())) {
+                continue;
+            }
+
+            if is_storage_only {
+                println!(
+                    "    {} {} diverges in {} at iteration {}, not saved as the test format cannot express storage expectations",
+                    "Divergence".bright_red().bold(),
+                    method,
+                    differences,
+                    iteration,
+                );
+            } else {
+                let test_path = self.save(
+                    path,
+                    source_code.as_str(),
+                    reference_build.name.as_str(),
+                    &calls[..index],
+                    &reference_outcomes[..=index],
+                    contract_address.unwrap_or_default(),
+                    format!("`{method}` diverges in {differences}").as_str(),
+                )?;
+                println!(
+                    "    {} {} diverges in {} at iteration {}, saved to {:?}",
+                    "Divergence".bright_red().bold(),
+                    method,
+                    differences,
+                    iteration,
+                    test_path,
+                );
+            }
+
+            // The deployment does not depend on the generated inputs.
+            if index == 0 {
+                break;
+            }
+        }
+
+        Ok(divergence_keys.len())
+    }
+
+    ///
+    /// Compiles the contract and collects its public functions.
+    ///
+    fn build(
+        &self,
+        compiler: &SolidityCompiler,
+        mode: &Mode,
+        source_path: &str,
+        sources: &[(String, String)],
+    ) -> anyhow::Result<Build> {
+        let EVMInput {
+            builds,
+            method_identifiers,
+            last_contract,
+            ..
+        } = compiler
+            .compile_for_evm(
+                source_path.to_owned(),
+                sources.to_vec(),
+                solx_utils::Libraries::default(),
+                mode,
+                None,
+                vec![],
+                None,
+            )
+            .map_err(|error| anyhow::anyhow!("{mode} compilation failed: {error}"))?;
+
+        let (deploy_code, _runtime_code_size) = builds
+            .get(last_contract.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Contract `{last_contract}` not found in the build"))?
+            .to_owned();
+        let functions = method_identifiers
+            .and_then(|mut method_identifiers| method_identifiers.remove(last_contract.as_str()))
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|(signature, selector)| {
+                let types = ABIType::parse_signature(signature.as_str()).ok()?;
+                Some((signature, selector, types))
+            })
+            .collect();
+
+        let name = last_contract
+            .rsplit_once(':')
+            .map(|(_path, name)| name)
+            .unwrap_or(last_contract.as_str())
+            .to_owned();
+
+        Ok(Build {
+            name,
+            deploy_code,
+            functions,
+        })
+    }
+
+    ///
+    /// Deploys the contract to a clean REVM and runs the calls.
+    ///
+    /// Mirrors the environment of the Matter Labs tests, so the saved inputs reproduce the same
+    /// outcomes when run by the tester. Returns the contract address and the outcomes of the
+    /// deployment and every call, stopping after a failed deployment.
+    ///
+    fn execute(
+        deploy_code: &[u8],
+        calls: &[Call],
+    ) -> anyhow::Result<(Option<Address>, Vec<Outcome>)> {
+        let mut vm = REVM::new(false);
+        let caller = Address::from_str(DEFAULT_CALLER_ADDRESS).expect("Always valid");
+        let mut outcomes = Vec::with_capacity(calls.len() + 1);
+
+        vm.set_account(&caller, U256::from(1) << 100);
+        vm.set_block_data(U256::from(1), U256::from(REVM::BLOCK_TIMESTAMP_STEP));
+        let result = vm
+            .execute_transaction(REVM::new_deploy_transaction(
+                caller,
+                None,
+                deploy_code.to_vec(),
+            ))
+            .map_err(|error| anyhow::anyhow!("Deploy transaction failed: {error:?}"))?;
+        let address = match result {
+            ExecutionResult::Success { ref output, .. } => output.address().copied(),
+            _ => None,
+        };
+        outcomes.push(Outcome::new(result, &vm, address));
+        let Some(address) = address else {
+            return Ok((None, outcomes));
+        };
+
+        for (index, call) in calls.iter().enumerate() {
+            let input_index = index + 1;
+            vm.set_account(&caller, U256::from(REVM::TX_BLOB_FEE));
+            vm.set_block_data(
+                U256::from(input_index + 1),
+                U256::from(((input_index + 1) as u128) * REVM::BLOCK_TIMESTAMP_STEP),
+            );
+            let result = vm
+                .execute_transaction(REVM::new_runtime_transaction(
+                    address,
+                    caller,
+                    Calldata::from(call.calldata()),
+                    None,
+                ))
+                .map_err(|error| anyhow::anyhow!("Runtime transaction failed: {error:?}"))?;
+            outcomes.push(Outcome::new(result, &vm, Some(address)));
+        }
+
+        Ok((Some(address), outcomes))
+    }
+
+    ///
+    /// Saves the calls with the reference outcomes as a Matter Labs-format test.
+    ///
+    /// `outcomes` starts with the deployment outcome, followed by the outcome of every call.
+    ///
+    fn save(
+        &self,
+        path: &Path,
+        source_code: &str,
+        contract_name: &str,
+        calls: &[Call],
+        outcomes: &[Outcome],
+        contract_address: Address,
+        comment: &str,
+    ) -> anyhow::Result<PathBuf> {
+        std::fs::create_dir_all(self.output_directory.as_path())?;
+        let stem = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let mut index = 0;
+        let test_path = loop {
+            let mut test_path = self.output_directory.to_owned();
+            test_path.push(format!(
+                "{stem}_{}_{index}.{}",
+                self.seed,
+                solx_utils::EXTENSION_SOLIDITY
+            ));
+            if !test_path.exists() {
+                break test_path;
+            }
+            index += 1;
+        };
+
+        let mut inputs = Vec::with_capacity(outcomes.len());
+        if outcomes[0].exception {
+            inputs.push(serde_json::json!({
+                "method": "#deployer",
+                "calldata": [],
+                "expected": outcomes[0].to_matter_labs_expected(&contract_address),
+            }));
+        }
+        for (call, outcome) in calls.iter().zip(outcomes.iter().skip(1)) {
+            inputs.push(serde_json::json!({
+                "method": call.signature,
+                "calldata": format!("0x{}", hex::encode(call.arguments.as_slice())),
+                "expected": outcome.to_matter_labs_expected(&contract_address),
+            }));
+        }
+        let mut metadata = serde_json::json!({
+            "modes": [if self.via_ir { "Y" } else { "E" }],
+            "cases": [{
+                "name": Self::CASE_NAME,
+                "comment": comment,
+                "inputs": inputs,
+            }],
+        });
+        if contract_name != SIMPLE_TESTS_CONTRACT_NAME {
+            let file_name = test_path
+                .file_name()
+                .map(|file_name| file_name.to_string_lossy().to_string())
+                .unwrap_or_default();
+            metadata["contracts"] = serde_json::json!({
+                SIMPLE_TESTS_INSTANCE: format!("{file_name}:{contract_name}"),
+            });
+        }
+
+        let mut test = serde_json::to_string_pretty(&metadata)
+            .expect("Always valid")
+            .lines()
+            .map(|line| format!("//! {line}\n"))
+            .collect::<String>();
+        for line in source_code.lines().filter(|line| !line.starts_with("//!")) {
+            test.push_str(line);
+            test.push('\n');
+        }
+
+        std::fs::write(test_path.as_path(), test)
+            .map_err(|error| anyhow::anyhow!("Failed to write {test_path:?}: {error}"))?;
+        Ok(test_path)
+    }
+}
@@ -0,0 +1,141 @@
+//!
+//! The fuzzer transaction outcome.
+//!
+
+use std::collections::BTreeMap;
+
+use revm::context::result::ExecutionResult;
+use revm::primitives::Address;
+use revm::primitives::Log;
+use revm::primitives::U256;
+
+use crate::revm::REVM;
+
+///
+/// The fuzzer transaction outcome.
+///
+/// Holds everything observable by the contract users, so that the outcomes produced by different
+/// compilers can be compared directly.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Outcome {
+    /// The return or revert data.
+    pub return_data: Vec<u8>,
+    /// Whether the transaction reverted or halted.
+    pub exception: bool,
+    /// The emitted logs.
+    pub logs: Vec<Log>,
+    /// The non-zero storage slots of the contract after the transaction.
+    pub storage: BTreeMap<U256, U256>,
+}
+
+impl Outcome {
+    /// The name of the storage outcome part.
+    pub const STORAGE_DIFFERENCE: &'static str = "storage";
+
+    ///
+    /// Collects the outcome of the transaction executed on `vm`.
+    ///
+    pub fn new(result: ExecutionResult, vm: &REVM, address: Option<Address>) -> Self {
+        let (return_data, exception, logs) = match result {
+            ExecutionResult::Success { output, logs, .. } => (output.data().to_vec(), false, logs),
+            ExecutionResult::Revert { output, .. } => (output.to_vec(), true, vec![]),
+            ExecutionResult::Halt { .. } => (vec![], true, vec![]),
+        };
+
+        let storage = address
+            .and_then(|address| vm.ctx_db().cache.accounts.get(&address))
+            .and_then(|account| account.account.as_ref())
+            .map(|account| {
+                account
+                    .storage
+                    .iter()
+                    .filter(|(_key, value)| !value.is_zero())
+                    .map(|(key, value)| (*key, *value))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self {
+            return_data,
+            exception,
+            logs,
+            storage,
+        }
+    }
+
+    ///
+    /// Returns the names of the outcome parts that differ from `other`.
+    ///
+    pub fn differences(&self, other: &Self) -> Vec<&'static str> {
+        let mut differences = Vec::with_capacity(4);
+        if self.exception != other.exception {
+            differences.push("revert status");
+        }
+        if self.return_data != other.return_data {
+            differences.push("return data");
+        }
+        if self.logs != other.logs {
+            differences.push("logs");
+        }
+        if self.storage != other.storage {
+            differences.push(Self::STORAGE_DIFFERENCE);
+        }
+        differences
+    }
+
+    ///
+    /// Converts the outcome into the Matter Labs test expected data.
+    ///
+    pub fn to_matter_labs_expected(&self, contract_address: &Address) -> serde_json::Value {
+        let return_data = Self::words(self.return_data.as_slice());
+        if !self.exception && self.logs.is_empty() {
+            return serde_json::json!(return_data);
+        }
+
+        let events = self
+            .logs
+            .iter()
+            .map(|log| {
+                let address = if log.address == *contract_address {
+                    format!(
+                        "{}.address",
+                        crate::directories::matter_labs::test::SIMPLE_TESTS_INSTANCE
+                    )
+                } else {
+                    format!("0x{}", crate::utils::address_as_string(&log.address))
+                };
+                let topics = log
+                    .data
+                    .topics()
+                    .iter()
+                    .map(|topic| format!("0x{}", hex::encode(topic.as_slice())))
+                    .collect::<Vec<String>>();
+                serde_json::json!({
+                    "address": address,
+                    "topics": topics,
+                    "values": Self::words(log.data.data.as_ref()),
+                })
+            })
+            .collect::<Vec<serde_json::Value>>();
+
+        serde_json::json!({
+            "return_data": return_data,
+            "events": events,
+            "exception": self.exception,
+        })
+    }
+
+    ///
+    /// Splits the data into hexadecimal words, padding the last one with zeros.
+    ///
+    fn words(data: &[u8]) -> Vec<String> {
+        data.chunks(solx_utils::BYTE_LENGTH_FIELD)
+            .map(|chunk| {
+                let mut word = [0u8; solx_utils::BYTE_LENGTH_FIELD];
+                word[..chunk.len()].copy_from_slice(chunk);
+                format!("0x{}", hex::encode(word))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,85 @@
+//!
+//! The fuzzer pseudo-random number generator.
+//!
+
+use revm::primitives::U256;
+
+///
+/// The fuzzer pseudo-random number generator.
+///
+/// A SplitMix64 generator: fast, seedable, and stable across platforms, so a seed reported by a
+/// fuzzing run reproduces the same inputs.
+///
+#[derive(Debug, Clone)]
+pub struct Random {
+    /// The generator state.
+    state: u64,
+}
+
+impl Random {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    ///
+    /// Returns the next 64 random bits.
+    ///
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut value = self.state;
+        value = (value ^ (value >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        value = (value ^ (value >> 27)).wrapping_mul(0x94d049bb133111eb);
+        value ^ (value >> 31)
+    }
+
+    ///
+    /// Returns a random number in the range `0..bound`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `bound` is zero.
+    ///
+    pub fn below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % (bound as u64)) as usize
+    }
+
+    ///
+    /// Returns `true` with the probability of `1 / denominator`.
+    ///
+    pub fn one_in(&mut self, denominator: usize) -> bool {
+        self.below(denominator) == 0
+    }
+
+    ///
+    /// Returns a random 256-bit word.
+    ///
+    pub fn word(&mut self) -> U256 {
+        U256::from_limbs([
+            self.next_u64(),
+            self.next_u64(),
+            self.next_u64(),
+            self.next_u64(),
+        ])
+    }
+
+    ///
+    /// Returns a random element of the slice.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `slice` is empty.
+    ///
+    pub fn choose<'a, T>(&mut self, slice: &'a [T]) -> &'a T {
+        &slice[self.below(slice.len())]
+    }
+
+    ///
+    /// Returns `length` random bytes.
+    ///
+    pub fn bytes(&mut self, length: usize) -> Vec<u8> {
+        (0..length).map(|_| self.next_u64() as u8).collect()
+    }
+}
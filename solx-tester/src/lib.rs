@@ -8,12 +8,17 @@
 #![allow(clippy::type_complexity)]
 
 pub(crate) mod compilers;
+pub(crate) mod coverage;
 pub(crate) mod directories;
 pub(crate) mod filters;
+pub(crate) mod fuzzer;
+#[cfg(feature = "slang-ast")]
+pub(crate) mod reducer;
 pub(crate) mod revm;
 pub(crate) mod summary;
 pub(crate) mod test;
 pub(crate) mod toolchain;
+pub(crate) mod trace_diff;
 pub(crate) mod utils;
 
 use std::path::PathBuf;
@@ -29,15 +34,20 @@ pub use crate::compilers::llvm_ir::LLVMIRCompiler;
 pub use crate::compilers::mode::Mode;
 pub use crate::compilers::mode::llvm_options::LLVMOptions;
 pub use crate::compilers::solidity::SolidityCompiler;
+pub use crate::coverage::Coverage;
 pub use crate::directories::Buildable;
 pub use crate::directories::Collection;
 pub use crate::directories::ethereum::EthereumDirectory;
 pub use crate::directories::ethereum::test::EthereumTest;
 pub use crate::directories::matter_labs::MatterLabsDirectory;
 pub use crate::filters::Filters;
+pub use crate::fuzzer::Fuzzer;
+#[cfg(feature = "slang-ast")]
+pub use crate::reducer::Reducer;
 pub use crate::revm::REVM;
 pub use crate::summary::Summary;
 pub use crate::toolchain::Toolchain;
+pub use crate::trace_diff::TraceDiff;
 pub use solx_dev::Workflow;
 
 /// The debug directory path.
@@ -60,6 +70,8 @@ pub struct SolxTester<'a> {
     pub debug_config: Option<solx_codegen_evm::OutputConfig>,
     /// Actions to perform.
     pub workflow: Workflow,
+    /// The code coverage to collect, if requested.
+    pub coverage: Option<Arc<Mutex<Coverage>>>,
 }
 
 impl<'a> SolxTester<'a> {
@@ -87,12 +99,14 @@ impl<'a> SolxTester<'a> {
         filters: Filters<'a>,
         debug_config: Option<solx_codegen_evm::OutputConfig>,
         workflow: Workflow,
+        coverage: Option<Arc<Mutex<Coverage>>>,
     ) -> anyhow::Result<Self> {
         Ok(Self {
             summary,
             filters,
             debug_config,
             workflow,
+            coverage,
         })
     }
 
@@ -118,7 +132,7 @@ impl<'a> SolxTester<'a> {
                     specialized_debug_config,
                 ) && let Workflow::BuildAndRun = self.workflow
                 {
-                    test.run_revm(self.summary.clone(), enable_trace)
+                    test.run_revm(self.summary.clone(), enable_trace, self.coverage.clone())
                 };
             })
             .collect();
@@ -130,10 +144,17 @@ impl<'a> SolxTester<'a> {
     /// Returns all tests from all directories.
     ///
     fn all_tests(&self, solidity_compiler_path: PathBuf) -> anyhow::Result<Vec<Test>> {
-        let solidity_compiler = Arc::new(SolidityCompiler::new(
+        let mut solidity_compiler = SolidityCompiler::new(
             solidity_compiler_path.clone(),
             solx_standard_json::InputLanguage::Solidity,
-        )?);
+        )?;
+        if self.coverage.is_some() {
+            if solidity_compiler.toolchain() != Toolchain::Solx {
+                anyhow::bail!("Code coverage is only available with `solx`");
+            }
+            solidity_compiler = solidity_compiler.with_debug_info();
+        }
+        let solidity_compiler = Arc::new(solidity_compiler);
         let toolchain = solidity_compiler.toolchain();
 
         #[cfg(not(feature = "slang-ast"))]
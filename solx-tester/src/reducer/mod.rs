@@ -0,0 +1,423 @@
+//!
+//! The delta-debugging test reducer.
+//!
+
+pub mod predicate;
+
+use std::ops::Range;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Instant;
+
+use colored::Colorize;
+
+use crate::compilers::mode::Mode;
+use crate::compilers::solidity::SolidityCompiler;
+use crate::compilers::solidity::mode::Mode as SolidityMode;
+use crate::compilers::solidity::slang_ast::SlangAst;
+use crate::toolchain::Toolchain;
+
+use self::predicate::Predicate;
+
+///
+/// The delta-debugging test reducer.
+///
+/// Minimizes a Matter Labs-format Solidity test while it keeps failing the same way, first
+/// dropping the unrelated cases and inputs from the metadata, and then removing contracts,
+/// their members, and statements, located with the Slang parser, from the source code.
+///
+pub struct Reducer {
+    /// The `solx` compiler.
+    compiler: Arc<SolidityCompiler>,
+    /// The compilation mode.
+    mode: Mode,
+    /// The failing case to preserve, chosen automatically if not set.
+    case: Option<String>,
+    /// The compilation error pattern to preserve.
+    error_pattern: Option<String>,
+    /// The reference `solc` compiler and its mode, unused when preserving a compilation error.
+    reference: Option<(Arc<SolidityCompiler>, Mode)>,
+    /// The path to write the candidates and the reduced test to.
+    output_path: PathBuf,
+    /// The number of predicate checks performed.
+    checks: usize,
+}
+
+///
+/// The reduction candidate: test metadata and source code.
+///
+#[derive(Debug, Clone)]
+struct Candidate {
+    /// The metadata JSON.
+    metadata: serde_json::Value,
+    /// The source code without the metadata comments.
+    source_code: String,
+}
+
+impl Reducer {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        solidity_compiler: PathBuf,
+        reference_compiler: PathBuf,
+        via_ir: bool,
+        optimization: char,
+        case: Option<String>,
+        error_pattern: Option<String>,
+        output_path: PathBuf,
+    ) -> anyhow::Result<Self> {
+        let compiler = SolidityCompiler::new(
+            solidity_compiler.clone(),
+            solx_standard_json::InputLanguage::Solidity,
+        )?;
+        if compiler.toolchain() != Toolchain::Solx {
+            anyhow::bail!("{solidity_compiler:?} is not a `solx` executable");
+        }
+        let mode = SolidityMode::new_solx(
+            compiler.version().to_owned(),
+            via_ir,
+            solx_codegen_evm::OptimizerSettings::try_from_cli(optimization)?,
+        )
+        .into();
+
+        let reference = match error_pattern {
+            Some(_) => None,
+            None => {
+                let reference = SolidityCompiler::new(
+                    reference_compiler.clone(),
+                    solx_standard_json::InputLanguage::Solidity,
+                )?;
+                if reference.toolchain() != Toolchain::Solc {
+                    anyhow::bail!("{reference_compiler:?} is not a `solc` executable");
+                }
+                let reference_mode =
+                    SolidityMode::new_solc(reference.version().to_owned(), via_ir, true).into();
+                Some((Arc::new(reference), reference_mode))
+            }
+        };
+
+        Ok(Self {
+            compiler: Arc::new(compiler),
+            mode,
+            case,
+            error_pattern,
+            reference,
+            output_path,
+            checks: 0,
+        })
+    }
+
+    ///
+    /// Reduces the test at `path`, and writes the result to the output path.
+    ///
+    pub fn run(&mut self, path: &Path) -> anyhow::Result<()> {
+        let test = std::fs::read_to_string(path)
+            .map_err(|error| anyhow::anyhow!("Failed to read {path:?}: {error}"))?;
+        let mut candidate = Candidate::parse(test.as_str())?;
+        candidate.rename(path, self.output_path.as_path());
+        let time_start = Instant::now();
+
+        std::fs::write(self.output_path.as_path(), candidate.to_string())?;
+        let predicate = match (self.error_pattern.as_ref(), self.reference.as_ref()) {
+            (Some(pattern), _) => Predicate::Error {
+                pattern: pattern.to_owned(),
+            },
+            (None, Some((reference, reference_mode))) => Predicate::Divergence {
+                case: match self.case.as_ref() {
+                    Some(case) => case.to_owned(),
+                    None => Predicate::failed_cases(
+                        self.output_path.as_path(),
+                        self.compiler.clone(),
+                        &self.mode,
+                    )
+                    .into_iter()
+                    .next()
+                    .ok_or_else(|| {
+                        anyhow::anyhow!("No case of {path:?} fails in mode {}", self.mode)
+                    })?,
+                },
+                reference: reference.clone(),
+                reference_mode: reference_mode.to_owned(),
+            },
+            (None, None) => {
+                unreachable!("The reference compiler is only omitted with an error pattern")
+            }
+        };
+        println!(
+            "    {} {:?} while {} in mode {}",
+            "Reducing".bright_green().bold(),
+            path,
+            predicate,
+            self.mode,
+        );
+        if !self.check(&predicate, &candidate)? {
+            anyhow::bail!("The original test does not hold the predicate: {predicate}");
+        }
+
+        if let Predicate::Divergence { case, .. } = &predicate {
+            candidate = self.reduce_metadata(&predicate, candidate, case.as_str())?;
+        }
+        candidate = self.reduce_source_code(&predicate, candidate)?;
+
+        let reduced = candidate.to_string();
+        std::fs::write(self.output_path.as_path(), reduced.as_str())?;
+        println!(
+            "    {} reducing in {}m{:02}s: {} to {} bytes in {} checks, saved to {:?}",
+            "Finished".bright_green().bold(),
+            time_start.elapsed().as_secs() / 60,
+            time_start.elapsed().as_secs() % 60,
+            test.len(),
+            reduced.len(),
+            self.checks,
+            self.output_path,
+        );
+        Ok(())
+    }
+
+    ///
+    /// Drops every case but the failing one, and minimizes its inputs.
+    ///
+    fn reduce_metadata(
+        &mut self,
+        predicate: &Predicate,
+        mut candidate: Candidate,
+        case: &str,
+    ) -> anyhow::Result<Candidate> {
+        if let Some(cases) = candidate.metadata["cases"].as_array_mut() {
+            cases.retain(|value| value["name"].as_str() == Some(case));
+        }
+        if !self.check(predicate, &candidate)? {
+            anyhow::bail!("Case `{case}` only fails together with other cases");
+        }
+
+        let Some(inputs) = candidate.metadata["cases"][0]["inputs"].as_array().cloned() else {
+            return Ok(candidate);
+        };
+        let inputs = Self::minimize(inputs, &mut |inputs| {
+            let mut next = candidate.clone();
+            next.metadata["cases"][0]["inputs"] = serde_json::Value::Array(inputs.to_vec());
+            self.check(predicate, &next)
+        })?;
+        candidate.metadata["cases"][0]["inputs"] = serde_json::Value::Array(inputs);
+        Ok(candidate)
+    }
+
+    ///
+    /// Removes definitions, members, and statements from the source code until no single pass
+    /// makes progress.
+    ///
+    fn reduce_source_code(
+        &mut self,
+        predicate: &Predicate,
+        mut candidate: Candidate,
+    ) -> anyhow::Result<Candidate> {
+        let path = crate::utils::path_to_string_normalized(self.output_path.as_path());
+        loop {
+            let length = candidate.source_code.len();
+
+            candidate = self.reduce_ranges(predicate, candidate, |ast| {
+                ast.definition_ranges(path.as_str())
+            })?;
+            candidate =
+                self.reduce_ranges(predicate, candidate, |ast| ast.member_ranges(path.as_str()))?;
+            for depth in 0.. {
+                let mut is_empty = false;
+                candidate = self.reduce_ranges(predicate, candidate, |ast| {
+                    let ranges = ast.statement_ranges(path.as_str(), depth);
+                    is_empty = ranges.is_empty();
+                    ranges
+                })?;
+                if is_empty {
+                    break;
+                }
+            }
+
+            if candidate.source_code.len() >= length {
+                return Ok(candidate);
+            }
+        }
+    }
+
+    ///
+    /// Removes a subset of the source code ranges returned by `ranges` from the candidate.
+    ///
+    fn reduce_ranges<F>(
+        &mut self,
+        predicate: &Predicate,
+        candidate: Candidate,
+        ranges: F,
+    ) -> anyhow::Result<Candidate>
+    where
+        F: FnOnce(&SlangAst) -> Vec<Range<usize>>,
+    {
+        let path = crate::utils::path_to_string_normalized(self.output_path.as_path());
+        let ast = SlangAst::parse(&[(path, candidate.source_code.clone())]);
+        let ranges: Vec<Range<usize>> = ranges(&ast)
+            .into_iter()
+            .filter(|range| {
+                candidate.source_code.is_char_boundary(range.start)
+                    && candidate.source_code.is_char_boundary(range.end)
+            })
+            .collect();
+        if ranges.is_empty() {
+            return Ok(candidate);
+        }
+
+        let without = |kept: &[Range<usize>]| {
+            let mut next = candidate.clone();
+            next.source_code = Candidate::remove(
+                candidate.source_code.as_str(),
+                ranges.iter().filter(|range| !kept.contains(range)),
+            );
+            next
+        };
+        let kept = Self::minimize(ranges.clone(), &mut |kept| {
+            self.check(predicate, &without(kept))
+        })?;
+        if kept.len() == ranges.len() {
+            return Ok(candidate);
+        }
+        Ok(without(kept.as_slice()))
+    }
+
+    ///
+    /// Writes the candidate to the output path, and checks the predicate.
+    ///
+    fn check(&mut self, predicate: &Predicate, candidate: &Candidate) -> anyhow::Result<bool> {
+        self.checks += 1;
+        std::fs::write(self.output_path.as_path(), candidate.to_string())?;
+        predicate.check(
+            self.output_path.as_path(),
+            self.compiler.clone(),
+            &self.mode,
+        )
+    }
+
+    ///
+    /// Minimizes `items` with the delta debugging algorithm, keeping `is_interesting` true.
+    ///
+    /// Removes ever smaller chunks of the items, and returns a subset from which no single chunk
+    /// of the final granularity can be removed.
+    ///
+    fn minimize<T, F>(mut items: Vec<T>, is_interesting: &mut F) -> anyhow::Result<Vec<T>>
+    where
+        T: Clone,
+        F: FnMut(&[T]) -> anyhow::Result<bool>,
+    {
+        let mut granularity = 2;
+        while items.len() >= 2 {
+            let chunk_size = items.len().div_ceil(granularity);
+            let mut is_reduced = false;
+            for start in (0..items.len()).step_by(chunk_size) {
+                let end = (start + chunk_size).min(items.len());
+                let complement = [&items[..start], &items[end..]].concat();
+                if is_interesting(complement.as_slice())? {
+                    items = complement;
+                    granularity = (granularity - 1).max(2);
+                    is_reduced = true;
+                    break;
+                }
+            }
+            if !is_reduced {
+                if granularity >= items.len() {
+                    break;
+                }
+                granularity = (granularity * 2).min(items.len());
+            }
+        }
+        if items.len() == 1 && is_interesting(&[])? {
+            items.clear();
+        }
+        Ok(items)
+    }
+}
+
+impl Candidate {
+    ///
+    /// Splits the test into the metadata and the source code.
+    ///
+    fn parse(test: &str) -> anyhow::Result<Self> {
+        let mut metadata = String::new();
+        let mut source_code = String::with_capacity(test.len());
+        for line in test.lines() {
+            match line.strip_prefix("//!") {
+                Some(metadata_line) => metadata.push_str(metadata_line),
+                None => {
+                    source_code.push_str(line);
+                    source_code.push('\n');
+                }
+            }
+        }
+        let metadata = serde_json::from_str(metadata.as_str())
+            .map_err(|error| anyhow::anyhow!("Invalid metadata JSON: {error}"))?;
+        Ok(Self {
+            metadata,
+            source_code,
+        })
+    }
+
+    ///
+    /// Points the contracts of the metadata defined in the test at `from` to the test at `to`.
+    ///
+    fn rename(&mut self, from: &Path, to: &Path) {
+        let file_name = |path: &Path| {
+            path.file_name()
+                .map(|file_name| file_name.to_string_lossy().to_string())
+                .unwrap_or_default()
+        };
+        let (from, to) = (file_name(from), file_name(to));
+
+        let Some(contracts) = self.metadata["contracts"].as_object_mut() else {
+            return;
+        };
+        for path in contracts.values_mut() {
+            let Some(contract_name) = path
+                .as_str()
+                .and_then(|path| path.strip_prefix(from.as_str()))
+                .and_then(|path| path.strip_prefix(':'))
+            else {
+                continue;
+            };
+            *path = serde_json::Value::String(format!("{to}:{contract_name}"));
+        }
+    }
+
+    ///
+    /// Removes the sorted disjoint `ranges` from the source code, along with the blank lines
+    /// they leave behind.
+    ///
+    fn remove<'a>(source_code: &str, ranges: impl Iterator<Item = &'a Range<usize>>) -> String {
+        let mut result = String::with_capacity(source_code.len());
+        let mut offset = 0;
+        for range in ranges {
+            result.push_str(&source_code[offset..range.start]);
+            offset = range.end;
+        }
+        result.push_str(&source_code[offset..]);
+
+        let mut source_code = String::with_capacity(result.len());
+        let mut is_previous_blank = false;
+        for line in result.lines() {
+            let line = line.trim_end();
+            if line.is_empty() && is_previous_blank {
+                continue;
+            }
+            is_previous_blank = line.is_empty();
+            source_code.push_str(line);
+            source_code.push('\n');
+        }
+        source_code
+    }
+}
+
+impl std::fmt::Display for Candidate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let metadata = serde_json::to_string_pretty(&self.metadata).expect("Always valid");
+        for line in metadata.lines() {
+            writeln!(f, "//! {line}")?;
+        }
+        write!(f, "{}", self.source_code)
+    }
+}
@@ -0,0 +1,152 @@
+//!
+//! The reducer interestingness predicate.
+//!
+
+use std::collections::HashSet;
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use crate::compilers::Compiler;
+use crate::compilers::mode::Mode;
+use crate::compilers::solidity::SolidityCompiler;
+use crate::directories::Buildable;
+use crate::directories::matter_labs::test::MatterLabsTest;
+use crate::filters::Filters;
+use crate::fuzzer::outcome::Outcome as TransactionOutcome;
+use crate::summary::Summary;
+use crate::test::Test;
+
+///
+/// The reducer interestingness predicate.
+///
+/// Decides whether a candidate test still fails the same way as the original one.
+///
+#[derive(Clone)]
+pub enum Predicate {
+    /// The test case still diverges: the `solx` outcomes differ from the reference `solc` ones.
+    ///
+    /// The pinned expected data is not consulted, since a candidate removing the code computing
+    /// the expected result would keep failing against it without reproducing the miscompilation.
+    Divergence {
+        /// The name of the diverging case.
+        case: String,
+        /// The reference `solc` compiler.
+        reference: Arc<SolidityCompiler>,
+        /// The reference `solc` compilation mode.
+        reference_mode: Mode,
+    },
+    /// The compilation still fails with an error containing the pattern.
+    Error {
+        /// The error message pattern.
+        pattern: String,
+    },
+}
+
+impl Predicate {
+    ///
+    /// Checks whether the test at `path` holds the predicate.
+    ///
+    pub fn check(
+        &self,
+        path: &Path,
+        compiler: Arc<SolidityCompiler>,
+        mode: &Mode,
+    ) -> anyhow::Result<bool> {
+        match self {
+            Self::Divergence {
+                case,
+                reference,
+                reference_mode,
+            } => {
+                let Some(solx_outcomes) = Self::outcomes(path, compiler, mode, case.as_str())
+                else {
+                    return Ok(false);
+                };
+                let Some(reference_outcomes) =
+                    Self::outcomes(path, reference.clone(), reference_mode, case.as_str())
+                else {
+                    return Ok(false);
+                };
+                Ok(solx_outcomes != reference_outcomes)
+            }
+            Self::Error { pattern } => {
+                let source_path = crate::utils::path_to_string_normalized(path);
+                let source_code = std::fs::read_to_string(path)?;
+                let result = compiler.compile_for_evm(
+                    source_path.clone(),
+                    vec![(source_path, source_code)],
+                    solx_utils::Libraries::default(),
+                    mode,
+                    None,
+                    vec![],
+                    None,
+                );
+                Ok(result.is_err_and(|error| format!("{error:?}").contains(pattern.as_str())))
+            }
+        }
+    }
+
+    ///
+    /// Runs the test at `path`, and returns the names of its failed cases.
+    ///
+    pub fn failed_cases(path: &Path, compiler: Arc<SolidityCompiler>, mode: &Mode) -> Vec<String> {
+        let summary = Summary::new(false, true).wrap();
+        let filters = Filters::new(HashSet::new(), false, None, vec![]);
+
+        if let Some(test) = Self::build(path, compiler, mode, summary.clone(), &filters) {
+            test.run_revm(summary.clone(), false, None);
+        }
+
+        Summary::unwrap_arc(summary).failed_cases()
+    }
+
+    ///
+    /// Runs the case of the test at `path`, and returns the outcomes of its transactions.
+    ///
+    /// Returns `None` if the test cannot be built or run, which makes the candidate uninteresting.
+    ///
+    fn outcomes(
+        path: &Path,
+        compiler: Arc<SolidityCompiler>,
+        mode: &Mode,
+        case: &str,
+    ) -> Option<Vec<TransactionOutcome>> {
+        let summary = Summary::new(false, true).wrap();
+        let filters = Filters::new(HashSet::new(), false, None, vec![]);
+
+        Self::build(path, compiler, mode, summary, &filters)?
+            .observe_revm(case)
+            .ok()
+    }
+
+    ///
+    /// Builds the test at `path`.
+    ///
+    fn build(
+        path: &Path,
+        compiler: Arc<SolidityCompiler>,
+        mode: &Mode,
+        summary: Arc<Mutex<Summary>>,
+        filters: &Filters,
+    ) -> Option<Test> {
+        MatterLabsTest::new(path.to_owned(), summary.clone(), filters).and_then(|test| {
+            test.build_for_evm(
+                mode.to_owned(),
+                compiler as Arc<dyn Compiler>,
+                summary,
+                filters,
+                None,
+            )
+        })
+    }
+}
+
+impl std::fmt::Display for Predicate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Divergence { case, .. } => write!(f, "case `{case}` diverges from `solc`"),
+            Self::Error { pattern } => write!(f, "compilation fails with `{pattern}`"),
+        }
+    }
+}
@@ -0,0 +1,125 @@
+//!
+//! The code coverage collecting inspector.
+//!
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use revm::inspector::Inspector;
+use revm::interpreter::CallInputs;
+use revm::interpreter::CallOutcome;
+use revm::interpreter::CreateInputs;
+use revm::interpreter::CreateOutcome;
+use revm::interpreter::Interpreter;
+use revm::interpreter::interpreter::EthInterpreter;
+use revm::interpreter::interpreter_types::Jumps;
+use revm::interpreter::interpreter_types::LegacyBytecode;
+use revm::primitives::Address;
+use solx_utils::CodeSegment;
+
+use crate::coverage::code_map::CodeMap;
+use crate::revm::Context;
+use crate::revm::transient_storage::TransientStorageInspector;
+
+///
+/// The program counter execution counts by contract index and code segment.
+///
+pub type Hits = HashMap<(usize, CodeSegment), HashMap<usize, u64>>;
+
+///
+/// The code coverage collecting inspector.
+///
+/// The creations are recognized by their init code, and the calls by the address the contract
+/// has been created at, as the runtime code may differ from the compiled one in immutables.
+///
+#[derive(Debug)]
+pub struct CoverageInspector {
+    /// The transient storage capturing inspector, so that the assertions work with coverage.
+    pub transient_storage: TransientStorageInspector,
+    /// The contract codes of the test.
+    code_map: Arc<CodeMap>,
+    /// The contract indexes by the address they have been created at.
+    created: HashMap<Address, usize>,
+    /// The contract code executed by the frame at each call depth.
+    frames: Vec<Option<(usize, CodeSegment)>>,
+    /// The collected program counter hits.
+    hits: Hits,
+}
+
+impl CoverageInspector {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(code_map: Arc<CodeMap>) -> Self {
+        Self {
+            transient_storage: TransientStorageInspector::default(),
+            code_map,
+            created: HashMap::new(),
+            frames: Vec::new(),
+            hits: Hits::new(),
+        }
+    }
+
+    ///
+    /// Takes the collected program counter hits, leaving the inspector empty.
+    ///
+    pub fn take(&mut self) -> Hits {
+        std::mem::take(&mut self.hits)
+    }
+}
+
+impl Inspector<Context, EthInterpreter> for CoverageInspector {
+    fn initialize_interp(
+        &mut self,
+        interp: &mut Interpreter<EthInterpreter>,
+        context: &mut Context,
+    ) {
+        let code = match interp.input.bytecode_address {
+            Some(address) => self
+                .created
+                .get(&address)
+                .map(|index| (*index, CodeSegment::Runtime)),
+            None => {
+                let index = self
+                    .code_map
+                    .find_contract(interp.bytecode.bytecode_slice());
+                if let Some(index) = index {
+                    self.created.insert(interp.input.target_address, index);
+                }
+                index.map(|index| (index, CodeSegment::Deploy))
+            }
+        };
+
+        let depth = context.journaled_state.inner.depth;
+        if self.frames.len() <= depth {
+            self.frames.resize(depth + 1, None);
+        }
+        self.frames[depth] = code;
+    }
+
+    fn step(&mut self, interp: &mut Interpreter<EthInterpreter>, context: &mut Context) {
+        let depth = context.journaled_state.inner.depth;
+        let Some(Some(code)) = self.frames.get(depth) else {
+            return;
+        };
+        *self
+            .hits
+            .entry(*code)
+            .or_default()
+            .entry(interp.bytecode.pc())
+            .or_default() += 1;
+    }
+
+    fn call_end(&mut self, context: &mut Context, inputs: &CallInputs, outcome: &mut CallOutcome) {
+        self.transient_storage.call_end(context, inputs, outcome);
+    }
+
+    fn create_end(
+        &mut self,
+        context: &mut Context,
+        inputs: &CreateInputs,
+        outcome: &mut CreateOutcome,
+    ) {
+        self.transient_storage.create_end(context, inputs, outcome);
+    }
+}
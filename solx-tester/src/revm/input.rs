@@ -7,6 +7,7 @@ use std::collections::HashMap;
 
 use revm::primitives::Address;
 
+use crate::compilers::solidity::dwarf::LineTable;
 use crate::test::instance::Instance;
 
 ///
@@ -20,6 +21,8 @@ pub struct Input {
     pub method_identifiers: Option<BTreeMap<String, BTreeMap<String, u32>>>,
     /// The last contract name.
     pub last_contract: String,
+    /// The DWARF line tables of the contract code segments, if debug information is requested.
+    pub line_tables: Vec<(String, solx_utils::CodeSegment, LineTable)>,
 }
 
 impl Input {
@@ -30,11 +33,13 @@ impl Input {
         builds: HashMap<String, (Vec<u8>, usize)>,
         method_identifiers: Option<BTreeMap<String, BTreeMap<String, u32>>>,
         last_contract: String,
+        line_tables: Vec<(String, solx_utils::CodeSegment, LineTable)>,
     ) -> Self {
         Self {
             builds,
             method_identifiers,
             last_contract,
+            line_tables,
         }
     }
 
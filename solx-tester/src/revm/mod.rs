@@ -2,13 +2,17 @@
 /// The REVM adapter.
 ///
 pub mod address_iterator;
+pub mod coverage;
 pub mod input;
 pub mod revm_type_conversions;
+pub mod tracing;
+pub mod transient_storage;
 
 use std::collections::HashMap;
 use std::convert::Infallible;
 use std::path::PathBuf;
 use std::str::FromStr;
+use std::sync::Arc;
 use std::time::Instant;
 
 use colored::Colorize;
@@ -19,14 +23,19 @@ use revm::{
     database::states::block_hash_cache::BlockHashCache,
     database::{CacheState, Database, states::plain_account::PlainStorage},
     handler::{EthFrame, EthPrecompiles, instructions::EthInstructions},
-    inspector::inspectors::TracerEip3155,
     interpreter::interpreter::EthInterpreter,
     primitives::{Address, FixedBytes, U256},
     state::AccountInfo,
 };
 
+use crate::coverage::code_map::CodeMap;
 use crate::test::case::input::calldata::Calldata;
 
+use self::coverage::CoverageInspector;
+use self::coverage::Hits;
+use self::tracing::TracingInspector;
+use self::transient_storage::TransientStorageInspector;
+
 /// The overloaded REVM Context type.
 type Context = revm::context::Context<
     revm::context::BlockEnv,
@@ -43,13 +52,31 @@ type Context = revm::context::Context<
 ///
 #[derive(Debug)]
 pub enum REVM {
-    /// REVM without tracing.
-    Default(Evm<Context, (), EthInstructions<EthInterpreter, Context>, EthPrecompiles, EthFrame>),
-    /// REVM with EIP-3155 tracer.
+    /// REVM without tracing, able to capture transient storage on demand.
+    Default(
+        Evm<
+            Context,
+            TransientStorageInspector,
+            EthInstructions<EthInterpreter, Context>,
+            EthPrecompiles,
+            EthFrame,
+        >,
+    ),
+    /// REVM collecting code coverage, able to capture transient storage on demand.
+    Coverage(
+        Evm<
+            Context,
+            CoverageInspector,
+            EthInstructions<EthInterpreter, Context>,
+            EthPrecompiles,
+            EthFrame,
+        >,
+    ),
+    /// REVM with EIP-3155 tracer, able to capture transient storage on demand.
     Tracing(
         Evm<
             Context,
-            TracerEip3155,
+            TracingInspector,
             EthInstructions<EthInterpreter, Context>,
             EthPrecompiles,
             EthFrame,
@@ -109,25 +136,56 @@ impl REVM {
     /// A shortcut constructor.
     ///
     pub fn new(enable_trace: bool) -> Self {
-        let mut evm = if enable_trace {
-            REVM::Tracing(Evm::new_with_inspector(
-                Self::context(),
-                TracerEip3155::new_stdout(),
-                EthInstructions::new_mainnet_with_spec(revm::primitives::hardfork::OSAKA),
-                EthPrecompiles::new(revm::primitives::hardfork::OSAKA),
-            ))
-        } else {
-            REVM::Default(Evm::new(
-                Self::context(),
-                EthInstructions::new_mainnet_with_spec(revm::primitives::hardfork::OSAKA),
-                EthPrecompiles::new(revm::primitives::hardfork::OSAKA),
-            ))
-        };
-        match &mut evm {
-            REVM::Default(evm) => Self::set_data(evm),
-            REVM::Tracing(evm) => Self::set_data(evm),
+        if enable_trace {
+            return Self::new_tracing(Box::new(std::io::stdout()));
+        }
+
+        let mut evm = Evm::new_with_inspector(
+            Self::context(),
+            TransientStorageInspector::default(),
+            EthInstructions::new_mainnet_with_spec(revm::primitives::hardfork::OSAKA),
+            EthPrecompiles::new(revm::primitives::hardfork::OSAKA),
+        );
+        Self::set_data(&mut evm);
+        REVM::Default(evm)
+    }
+
+    ///
+    /// A shortcut constructor with the EIP-3155 trace written to `output`.
+    ///
+    pub fn new_tracing(output: Box<dyn std::io::Write>) -> Self {
+        let mut evm = Evm::new_with_inspector(
+            Self::context(),
+            TracingInspector::new(output),
+            EthInstructions::new_mainnet_with_spec(revm::primitives::hardfork::OSAKA),
+            EthPrecompiles::new(revm::primitives::hardfork::OSAKA),
+        );
+        Self::set_data(&mut evm);
+        REVM::Tracing(evm)
+    }
+
+    ///
+    /// A shortcut constructor collecting the code coverage of the contracts in `code_map`.
+    ///
+    pub fn new_coverage(code_map: Arc<CodeMap>) -> Self {
+        let mut evm = Evm::new_with_inspector(
+            Self::context(),
+            CoverageInspector::new(code_map),
+            EthInstructions::new_mainnet_with_spec(revm::primitives::hardfork::OSAKA),
+            EthPrecompiles::new(revm::primitives::hardfork::OSAKA),
+        );
+        Self::set_data(&mut evm);
+        REVM::Coverage(evm)
+    }
+
+    ///
+    /// Takes the program counter hits collected so far, if the coverage is collected.
+    ///
+    pub fn take_coverage(&mut self) -> Option<Hits> {
+        match self {
+            REVM::Coverage(vm) => Some(vm.inspector.take()),
+            REVM::Default(_) | REVM::Tracing(_) => None,
         }
-        evm
     }
 
     ///
@@ -144,10 +202,42 @@ impl REVM {
     > {
         match self {
             REVM::Default(vm) => vm.transact_commit(tx),
+            REVM::Coverage(vm) => vm.inspect_tx_commit(tx),
             REVM::Tracing(vm) => vm.inspect_tx_commit(tx),
         }
     }
 
+    ///
+    /// Executes the transaction, and returns the transient storage at its end along with the result.
+    ///
+    pub fn execute_transaction_with_transient_storage(
+        &mut self,
+        tx: revm::context::TxEnv,
+    ) -> Result<
+        (
+            revm::context::result::ExecutionResult,
+            HashMap<(Address, U256), U256>,
+        ),
+        revm::context::result::EVMError<
+            revm::database_interface::bal::EvmDatabaseError<Infallible>,
+        >,
+    > {
+        match self {
+            REVM::Default(vm) => {
+                let result = vm.inspect_tx_commit(tx)?;
+                Ok((result, vm.inspector.take()))
+            }
+            REVM::Coverage(vm) => {
+                let result = vm.inspect_tx_commit(tx)?;
+                Ok((result, vm.inspector.transient_storage.take()))
+            }
+            REVM::Tracing(vm) => {
+                let result = vm.inspect_tx_commit(tx)?;
+                Ok((result, vm.inspector.transient_storage.take()))
+            }
+        }
+    }
+
     ///
     /// Downloads the necessary compiler executables.
     ///
@@ -289,6 +379,7 @@ impl REVM {
     ) {
         let block = match self {
             REVM::Default(evm) => &mut evm.block,
+            REVM::Coverage(evm) => &mut evm.block,
             REVM::Tracing(evm) => &mut evm.block,
         };
         block.number = number;
@@ -345,6 +436,7 @@ impl REVM {
     pub fn db(&self) -> &revm::database::State<revm::database::EmptyDB> {
         match self {
             REVM::Default(evm) => evm.db(),
+            REVM::Coverage(evm) => evm.db(),
             REVM::Tracing(evm) => evm.db(),
         }
     }
@@ -355,6 +447,7 @@ impl REVM {
     pub fn db_mut(&mut self) -> &mut revm::database::State<revm::database::EmptyDB> {
         match self {
             REVM::Default(evm) => evm.db_mut(),
+            REVM::Coverage(evm) => evm.db_mut(),
             REVM::Tracing(evm) => evm.db_mut(),
         }
     }
@@ -365,6 +458,7 @@ impl REVM {
     pub fn ctx_db(&self) -> &revm::database::State<revm::database::EmptyDB> {
         match self {
             REVM::Default(evm) => evm.ctx.db(),
+            REVM::Coverage(evm) => evm.ctx.db(),
             REVM::Tracing(evm) => evm.ctx.db(),
         }
     }
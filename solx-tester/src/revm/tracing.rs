@@ -0,0 +1,74 @@
+//!
+//! The EIP-3155 tracing inspector.
+//!
+
+use revm::inspector::Inspector;
+use revm::inspector::inspectors::TracerEip3155;
+use revm::interpreter::CallInputs;
+use revm::interpreter::CallOutcome;
+use revm::interpreter::CreateInputs;
+use revm::interpreter::CreateOutcome;
+use revm::interpreter::Interpreter;
+use revm::interpreter::interpreter::EthInterpreter;
+
+use crate::revm::Context;
+use crate::revm::transient_storage::TransientStorageInspector;
+
+///
+/// The EIP-3155 tracing inspector.
+///
+/// Wraps the tracer to capture the transient storage as well, so that the assertions work with
+/// tracing.
+///
+#[derive(Debug)]
+pub struct TracingInspector {
+    /// The transient storage capturing inspector.
+    pub transient_storage: TransientStorageInspector,
+    /// The EIP-3155 tracer.
+    tracer: TracerEip3155,
+}
+
+impl TracingInspector {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(output: Box<dyn std::io::Write>) -> Self {
+        Self {
+            transient_storage: TransientStorageInspector::default(),
+            tracer: TracerEip3155::new(output),
+        }
+    }
+}
+
+impl Inspector<Context, EthInterpreter> for TracingInspector {
+    fn initialize_interp(
+        &mut self,
+        interp: &mut Interpreter<EthInterpreter>,
+        context: &mut Context,
+    ) {
+        self.tracer.initialize_interp(interp, context);
+    }
+
+    fn step(&mut self, interp: &mut Interpreter<EthInterpreter>, context: &mut Context) {
+        self.tracer.step(interp, context);
+    }
+
+    fn step_end(&mut self, interp: &mut Interpreter<EthInterpreter>, context: &mut Context) {
+        self.tracer.step_end(interp, context);
+    }
+
+    fn call_end(&mut self, context: &mut Context, inputs: &CallInputs, outcome: &mut CallOutcome) {
+        self.transient_storage.call_end(context, inputs, outcome);
+        self.tracer.call_end(context, inputs, outcome);
+    }
+
+    fn create_end(
+        &mut self,
+        context: &mut Context,
+        inputs: &CreateInputs,
+        outcome: &mut CreateOutcome,
+    ) {
+        self.transient_storage.create_end(context, inputs, outcome);
+        self.tracer.create_end(context, inputs, outcome);
+    }
+}
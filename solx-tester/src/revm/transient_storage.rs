@@ -0,0 +1,70 @@
+//!
+//! The transient storage capturing inspector.
+//!
+
+use std::collections::HashMap;
+
+use revm::inspector::Inspector;
+use revm::interpreter::CallInputs;
+use revm::interpreter::CallOutcome;
+use revm::interpreter::CreateInputs;
+use revm::interpreter::CreateOutcome;
+use revm::interpreter::interpreter::EthInterpreter;
+use revm::primitives::Address;
+use revm::primitives::U256;
+
+use crate::revm::Context;
+
+///
+/// The transient storage capturing inspector.
+///
+/// Transient storage is cleared when the transaction is committed, so it is copied at the end of
+/// every call frame, leaving the state at the end of the outermost one.
+///
+#[derive(Debug, Default, Clone)]
+pub struct TransientStorageInspector {
+    /// The transient storage at the end of the last finished frame.
+    snapshot: HashMap<(Address, U256), U256>,
+}
+
+impl TransientStorageInspector {
+    ///
+    /// Takes the captured transient storage, leaving the inspector empty.
+    ///
+    pub fn take(&mut self) -> HashMap<(Address, U256), U256> {
+        std::mem::take(&mut self.snapshot)
+    }
+
+    ///
+    /// Copies the transient storage from the journal.
+    ///
+    fn capture(&mut self, context: &Context) {
+        self.snapshot = context
+            .journaled_state
+            .inner
+            .transient_storage
+            .iter()
+            .map(|(key, value)| (*key, *value))
+            .collect();
+    }
+}
+
+impl Inspector<Context, EthInterpreter> for TransientStorageInspector {
+    fn call_end(
+        &mut self,
+        context: &mut Context,
+        _inputs: &CallInputs,
+        _outcome: &mut CallOutcome,
+    ) {
+        self.capture(context);
+    }
+
+    fn create_end(
+        &mut self,
+        context: &mut Context,
+        _inputs: &CreateInputs,
+        _outcome: &mut CreateOutcome,
+    ) {
+        self.capture(context);
+    }
+}
@@ -55,6 +55,81 @@ fn main_inner(arguments: Arguments) -> anyhow::Result<()> {
         None
     };
 
+    match arguments.command {
+        Some(solx_dev::SolxTesterCommand::Fuzz(fuzz)) => {
+            let seed = fuzz.seed.unwrap_or_else(|| {
+                std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .map(|duration| duration.as_nanos() as u64)
+                    .unwrap_or_default()
+            });
+            let fuzzer = solx_tester::Fuzzer::new(
+                arguments
+                    .solidity_compiler
+                    .unwrap_or_else(|| PathBuf::from("solx")),
+                fuzz.reference_compiler,
+                fuzz.via_ir,
+                fuzz.optimization,
+                fuzz.iterations,
+                fuzz.calls,
+                seed,
+                fuzz.output_dir,
+            )?;
+
+            let run_time_start = Instant::now();
+            let divergences = fuzzer.run(fuzz.contract.as_path())?;
+            println!(
+                "    {} fuzzing in {}m{:02}s with {} divergences",
+                "Finished".bright_green().bold(),
+                run_time_start.elapsed().as_secs() / 60,
+                run_time_start.elapsed().as_secs() % 60,
+                divergences,
+            );
+            if divergences > 0 {
+                anyhow::bail!(
+                    "Found {divergences} divergences from the reference compiler with seed {seed}"
+                );
+            }
+            return Ok(());
+        }
+        #[cfg(feature = "slang-ast")]
+        Some(solx_dev::SolxTesterCommand::Reduce(reduce)) => {
+            let output = reduce
+                .output
+                .unwrap_or_else(|| reduce.test.with_extension("reduced.sol"));
+            let mut reducer = solx_tester::Reducer::new(
+                arguments
+                    .solidity_compiler
+                    .unwrap_or_else(|| PathBuf::from("solx")),
+                reduce.reference_compiler,
+                reduce.via_ir,
+                reduce.optimization,
+                reduce.case,
+                reduce.error_pattern,
+                output,
+            )?;
+            return reducer.run(reduce.test.as_path());
+        }
+        #[cfg(not(feature = "slang-ast"))]
+        Some(solx_dev::SolxTesterCommand::Reduce(_)) => {
+            anyhow::bail!("The reducer requires solx-tester built with the `slang-ast` feature");
+        }
+        Some(solx_dev::SolxTesterCommand::TraceDiff(trace_diff)) => {
+            let differ = solx_tester::TraceDiff::new(
+                arguments
+                    .solidity_compiler
+                    .unwrap_or_else(|| PathBuf::from("solx")),
+                trace_diff.reference_compiler,
+                trace_diff.via_ir,
+                trace_diff.optimization,
+                trace_diff.context,
+            )?;
+            differ.run(trace_diff.test.as_path(), trace_diff.case)?;
+            return Ok(());
+        }
+        None => {}
+    }
+
     let mut thread_pool_builder = rayon::ThreadPoolBuilder::new();
     if let Some(threads) = arguments.threads {
         thread_pool_builder = thread_pool_builder.num_threads(threads);
@@ -84,8 +159,18 @@ fn main_inner(arguments: Arguments) -> anyhow::Result<()> {
 
     let workflow = arguments.workflow.unwrap_or(Workflow::BuildAndRun);
 
-    let compiler_tester =
-        solx_tester::SolxTester::new(summary.clone(), filters, debug_config.clone(), workflow)?;
+    let coverage = arguments
+        .coverage
+        .as_ref()
+        .map(|_| solx_tester::Coverage::default().wrap());
+
+    let compiler_tester = solx_tester::SolxTester::new(
+        summary.clone(),
+        filters,
+        debug_config.clone(),
+        workflow,
+        coverage.clone(),
+    )?;
 
     let run_time_start = Instant::now();
     println!(
@@ -116,6 +201,17 @@ fn main_inner(arguments: Arguments) -> anyhow::Result<()> {
         output.write_to_file(path)?;
     }
 
+    if let (Some(path), Some(coverage)) = (arguments.coverage.as_ref(), coverage) {
+        solx_tester::Coverage::unwrap_arc(coverage).write_lcov(path)?;
+    }
+
+    for report in arguments.report.iter() {
+        match report {
+            solx_dev::SolxTesterReport::JUnit(path) => summary.write_junit(path)?,
+            solx_dev::SolxTesterReport::Sarif(path) => summary.write_sarif(path)?,
+        }
+    }
+
     if !summary.is_successful() {
         anyhow::bail!("");
     }
@@ -4,6 +4,8 @@
 
 pub mod outcome;
 
+use std::time::Duration;
+
 use colored::Colorize;
 
 use crate::test::description::TestDescription;
@@ -20,6 +22,8 @@ pub struct Element {
     pub test_description: TestDescription,
     /// The test outcome.
     pub outcome: Outcome,
+    /// The input execution time, if the element has been produced by an executed input.
+    pub duration: Option<Duration>,
 }
 
 impl Element {
@@ -30,6 +34,7 @@ impl Element {
         Self {
             test_description: name,
             outcome,
+            duration: None,
         }
     }
 
@@ -46,6 +51,7 @@ impl Element {
         let outcome = match self.outcome {
             Outcome::Passed { .. } => "PASSED".green(),
             Outcome::Failed { .. } => "FAILED".bright_red(),
+            Outcome::OverBudget { .. } => "FAILED".bright_red(),
             Outcome::Invalid { .. } => "INVALID".red(),
             Outcome::Ignored => "IGNORED".bright_black(),
         };
@@ -103,7 +109,8 @@ impl Element {
                     calldata,
                 )
             }
-            Outcome::Invalid { ref error } => error.to_string(),
+            Outcome::OverBudget { ref violations } => format!("({})", violations.join(", ")),
+            Outcome::Invalid { ref error, .. } => error.to_string(),
             _ => String::new(),
         };
 
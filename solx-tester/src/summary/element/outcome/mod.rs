@@ -4,6 +4,8 @@
 
 pub mod passed_variant;
 
+use crate::compilers::diagnostics::Diagnostic;
+use crate::compilers::diagnostics::Diagnostics;
 use crate::test::case::input::output::Output;
 
 use self::passed_variant::PassedVariant;
@@ -29,10 +31,18 @@ pub enum Outcome {
         /// The calldata.
         calldata: String,
     },
+    /// The `over budget` outcome. The output result is correct, but the gas usage or code size is
+    /// out of the budget pinned in the test.
+    OverBudget {
+        /// The budget violation descriptions.
+        violations: Vec<String>,
+    },
     /// The `invalid` outcome. The test is incorrect.
     Invalid {
         /// The building error description.
         error: String,
+        /// The compiler errors with source locations, if the compilation has failed.
+        diagnostics: Vec<Diagnostic>,
     },
     /// The `ignored` outcome. The test is ignored.
     Ignored,
@@ -57,6 +67,13 @@ impl Outcome {
         }
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn over_budget(violations: Vec<String>) -> Self {
+        Self::OverBudget { violations }
+    }
+
     ///
     /// A shortcut constructor.
     ///
@@ -66,6 +83,21 @@ impl Outcome {
     {
         Self::Invalid {
             error: error.to_string(),
+            diagnostics: vec![],
+        }
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn invalid_compilation(error: anyhow::Error) -> Self {
+        let diagnostics = error
+            .downcast_ref::<Diagnostics>()
+            .map(|diagnostics| diagnostics.0.to_owned())
+            .unwrap_or_default();
+        Self::Invalid {
+            error: format!("Failed to compile sources:\n{error}"),
+            diagnostics,
         }
     }
 
@@ -4,9 +4,14 @@
 
 pub mod benchmark_adapters;
 pub mod element;
+pub mod report;
 
+use std::collections::HashMap;
+use std::path::Path;
 use std::sync::Arc;
 use std::sync::Mutex;
+use std::thread::ThreadId;
+use std::time::Instant;
 
 use benchmark_adapters::mode::ModeInfo;
 use colored::Colorize;
@@ -38,6 +43,8 @@ pub struct Summary {
     invalid: usize,
     /// The ignored tests counter.
     ignored: usize,
+    /// The start times of the inputs being executed by each thread.
+    input_start_times: HashMap<ThreadId, Instant>,
 }
 
 impl Summary {
@@ -56,6 +63,7 @@ impl Summary {
             failed: 0,
             invalid: 0,
             ignored: 0,
+            input_start_times: HashMap::new(),
         }
     }
 
@@ -67,6 +75,7 @@ impl Summary {
             match element.outcome {
                 Outcome::Passed { .. } => continue,
                 Outcome::Failed { .. } => return false,
+                Outcome::OverBudget { .. } => return false,
                 Outcome::Invalid { .. } => return false,
                 Outcome::Ignored => continue,
             }
@@ -75,6 +84,28 @@ impl Summary {
         true
     }
 
+    ///
+    /// Returns the names of the test cases with a failed outcome, in the order of execution.
+    ///
+    pub fn failed_cases(&self) -> Vec<String> {
+        let mut cases: Vec<String> = Vec::new();
+        for element in self.elements.iter() {
+            if !matches!(
+                element.outcome,
+                Outcome::Failed { .. } | Outcome::OverBudget { .. }
+            ) {
+                continue;
+            }
+            let Some(case) = element.test_description.selector.case.as_ref() else {
+                continue;
+            };
+            if !cases.contains(case) {
+                cases.push(case.to_owned());
+            }
+        }
+        cases
+    }
+
     ///
     /// Returns the benchmark structure.
     ///
@@ -89,6 +120,7 @@ impl Summary {
                     selector,
                 },
             outcome,
+            ..
         } in self.elements.iter()
         {
             let (deploy_size, runtime_size, gas) = match outcome {
@@ -147,6 +179,26 @@ impl Summary {
         Ok(benchmark)
     }
 
+    ///
+    /// Writes the JUnit XML report to `path`.
+    ///
+    pub fn write_junit(&self, path: &Path) -> anyhow::Result<()> {
+        std::fs::write(path, self::report::junit::render(self.elements.as_slice()))
+            .map_err(|error| anyhow::anyhow!("JUnit report {path:?} writing: {error}"))
+    }
+
+    ///
+    /// Writes the SARIF report of the compiler errors to `path`.
+    ///
+    pub fn write_sarif(&self, path: &Path) -> anyhow::Result<()> {
+        let sarif = self::report::sarif::render(self.elements.as_slice());
+        std::fs::write(
+            path,
+            serde_json::to_vec_pretty(&sarif).expect("Always valid"),
+        )
+        .map_err(|error| anyhow::anyhow!("SARIF report {path:?} writing: {error}"))
+    }
+
     ///
     /// Wraps data into a thread-safe shared reference.
     ///
@@ -164,6 +216,30 @@ impl Summary {
             .expect("Last shared reference")
     }
 
+    ///
+    /// Marks the start of an input execution by the current thread.
+    ///
+    /// The next element pushed by the thread is assigned the time elapsed since then.
+    ///
+    pub fn start_input(summary: Arc<Mutex<Self>>) {
+        summary
+            .lock_sync()
+            .input_start_times
+            .insert(std::thread::current().id(), Instant::now());
+    }
+
+    ///
+    /// Discards the start time of the current thread's input execution, if it is not consumed.
+    ///
+    /// Inputs pushing no element must not leak their start time to the next element of the thread.
+    ///
+    pub fn finish_input(summary: Arc<Mutex<Self>>) {
+        summary
+            .lock_sync()
+            .input_start_times
+            .remove(&std::thread::current().id());
+    }
+
     ///
     /// Adds a passed outcome of a deploy call.
     ///
@@ -212,6 +288,14 @@ impl Summary {
         summary.lock_sync().push_element(element);
     }
 
+    ///
+    /// Adds an outcome of a correct call out of its gas or code size budget.
+    ///
+    pub fn over_budget(summary: Arc<Mutex<Self>>, test: TestDescription, violations: Vec<String>) {
+        let element = Element::new(test, Outcome::over_budget(violations));
+        summary.lock_sync().push_element(element);
+    }
+
     ///
     /// Adds an invalid outcome.
     ///
@@ -223,6 +307,18 @@ impl Summary {
         summary.lock_sync().push_element(element);
     }
 
+    ///
+    /// Adds an invalid outcome of a failed compilation, keeping the compiler error locations.
+    ///
+    pub fn compilation_failed(
+        summary: Arc<Mutex<Self>>,
+        test: TestDescription,
+        error: anyhow::Error,
+    ) {
+        let element = Element::new(test, Outcome::invalid_compilation(error));
+        summary.lock_sync().push_element(element);
+    }
+
     ///
     /// Adds an ignored outcome.
     ///
@@ -243,7 +339,12 @@ impl Summary {
     ///
     /// Pushes an element to the summary, printing it.
     ///
-    fn push_element(&mut self, element: Element) {
+    fn push_element(&mut self, mut element: Element) {
+        element.duration = self
+            .input_start_times
+            .remove(&std::thread::current().id())
+            .map(|start_time| start_time.elapsed());
+
         if let Some(string) = element.print(self.verbosity) {
             println!("{string}");
         }
@@ -253,7 +354,7 @@ impl Summary {
                 self.passed += 1;
                 true
             }
-            Outcome::Failed { .. } => {
+            Outcome::Failed { .. } | Outcome::OverBudget { .. } => {
                 self.failed += 1;
                 true
             }
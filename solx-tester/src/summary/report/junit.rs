@@ -0,0 +1,202 @@
+//!
+//! `solx` tester summary JUnit XML report.
+//!
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::time::Duration;
+
+use crate::summary::element::Element;
+use crate::summary::element::outcome::Outcome;
+
+///
+/// Renders the summary elements as a JUnit XML report.
+///
+/// The elements are grouped into a test suite per toolchain and mode, with the ignored tests,
+/// which have no mode, in a separate suite.
+///
+pub fn render(elements: &[Element]) -> String {
+    let mut suites: BTreeMap<String, Vec<&Element>> = BTreeMap::new();
+    for element in elements.iter() {
+        let suite = match element.test_description.mode.as_ref() {
+            Some(mode) => format!("{} {mode}", mode.toolchain()),
+            None => "ignored".to_owned(),
+        };
+        suites.entry(suite).or_default().push(element);
+    }
+
+    let mut xml = String::new();
+    writeln!(xml, r#"<?xml version="1.0" encoding="UTF-8"?>"#).expect("Always valid");
+    writeln!(
+        xml,
+        r#"<testsuites name="solx-tester" tests="{}" failures="{}" errors="{}" skipped="{}" time="{}">"#,
+        elements.len(),
+        count(elements.iter(), |outcome| matches!(
+            outcome,
+            Outcome::Failed { .. } | Outcome::OverBudget { .. }
+        )),
+        count(elements.iter(), |outcome| matches!(
+            outcome,
+            Outcome::Invalid { .. }
+        )),
+        count(elements.iter(), |outcome| matches!(outcome, Outcome::Ignored)),
+        seconds(elements.iter().filter_map(|element| element.duration).sum()),
+    )
+    .expect("Always valid");
+    for (name, elements) in suites.into_iter() {
+        writeln!(
+            xml,
+            r#"  <testsuite name="{}" tests="{}" failures="{}" errors="{}" skipped="{}" time="{}">"#,
+            escape(name.as_str()),
+            elements.len(),
+            count(elements.iter().copied(), |outcome| matches!(
+                outcome,
+                Outcome::Failed { .. } | Outcome::OverBudget { .. }
+            )),
+            count(elements.iter().copied(), |outcome| matches!(
+                outcome,
+                Outcome::Invalid { .. }
+            )),
+            count(elements.iter().copied(), |outcome| matches!(
+                outcome,
+                Outcome::Ignored
+            )),
+            seconds(elements.iter().filter_map(|element| element.duration).sum()),
+        )
+        .expect("Always valid");
+        for element in elements.into_iter() {
+            write_test_case(&mut xml, element);
+        }
+        writeln!(xml, "  </testsuite>").expect("Always valid");
+    }
+    writeln!(xml, "</testsuites>").expect("Always valid");
+    xml
+}
+
+///
+/// Writes the element as a test case.
+///
+fn write_test_case(xml: &mut String, element: &Element) {
+    let description = &element.test_description;
+    let time = seconds(element.duration.unwrap_or_default());
+    writeln!(
+        xml,
+        r#"    <testcase classname="{}" name="{}" time="{time}">"#,
+        escape(description.selector.path.as_str()),
+        escape(description.selector.to_string().as_str()),
+    )
+    .expect("Always valid");
+
+    if let Some(mode) = description.mode.as_ref() {
+        writeln!(xml, "      <properties>").expect("Always valid");
+        writeln!(
+            xml,
+            r#"        <property name="toolchain" value="{}"/>"#,
+            mode.toolchain()
+        )
+        .expect("Always valid");
+        writeln!(
+            xml,
+            r#"        <property name="mode" value="{}"/>"#,
+            escape(mode.to_string().as_str())
+        )
+        .expect("Always valid");
+        if let Some(group) = description.group.as_ref() {
+            writeln!(
+                xml,
+                r#"        <property name="group" value="{}"/>"#,
+                escape(group.as_str())
+            )
+            .expect("Always valid");
+        }
+        writeln!(xml, "      </properties>").expect("Always valid");
+    }
+
+    match element.outcome {
+        Outcome::Passed { .. } => {}
+        Outcome::Failed {
+            ref expected,
+            ref found,
+            ref calldata,
+        } => {
+            let pretty = |output| {
+                ron::ser::to_string_pretty(output, ron::ser::PrettyConfig::default())
+                    .expect("Always valid")
+            };
+            let details = format!(
+                "expected:\n{}\nfound:\n{}\ncalldata: {calldata}",
+                pretty(expected),
+                pretty(found),
+            );
+            writeln!(
+                xml,
+                r#"      <failure message="The output does not match the expected one" type="mismatch">{}</failure>"#,
+                escape(details.as_str())
+            )
+            .expect("Always valid");
+        }
+        Outcome::OverBudget { ref violations } => {
+            writeln!(
+                xml,
+                r#"      <failure message="{}" type="budget">{}</failure>"#,
+                escape(violations.join(", ").as_str()),
+                escape(violations.join("\n").as_str()),
+            )
+            .expect("Always valid");
+        }
+        Outcome::Invalid { ref error, .. } => {
+            writeln!(
+                xml,
+                r#"      <error message="{}" type="invalid">{}</error>"#,
+                escape(error.lines().next().unwrap_or_default()),
+                escape(error.as_str()),
+            )
+            .expect("Always valid");
+        }
+        Outcome::Ignored => {
+            writeln!(xml, "      <skipped/>").expect("Always valid");
+        }
+    }
+    writeln!(xml, "    </testcase>").expect("Always valid");
+}
+
+///
+/// Counts the elements with the outcomes matching `predicate`.
+///
+fn count<'a, I, P>(elements: I, predicate: P) -> usize
+where
+    I: Iterator<Item = &'a Element>,
+    P: Fn(&Outcome) -> bool,
+{
+    elements
+        .filter(|element| predicate(&element.outcome))
+        .count()
+}
+
+///
+/// Formats the duration in seconds, as JUnit expects.
+///
+fn seconds(duration: Duration) -> String {
+    format!("{:.3}", duration.as_secs_f64())
+}
+
+///
+/// Escapes the text for XML, dropping the control characters XML does not allow, such as the
+/// terminal color codes.
+///
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for character in text.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\t' | '\n' | '\r' => escaped.push(character),
+            character if character.is_control() => {}
+            character => escaped.push(character),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,6 @@
+//!
+//! `solx` tester summary reports for CI systems.
+//!
+
+pub mod junit;
+pub mod sarif;
@@ -0,0 +1,124 @@
+//!
+//! `solx` tester summary SARIF report.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use crate::summary::element::Element;
+use crate::summary::element::outcome::Outcome;
+
+/// The SARIF schema URI.
+const SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// The SARIF version.
+const VERSION: &str = "2.1.0";
+
+/// The result deduplication key: the rule, path, line, column, and message.
+type ResultKey = (String, String, usize, usize, String);
+
+///
+/// Renders the compiler errors of the summary elements as a SARIF log.
+///
+/// The same error is usually reported in several modes, so the results are deduplicated, and the
+/// modes they are reported in are listed in the result properties.
+///
+/// The regions are given by line and column only, as the compiler source locations are byte
+/// offsets, whereas SARIF character offsets count characters.
+///
+pub fn render(elements: &[Element]) -> serde_json::Value {
+    let mut results: BTreeMap<ResultKey, (serde_json::Value, BTreeSet<String>)> = BTreeMap::new();
+    for element in elements.iter() {
+        let Outcome::Invalid {
+            ref diagnostics, ..
+        } = element.outcome
+        else {
+            continue;
+        };
+        let mode = element
+            .test_description
+            .mode
+            .as_ref()
+            .map(|mode| mode.to_string());
+
+        for diagnostic in diagnostics.iter() {
+            let error = &diagnostic.error;
+            let rule_id = error
+                .error_code
+                .clone()
+                .unwrap_or_else(|| error.r#type.clone());
+            let level = match error.severity.as_str() {
+                "error" => "error",
+                "warning" => "warning",
+                _ => "note",
+            };
+
+            let mut locations = Vec::with_capacity(1);
+            let mut key = (rule_id.clone(), String::new(), 0, 0, error.message.clone());
+            if let Some(location) = diagnostic.location.as_ref() {
+                let mut region = serde_json::Map::new();
+                if let (Some(line), Some(column)) = (location.line, location.column) {
+                    region.insert("startLine".to_owned(), line.into());
+                    region.insert("startColumn".to_owned(), column.into());
+                    key.2 = line;
+                    key.3 = column;
+                }
+                key.1 = location.path.clone();
+
+                let mut physical_location = serde_json::Map::new();
+                physical_location.insert(
+                    "artifactLocation".to_owned(),
+                    serde_json::json!({ "uri": location.path }),
+                );
+                if !region.is_empty() {
+                    physical_location.insert("region".to_owned(), region.into());
+                }
+                locations.push(serde_json::json!({ "physicalLocation": physical_location }));
+            }
+
+            let (_, modes) = results.entry(key).or_insert_with(|| {
+                (
+                    serde_json::json!({
+                        "ruleId": rule_id,
+                        "level": level,
+                        "message": { "text": error.message },
+                        "locations": locations,
+                    }),
+                    BTreeSet::new(),
+                )
+            });
+            modes.extend(mode.clone());
+        }
+    }
+
+    let rules: BTreeSet<&str> = results.keys().map(|key| key.0.as_str()).collect();
+    let rules: Vec<serde_json::Value> = rules
+        .into_iter()
+        .map(|rule_id| serde_json::json!({ "id": rule_id }))
+        .collect();
+    let results: Vec<serde_json::Value> = results
+        .into_values()
+        .map(|(mut result, modes)| {
+            result["properties"] = serde_json::json!({ "modes": modes });
+            result
+        })
+        .collect();
+
+    serde_json::json!({
+        "$schema": SCHEMA,
+        "version": VERSION,
+        "runs": [
+            {
+                "tool": {
+                    "driver": {
+                        "name": "solx-tester",
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "informationUri": "https://github.com/NomicFoundation/solx",
+                        "rules": rules,
+                    }
+                },
+                "results": results,
+            }
+        ],
+    })
+}
@@ -0,0 +1,142 @@
+//!
+//! The gas and code size budget of a test input.
+//!
+
+use crate::compilers::mode::Mode;
+use crate::directories::matter_labs::test::metadata::budget::Budget as MatterLabsTestBudget;
+use crate::directories::matter_labs::test::metadata::budget::contract::Contract as MatterLabsTestContractBudget;
+
+///
+/// The gas and code size budget of a test input.
+///
+#[derive(Debug, Clone, Default)]
+pub struct Budget {
+    /// The expected gas usage.
+    expected_gas: Option<Limit>,
+    /// The maximum gas usage.
+    max_gas: Option<Limit>,
+    /// The maximum deploy code size.
+    max_size: Option<Limit>,
+    /// The maximum runtime code size.
+    max_runtime_size: Option<Limit>,
+}
+
+///
+/// The budget value with its tolerance.
+///
+#[derive(Debug, Clone, Copy)]
+struct Limit {
+    /// The budget value.
+    value: u64,
+    /// The allowed deviation from the value in percent.
+    tolerance: f64,
+}
+
+impl Budget {
+    ///
+    /// Try convert from Matter Labs compiler test metadata budgets.
+    ///
+    /// The budgets without a variant matching `mode` are not checked.
+    ///
+    pub fn try_from_matter_labs(
+        expected_gas: Option<MatterLabsTestBudget>,
+        max_gas: Option<MatterLabsTestBudget>,
+        contract: Option<&MatterLabsTestContractBudget>,
+        mode: &Mode,
+    ) -> anyhow::Result<Self> {
+        let limit = |budget: Option<MatterLabsTestBudget>, name: &str| {
+            budget
+                .and_then(|budget| budget.select(mode))
+                .map(|(value, tolerance)| {
+                    if !tolerance.is_finite() || tolerance < 0.0 {
+                        anyhow::bail!("Invalid `{name}` tolerance {tolerance}");
+                    }
+                    Ok(Limit { value, tolerance })
+                })
+                .transpose()
+        };
+
+        Ok(Self {
+            expected_gas: limit(expected_gas, "expected_gas")?,
+            max_gas: limit(max_gas, "max_gas")?,
+            max_size: limit(
+                contract.and_then(|contract| contract.max_size.clone()),
+                "max_size",
+            )?,
+            max_runtime_size: limit(
+                contract.and_then(|contract| contract.max_runtime_size.clone()),
+                "max_runtime_size",
+            )?,
+        })
+    }
+
+    ///
+    /// Checks the measurements against the budget, and returns the descriptions of violations.
+    ///
+    /// The code sizes are only measured for deploy calls.
+    ///
+    pub fn check(&self, gas: u64, sizes: Option<(u64, u64)>) -> Vec<String> {
+        let mut violations = Vec::new();
+        if let Some(limit) = self.expected_gas
+            && !limit.matches(gas)
+        {
+            violations.push(format!(
+                "gas {gas} differs from the expected {} by more than {}%",
+                limit.value, limit.tolerance
+            ));
+        }
+        if let Some(limit) = self.max_gas
+            && limit.is_exceeded(gas)
+        {
+            violations.push(format!(
+                "gas {gas} exceeds the maximum {} with tolerance {}%",
+                limit.value, limit.tolerance
+            ));
+        }
+        if let Some((size, runtime_size)) = sizes {
+            if let Some(limit) = self.max_size
+                && limit.is_exceeded(size)
+            {
+                violations.push(format!(
+                    "deploy size {size} exceeds the maximum {} with tolerance {}%",
+                    limit.value, limit.tolerance
+                ));
+            }
+            if let Some(limit) = self.max_runtime_size
+                && limit.is_exceeded(runtime_size)
+            {
+                violations.push(format!(
+                    "runtime size {runtime_size} exceeds the maximum {} with tolerance {}%",
+                    limit.value, limit.tolerance
+                ));
+            }
+        }
+        violations
+    }
+}
+
+impl Limit {
+    ///
+    /// Whether the measurement is within the tolerance in both directions.
+    ///
+    /// Improvements beyond the tolerance are violations too, so that they are pinned by updating
+    /// the expected value.
+    ///
+    fn matches(&self, found: u64) -> bool {
+        (found as f64 - self.value as f64).abs() <= self.deviation()
+    }
+
+    ///
+    /// Whether the measurement exceeds the value by more than the tolerance.
+    ///
+    fn is_exceeded(&self, found: u64) -> bool {
+        found as f64 > self.value as f64 + self.deviation()
+    }
+
+    ///
+    /// Returns the allowed absolute deviation.
+    ///
+    fn deviation(&self) -> f64 {
+        self.value as f64 * self.tolerance / 100.0
+    }
+}
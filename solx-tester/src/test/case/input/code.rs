@@ -0,0 +1,113 @@
+//!
+//! The test input expected account code.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use revm::DatabaseRef;
+use revm::primitives::Address;
+use revm::primitives::U256;
+
+use crate::directories::matter_labs::test::metadata::case::input::code::Code as MatterLabsTestCode;
+use crate::revm::REVM;
+use crate::revm::revm_type_conversions::revm_bytes_to_vec_value;
+use crate::test::case::input::output::Output;
+use crate::test::case::input::value::Value;
+use crate::test::instance::Instance;
+
+///
+/// The test input expected account code.
+///
+#[derive(Debug, Clone)]
+pub enum Code {
+    /// The code hash, as returned by `EXTCODEHASH`.
+    Hash(U256),
+    /// The exact runtime bytecode.
+    Bytecode(Vec<u8>),
+}
+
+impl Code {
+    ///
+    /// Try convert from Matter Labs compiler test expected code data.
+    ///
+    pub fn try_from_matter_labs(
+        code: HashMap<String, MatterLabsTestCode>,
+        instances: &BTreeMap<String, Instance>,
+    ) -> anyhow::Result<HashMap<Address, Self>> {
+        let mut result = HashMap::with_capacity(code.len());
+        for (address, code) in code.into_iter() {
+            let address = match Value::try_from_matter_labs(address.as_str(), instances)
+                .map_err(|error| anyhow::anyhow!("Invalid code address: {error}"))?
+            {
+                Value::Known(value) => crate::utils::u256_to_address(&value),
+                Value::Any => anyhow::bail!("Code address can not be `*`"),
+            };
+
+            let code = match code {
+                MatterLabsTestCode::Hash(hash) => {
+                    match Value::try_from_matter_labs(hash.as_str(), instances)
+                        .map_err(|error| anyhow::anyhow!("Invalid code hash: {error}"))?
+                    {
+                        Value::Known(value) => Self::Hash(value),
+                        Value::Any => anyhow::bail!("Code hash can not be `*`"),
+                    }
+                }
+                MatterLabsTestCode::Bytecode(bytecode) => Self::Bytecode(
+                    hex::decode(bytecode.strip_prefix("0x").unwrap_or(bytecode.as_str()))
+                        .map_err(|error| anyhow::anyhow!("Invalid bytecode: {error}"))?,
+                ),
+            };
+            result.insert(address, code);
+        }
+        Ok(result)
+    }
+
+    ///
+    /// Checks the code of the account at `address`.
+    ///
+    /// Returns the expected and found outputs if the code does not match.
+    ///
+    pub fn check(&self, vm: &REVM, address: &Address) -> Option<(Output, Output)> {
+        let account_info = vm.db().basic_ref(*address).expect("Always valid");
+        match self {
+            Self::Hash(expected) => {
+                let hash = account_info
+                    .map(|info| U256::from_be_bytes(info.code_hash.0))
+                    .unwrap_or_default();
+                if hash == *expected {
+                    None
+                } else {
+                    Some(((*expected).into(), hash.into()))
+                }
+            }
+            Self::Bytecode(expected) => {
+                let bytecode = match account_info {
+                    Some(info) => match info.code {
+                        Some(code) => code.original_bytes(),
+                        None => vm
+                            .db()
+                            .code_by_hash_ref(info.code_hash)
+                            .expect("Always valid")
+                            .original_bytes(),
+                    },
+                    None => revm::primitives::Bytes::new(),
+                };
+                if bytecode.as_ref() == expected.as_slice() {
+                    None
+                } else {
+                    Some((
+                        Output::new(
+                            revm_bytes_to_vec_value(revm::primitives::Bytes::from(
+                                expected.to_owned(),
+                            )),
+                            false,
+                            vec![],
+                        ),
+                        Output::new(revm_bytes_to_vec_value(bytecode), false, vec![]),
+                    ))
+                }
+            }
+        }
+    }
+}
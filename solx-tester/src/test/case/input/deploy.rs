@@ -5,12 +5,15 @@
 use std::sync::Arc;
 use std::sync::Mutex;
 
+use revm::context::TxEnv;
 use revm::context::result::ExecutionResult;
 use revm::primitives::Address;
 use revm::primitives::U256;
 
+use crate::fuzzer::outcome::Outcome as TransactionOutcome;
 use crate::summary::Summary;
 use crate::test::InputContext;
+use crate::test::case::input::budget::Budget;
 use crate::test::case::input::calldata::Calldata;
 use crate::test::case::input::identifier::InputIdentifier;
 use crate::test::case::input::output::Output;
@@ -38,6 +41,8 @@ pub struct Deploy {
     value: Option<u128>,
     /// The expected output.
     expected: Output,
+    /// The gas and code size budget.
+    budget: Budget,
 }
 
 impl Deploy {
@@ -52,6 +57,7 @@ impl Deploy {
         caller: Address,
         value: Option<u128>,
         expected: Output,
+        budget: Budget,
     ) -> Self {
         Self {
             identifier,
@@ -61,6 +67,7 @@ impl Deploy {
             caller,
             value,
             expected,
+            budget,
         }
     }
 }
@@ -79,19 +86,9 @@ impl Deploy {
         );
 
         let deploy_code_size = self.deploy_code.len();
-        let mut calldata = self.deploy_code;
-        calldata.extend(self.calldata.inner);
+        let (calldata, tx) = self.prepare(vm, input_index);
         let calldata_cost = REVM::calldata_gas_cost(calldata.as_slice());
 
-        let tx = REVM::new_deploy_transaction(self.caller, self.value, calldata.clone());
-
-        let initial_balance = (U256::from(1) << 100) + U256::from(self.value.unwrap_or_default());
-        vm.set_account(&self.caller, initial_balance);
-        vm.set_block_data(
-            revm::primitives::U256::from(input_index + 1),
-            revm::primitives::U256::from(((input_index + 1) as u128) * REVM::BLOCK_TIMESTAMP_STEP),
-        );
-
         let result = match vm.execute_transaction(tx) {
             Ok(result) => result,
             Err(error) => {
@@ -138,17 +135,63 @@ impl Deploy {
         );
 
         if output == self.expected {
-            Summary::passed_deploy(
-                summary,
-                test,
-                deploy_code_size as u64,
-                self.runtime_code_size as u64,
+            let violations = self.budget.check(
                 gas,
+                Some((deploy_code_size as u64, self.runtime_code_size as u64)),
             );
+            if violations.is_empty() {
+                Summary::passed_deploy(
+                    summary,
+                    test,
+                    deploy_code_size as u64,
+                    self.runtime_code_size as u64,
+                    gas,
+                );
+            } else {
+                Summary::over_budget(summary, test, violations);
+            }
         } else if let Some(error) = halt_reason {
             Summary::invalid(summary, test, format!("{error:?}"));
         } else {
             Summary::failed(summary, test, self.expected, output, calldata);
         }
     }
+
+    ///
+    /// Runs the deploy transaction on REVM, and returns its outcome without checking it.
+    ///
+    pub fn observe_revm(
+        &self,
+        vm: &mut REVM,
+        input_index: usize,
+    ) -> anyhow::Result<TransactionOutcome> {
+        let (_calldata, tx) = self.prepare(vm, input_index);
+        let result = vm
+            .execute_transaction(tx)
+            .map_err(|error| anyhow::anyhow!("Deploy transaction failed: {error:?}"))?;
+        let address = match result {
+            ExecutionResult::Success { ref output, .. } => output.address().copied(),
+            _ => None,
+        };
+        Ok(TransactionOutcome::new(result, vm, address))
+    }
+
+    ///
+    /// Funds the caller and sets the block data, and returns the calldata and the transaction.
+    ///
+    fn prepare(&self, vm: &mut REVM, input_index: usize) -> (Vec<u8>, TxEnv) {
+        let mut calldata = self.deploy_code.clone();
+        calldata.extend_from_slice(self.calldata.inner.as_slice());
+
+        let tx = REVM::new_deploy_transaction(self.caller, self.value, calldata.clone());
+
+        let initial_balance = (U256::from(1) << 100) + U256::from(self.value.unwrap_or_default());
+        vm.set_account(&self.caller, initial_balance);
+        vm.set_block_data(
+            revm::primitives::U256::from(input_index + 1),
+            revm::primitives::U256::from(((input_index + 1) as u128) * REVM::BLOCK_TIMESTAMP_STEP),
+        );
+
+        (calldata, tx)
+    }
 }
@@ -3,7 +3,9 @@
 //!
 
 pub mod balance;
+pub mod budget;
 pub mod calldata;
+pub mod code;
 pub mod deploy;
 pub mod identifier;
 pub mod output;
@@ -13,6 +15,7 @@ pub mod storage_empty;
 pub mod value;
 
 use std::collections::BTreeMap;
+use std::collections::HashMap;
 use std::str::FromStr;
 use std::sync::Arc;
 use std::sync::Mutex;
@@ -20,14 +23,18 @@ use std::sync::Mutex;
 use solx_utils::ContractName;
 
 use crate::compilers::mode::Mode;
+use crate::directories::matter_labs::test::metadata::budget::contract::Contract as MatterLabsTestContractBudget;
 use crate::directories::matter_labs::test::metadata::case::input::Input as MatterLabsTestInput;
+use crate::fuzzer::outcome::Outcome as TransactionOutcome;
 use crate::revm::REVM;
 use crate::summary::Summary;
 use crate::test::InputContext;
 use crate::test::instance::Instance;
 
 use self::balance::Balance;
+use self::budget::Budget;
 use self::calldata::Calldata;
+use self::code::Code;
 use self::deploy::Deploy;
 use self::output::Output;
 use self::runtime::Runtime;
@@ -59,6 +66,7 @@ impl Input {
         mode: &Mode,
         instances: &BTreeMap<String, Instance>,
         method_identifiers: &Option<BTreeMap<String, BTreeMap<String, u32>>>,
+        budgets: &BTreeMap<String, MatterLabsTestContractBudget>,
     ) -> anyhow::Result<Self> {
         let caller = match Value::try_from_matter_labs(input.caller.as_str(), instances)
             .map_err(|error| anyhow::anyhow!("Invalid caller `{}`: {error}", input.caller))?
@@ -96,11 +104,32 @@ impl Input {
         let storage = Storage::try_from_matter_labs(input.storage, instances)
             .map_err(|error| anyhow::anyhow!("Invalid storage: {error}"))?;
 
+        let budget = Budget::try_from_matter_labs(
+            input.expected_gas,
+            input.max_gas,
+            if input.method == "#deployer" {
+                budgets.get(&input.instance)
+            } else {
+                None
+            },
+            mode,
+        )
+        .map_err(|error| anyhow::anyhow!("Invalid budget: {error}"))?;
+
+        let transient_storage =
+            Storage::try_from_matter_labs(input.expected_transient_storage, instances)
+                .map_err(|error| anyhow::anyhow!("Invalid expected transient storage: {error}"))?;
+        let code = Code::try_from_matter_labs(input.expected_code, instances)
+            .map_err(|error| anyhow::anyhow!("Invalid expected code: {error}"))?;
+
         let instance = instances
             .get(&input.instance)
             .ok_or_else(|| anyhow::anyhow!("Instance `{}` not found", input.instance))?;
 
         let input = match input.method.as_str() {
+            "#deployer" if !transient_storage.inner.is_empty() || !code.is_empty() => {
+                anyhow::bail!("Transient storage and code can only be checked after calls")
+            }
             "#deployer" => Input::Deploy(Deploy::new(
                 instance.path.to_owned(),
                 instance.deploy_code.to_owned(),
@@ -109,6 +138,7 @@ impl Input {
                 caller,
                 value,
                 expected,
+                budget,
             )),
             "#fallback" => {
                 let address = instance.address().ok_or_else(|| {
@@ -126,6 +156,9 @@ impl Input {
                     value,
                     storage,
                     expected,
+                    transient_storage.clone(),
+                    code.clone(),
+                    budget,
                 ))
             }
             entry => {
@@ -173,6 +206,9 @@ impl Input {
                     value,
                     storage,
                     expected,
+                    transient_storage.clone(),
+                    code.clone(),
+                    budget,
                 ))
             }
         };
@@ -227,6 +263,7 @@ impl Input {
                     *caller,
                     value,
                     expected,
+                    Budget::default(),
                 )))
             }
             solx_solc_test_adapter::FunctionCall::Library { name, source } => {
@@ -258,6 +295,7 @@ impl Input {
                     *caller,
                     None,
                     expected,
+                    Budget::default(),
                 )))
             }
             solx_solc_test_adapter::FunctionCall::Balance {
@@ -300,6 +338,9 @@ impl Input {
                     value,
                     Storage::default(),
                     expected,
+                    Storage::default(),
+                    HashMap::new(),
+                    Budget::default(),
                 )))
             }
             _ => None,
@@ -319,4 +360,21 @@ impl Input {
             Self::Balance(balance_check) => balance_check.run_revm(summary, vm, context),
         }
     }
+
+    ///
+    /// Runs the input on REVM, and returns the transaction outcome without checking it.
+    ///
+    /// Returns `None` for the inputs that only check the state and do not execute a transaction.
+    ///
+    pub fn observe_revm(
+        &self,
+        vm: &mut REVM,
+        input_index: usize,
+    ) -> anyhow::Result<Option<TransactionOutcome>> {
+        match self {
+            Self::Deploy(deploy) => deploy.observe_revm(vm, input_index).map(Some),
+            Self::Runtime(runtime) => runtime.observe_revm(vm, input_index).map(Some),
+            Self::StorageEmpty(_) | Self::Balance(_) => Ok(None),
+        }
+    }
 }
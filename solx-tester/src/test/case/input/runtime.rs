@@ -2,18 +2,23 @@
 //! The contract call input variant.
 //!
 
+use std::collections::HashMap;
 use std::str::FromStr;
 use std::sync::Arc;
 use std::sync::Mutex;
 
+use revm::context::TxEnv;
 use revm::context::result::ExecutionResult;
 use revm::primitives::Address;
 use revm::primitives::U256;
 
+use crate::fuzzer::outcome::Outcome as TransactionOutcome;
 use crate::revm::REVM;
 use crate::revm::revm_type_conversions::revm_bytes_to_vec_value;
 use crate::summary::Summary;
+use crate::test::case::input::budget::Budget;
 use crate::test::case::input::calldata::Calldata;
+use crate::test::case::input::code::Code;
 use crate::test::case::input::identifier::InputIdentifier;
 use crate::test::case::input::output::Output;
 use crate::test::case::input::storage::Storage;
@@ -38,6 +43,12 @@ pub struct Runtime {
     storage: Storage,
     /// The expected output.
     expected: Output,
+    /// The expected transient storage at the end of the transaction.
+    transient_storage: Storage,
+    /// The expected account code after the call.
+    code: HashMap<Address, Code>,
+    /// The gas and code size budget.
+    budget: Budget,
 }
 
 impl Runtime {
@@ -52,6 +63,9 @@ impl Runtime {
         value: Option<u128>,
         storage: Storage,
         expected: Output,
+        transient_storage: Storage,
+        code: HashMap<Address, Code>,
+        budget: Budget,
     ) -> Self {
         Self {
             name,
@@ -61,6 +75,9 @@ impl Runtime {
             value,
             storage,
             expected,
+            transient_storage,
+            code,
+            budget,
         }
     }
 }
@@ -80,7 +97,7 @@ impl Runtime {
             context,
             InputIdentifier::Runtime {
                 input_index,
-                name: self.name,
+                name: self.name.clone(),
             },
         );
 
@@ -88,34 +105,16 @@ impl Runtime {
         {
             self.caller = Address::from_str(REVM::TX_ORIGIN).unwrap();
         }
-        let tx = REVM::new_runtime_transaction(
-            self.address,
-            self.caller,
-            self.calldata.clone(),
-            self.value,
-        );
-
-        let mut initial_balance =
-            U256::from(self.value.unwrap_or_default()) + U256::from(REVM::TX_BLOB_FEE);
-        if REVM::get_rich_addresses().contains(&self.caller) {
-            initial_balance += U256::from(1) << 100;
-        }
-        vm.set_account(&self.caller, initial_balance);
-
-        let storage = self
-            .storage
-            .inner
-            .get(&self.address)
-            .cloned()
-            .unwrap_or_default();
-        vm.extend_account_storage(&self.address, storage);
-        vm.set_block_data(
-            revm::primitives::U256::from(input_index + 1),
-            revm::primitives::U256::from(((input_index + 1) as u128) * REVM::BLOCK_TIMESTAMP_STEP),
-        );
+        let tx = self.prepare(vm, input_index);
 
-        let result = match vm.execute_transaction(tx) {
-            Ok(result) => result,
+        let execution = if self.transient_storage.inner.is_empty() {
+            vm.execute_transaction(tx).map(|result| (result, None))
+        } else {
+            vm.execute_transaction_with_transient_storage(tx)
+                .map(|(result, transient_storage)| (result, Some(transient_storage)))
+        };
+        let (result, transient_storage) = match execution {
+            Ok(execution) => execution,
             Err(error) => {
                 Summary::invalid(summary.clone(), test, error);
                 return;
@@ -156,11 +155,123 @@ impl Runtime {
         let gas = REVM::runtime_bytecode_execution_gas(total_gas_used, calldata_cost);
 
         if output == self.expected {
-            Summary::passed_runtime(summary, test, gas);
+            match self.check_state(vm, transient_storage) {
+                Ok(None) => {}
+                Ok(Some((expected, found, location))) => {
+                    Summary::failed(summary, test, expected, found, location);
+                    return;
+                }
+                Err(error) => {
+                    Summary::invalid(summary, test, error);
+                    return;
+                }
+            }
+
+            let violations = self.budget.check(gas, None);
+            if violations.is_empty() {
+                Summary::passed_runtime(summary, test, gas);
+            } else {
+                Summary::over_budget(summary, test, violations);
+            }
         } else if let Some(error) = error {
             Summary::invalid(summary, test, format!("{error:?}"));
         } else {
             Summary::failed(summary, test, self.expected, output, self.calldata.inner);
         }
     }
+
+    ///
+    /// Runs the call on REVM, and returns its outcome without checking it.
+    ///
+    pub fn observe_revm(
+        &self,
+        vm: &mut REVM,
+        input_index: usize,
+    ) -> anyhow::Result<TransactionOutcome> {
+        let tx = self.prepare(vm, input_index);
+        let result = vm
+            .execute_transaction(tx)
+            .map_err(|error| anyhow::anyhow!("Runtime transaction failed: {error:?}"))?;
+        Ok(TransactionOutcome::new(result, vm, Some(self.address)))
+    }
+
+    ///
+    /// Funds the caller, sets the initial storage and the block data, and returns the transaction.
+    ///
+    fn prepare(&self, vm: &mut REVM, input_index: usize) -> TxEnv {
+        let tx = REVM::new_runtime_transaction(
+            self.address,
+            self.caller,
+            self.calldata.clone(),
+            self.value,
+        );
+
+        let mut initial_balance =
+            U256::from(self.value.unwrap_or_default()) + U256::from(REVM::TX_BLOB_FEE);
+        if REVM::get_rich_addresses().contains(&self.caller) {
+            initial_balance += U256::from(1) << 100;
+        }
+        vm.set_account(&self.caller, initial_balance);
+
+        let storage = self
+            .storage
+            .inner
+            .get(&self.address)
+            .cloned()
+            .unwrap_or_default();
+        vm.extend_account_storage(&self.address, storage);
+        vm.set_block_data(
+            revm::primitives::U256::from(input_index + 1),
+            revm::primitives::U256::from(((input_index + 1) as u128) * REVM::BLOCK_TIMESTAMP_STEP),
+        );
+
+        tx
+    }
+
+    ///
+    /// Checks the transient storage at the end of the transaction and the account code after it.
+    ///
+    /// Returns the expected and found outputs of the first mismatch, along with its address and
+    /// transient storage key.
+    ///
+    fn check_state(
+        &self,
+        vm: &REVM,
+        transient_storage: Option<HashMap<(Address, U256), U256>>,
+    ) -> anyhow::Result<Option<(Output, Output, Vec<u8>)>> {
+        if let Some(transient_storage) = transient_storage {
+            let mut expected = self
+                .transient_storage
+                .inner
+                .iter()
+                .flat_map(|(address, storage)| {
+                    storage
+                        .iter()
+                        .map(move |(key, value)| (*address, *key, *value))
+                })
+                .collect::<Vec<(Address, U256, U256)>>();
+            expected.sort();
+            for (address, key, value) in expected.into_iter() {
+                let found = transient_storage
+                    .get(&(address, key))
+                    .copied()
+                    .unwrap_or_default();
+                if found != value {
+                    let mut location = address.to_vec();
+                    location.extend(key.to_be_bytes::<32>());
+                    return Ok(Some((value.into(), found.into(), location)));
+                }
+            }
+        }
+
+        let mut addresses = self.code.keys().collect::<Vec<&Address>>();
+        addresses.sort();
+        for address in addresses.into_iter() {
+            if let Some((expected, found)) = self.code[address].check(vm, address) {
+                return Ok(Some((expected, found, address.to_vec())));
+            }
+        }
+
+        Ok(None)
+    }
 }
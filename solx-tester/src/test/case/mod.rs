@@ -9,7 +9,9 @@ use std::sync::Arc;
 use std::sync::Mutex;
 
 use crate::compilers::mode::Mode;
+use crate::directories::matter_labs::test::metadata::budget::contract::Contract as MatterLabsTestContractBudget;
 use crate::directories::matter_labs::test::metadata::case::Case as MatterLabsTestCase;
+use crate::fuzzer::outcome::Outcome as TransactionOutcome;
 use crate::revm::REVM;
 use crate::summary::Summary;
 use crate::test::context::case::CaseContext;
@@ -45,12 +47,14 @@ impl Case {
         mode: &Mode,
         instances: &BTreeMap<String, Instance>,
         method_identifiers: &Option<BTreeMap<String, BTreeMap<String, u32>>>,
+        budgets: &BTreeMap<String, MatterLabsTestContractBudget>,
     ) -> anyhow::Result<Self> {
         let mut inputs = Vec::with_capacity(case.inputs.len());
 
         for (index, input) in case.inputs.into_iter().enumerate() {
-            let input = Input::try_from_matter_labs(input, mode, instances, method_identifiers)
-                .map_err(|error| anyhow::anyhow!("Input #{index} is invalid: {error}"))?;
+            let input =
+                Input::try_from_matter_labs(input, mode, instances, method_identifiers, budgets)
+                    .map_err(|error| anyhow::anyhow!("Input #{index} is invalid: {error}"))?;
             inputs.push(input);
         }
 
@@ -89,17 +93,39 @@ impl Case {
         Ok(Self::new(None, inputs))
     }
 
+    ///
+    /// Returns the case name.
+    ///
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
     ///
     /// Runs the case on REVM.
     ///
-    pub fn run_revm(self, summary: Arc<Mutex<Summary>>, context: &CaseContext, mut vm: REVM) {
+    pub fn run_revm(self, summary: Arc<Mutex<Summary>>, context: &CaseContext, vm: &mut REVM) {
         for (index, input) in self.inputs.into_iter().enumerate() {
             let context = InputContext {
                 case_context: context,
                 case_name: &self.name,
                 selector: index,
             };
-            input.run_revm(summary.clone(), &mut vm, context)
+            Summary::start_input(summary.clone());
+            input.run_revm(summary.clone(), vm, context)
+        }
+        Summary::finish_input(summary);
+    }
+
+    ///
+    /// Runs the case on REVM, and returns the outcomes of its transactions without checking them.
+    ///
+    pub fn observe_revm(&self, vm: &mut REVM) -> anyhow::Result<Vec<TransactionOutcome>> {
+        let mut outcomes = Vec::with_capacity(self.inputs.len());
+        for (index, input) in self.inputs.iter().enumerate() {
+            if let Some(outcome) = input.observe_revm(vm, index)? {
+                outcomes.push(outcome);
+            }
         }
+        Ok(outcomes)
     }
 }
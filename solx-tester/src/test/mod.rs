@@ -11,7 +11,12 @@ pub mod selector;
 use std::sync::Arc;
 use std::sync::Mutex;
 
+use solx_utils::SyncLock;
+
 use crate::compilers::mode::Mode;
+use crate::coverage::Coverage;
+use crate::coverage::code_map::CodeMap;
+use crate::fuzzer::outcome::Outcome as TransactionOutcome;
 use crate::revm::REVM;
 use crate::summary::Summary;
 use crate::test::case::Case;
@@ -31,32 +36,94 @@ pub struct Test {
     mode: Mode,
     /// The test group.
     group: Option<String>,
+    /// The contract codes with their source lines, if debug information is available.
+    code_map: Option<Arc<CodeMap>>,
 }
 
 impl Test {
     ///
     /// A shortcut constructor.
     ///
-    pub fn new(name: String, cases: Vec<Case>, mode: Mode, group: Option<String>) -> Self {
+    pub fn new(
+        name: String,
+        cases: Vec<Case>,
+        mode: Mode,
+        group: Option<String>,
+        code_map: Option<CodeMap>,
+    ) -> Self {
         Self {
             name,
             cases,
             mode,
             group,
+            code_map: code_map.map(Arc::new),
         }
     }
 
     ///
     /// Runs the test on REVM.
     ///
-    pub fn run_revm(self, summary: Arc<Mutex<Summary>>, enable_trace: bool) {
+    /// If `coverage` is set and the test has debug information, the executed lines are added to it.
+    ///
+    pub fn run_revm(
+        self,
+        summary: Arc<Mutex<Summary>>,
+        enable_trace: bool,
+        coverage: Option<Arc<Mutex<Coverage>>>,
+    ) {
         for case in self.cases {
             let context = CaseContext {
                 name: &self.name,
                 mode: &self.mode,
                 group: &self.group,
             };
-            case.run_revm(summary.clone(), &context, REVM::new(enable_trace));
+            match (coverage.as_ref(), self.code_map.as_ref()) {
+                (Some(coverage), Some(code_map)) if !enable_trace => {
+                    let mut vm = REVM::new_coverage(code_map.clone());
+                    case.run_revm(summary.clone(), &context, &mut vm);
+                    if let Some(hits) = vm.take_coverage() {
+                        coverage.lock_sync().add(code_map, hits);
+                    }
+                }
+                _ => case.run_revm(summary.clone(), &context, &mut REVM::new(enable_trace)),
+            }
         }
     }
+
+    ///
+    /// Runs the case named `case_name` on REVM, and returns the outcomes of its transactions
+    /// without checking them.
+    ///
+    pub fn observe_revm(self, case_name: &str) -> anyhow::Result<Vec<TransactionOutcome>> {
+        let case = self
+            .cases
+            .iter()
+            .find(|case| case.name() == Some(case_name))
+            .ok_or_else(|| anyhow::anyhow!("Case `{case_name}` not found in `{}`", self.name))?;
+        case.observe_revm(&mut REVM::new(false))
+    }
+
+    ///
+    /// Runs the case named `case_name` on REVM, writing its EIP-3155 trace to `trace`.
+    ///
+    pub fn run_revm_traced(
+        self,
+        summary: Arc<Mutex<Summary>>,
+        case_name: &str,
+        trace: Box<dyn std::io::Write>,
+    ) {
+        let Some(case) = self
+            .cases
+            .into_iter()
+            .find(|case| case.name() == Some(case_name))
+        else {
+            return;
+        };
+        let context = CaseContext {
+            name: &self.name,
+            mode: &self.mode,
+            group: &self.group,
+        };
+        case.run_revm(summary, &context, &mut REVM::new_tracing(trace));
+    }
 }
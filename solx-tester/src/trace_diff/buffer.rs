@@ -0,0 +1,37 @@
+//!
+//! The shared trace buffer.
+//!
+
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use solx_utils::SyncLock;
+
+///
+/// The shared trace buffer.
+///
+/// Collects the trace written by the tracer owned by REVM, so that it can be read back after the
+/// execution.
+///
+#[derive(Debug, Default, Clone)]
+pub struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+impl Buffer {
+    ///
+    /// Returns the collected trace.
+    ///
+    pub fn contents(&self) -> String {
+        String::from_utf8_lossy(self.0.lock_sync().as_slice()).into_owned()
+    }
+}
+
+impl std::io::Write for Buffer {
+    fn write(&mut self, buffer: &[u8]) -> std::io::Result<usize> {
+        self.0.lock_sync().extend_from_slice(buffer);
+        Ok(buffer.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
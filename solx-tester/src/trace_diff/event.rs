@@ -0,0 +1,218 @@
+//!
+//! The semantically relevant trace event.
+//!
+
+use crate::trace_diff::trace::Step;
+
+///
+/// The semantically relevant trace event.
+///
+/// Two correct compilations of the same contract may execute entirely different opcodes, but
+/// must produce the same sequence of events, so the traces are aligned at them.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    /// A message call or contract creation.
+    Call {
+        /// The call depth.
+        depth: u64,
+        /// The opcode name.
+        opcode: String,
+        /// The callee address, unknown for creations.
+        target: Option<String>,
+        /// The transferred value, if the opcode transfers any.
+        value: Option<String>,
+        /// The input data size.
+        input_size: Option<String>,
+    },
+    /// A persistent or transient storage write.
+    Storage {
+        /// The call depth.
+        depth: u64,
+        /// The opcode name.
+        opcode: String,
+        /// The storage key.
+        key: Option<String>,
+        /// The stored value.
+        value: Option<String>,
+    },
+    /// An emitted log.
+    Log {
+        /// The call depth.
+        depth: u64,
+        /// The topics.
+        topics: Vec<Option<String>>,
+        /// The data size.
+        size: Option<String>,
+    },
+    /// A frame exit.
+    Exit {
+        /// The call depth.
+        depth: u64,
+        /// The opcode name.
+        opcode: String,
+        /// The return or revert data size.
+        size: Option<String>,
+    },
+}
+
+impl Event {
+    /// The last precompile address, up to the BLS12-381 ones.
+    const PRECOMPILE_LAST_ADDRESS: u64 = 0x11;
+
+    ///
+    /// Extracts the event from the step, if the step is semantically relevant.
+    ///
+    /// An empty `RETURN` is reported as `STOP`, since the two are indistinguishable for the caller.
+    /// Calls to precompiles are skipped, since compilers use them differently, for instance, the
+    /// identity precompile for memory copying.
+    ///
+    pub fn from_step(step: &Step) -> Option<Self> {
+        let depth = step.depth;
+        let opcode = step.op_name.to_owned();
+        let item = |index: usize| step.stack_item(index).map(str::to_owned);
+
+        let event = match step.op_name.as_str() {
+            "CALL" | "CALLCODE" | "DELEGATECALL" | "STATICCALL"
+                if step.stack_item(1).is_some_and(Self::is_precompile) =>
+            {
+                return None;
+            }
+            "CALL" | "CALLCODE" => Self::Call {
+                depth,
+                opcode,
+                target: item(1),
+                value: item(2),
+                input_size: item(4),
+            },
+            "DELEGATECALL" | "STATICCALL" => Self::Call {
+                depth,
+                opcode,
+                target: item(1),
+                value: None,
+                input_size: item(3),
+            },
+            "CREATE" | "CREATE2" => Self::Call {
+                depth,
+                opcode,
+                target: None,
+                value: item(0),
+                input_size: item(2),
+            },
+            "SSTORE" | "TSTORE" => Self::Storage {
+                depth,
+                opcode,
+                key: item(0),
+                value: item(1),
+            },
+            name if name.starts_with("LOG") => {
+                let count = name["LOG".len()..].parse::<usize>().ok()?;
+                Self::Log {
+                    depth,
+                    topics: (0..count).map(|index| item(2 + index)).collect(),
+                    size: item(1),
+                }
+            }
+            "RETURN" if item(1).as_deref().is_some_and(Self::is_zero) => Self::Exit {
+                depth,
+                opcode: "STOP".to_owned(),
+                size: None,
+            },
+            "RETURN" | "REVERT" => Self::Exit {
+                depth,
+                opcode,
+                size: item(1),
+            },
+            "STOP" | "INVALID" | "SELFDESTRUCT" => Self::Exit {
+                depth,
+                opcode,
+                size: None,
+            },
+            _ => return None,
+        };
+        Some(event)
+    }
+
+    ///
+    /// Checks whether the hexadecimal stack item is a precompile address.
+    ///
+    fn is_precompile(item: &str) -> bool {
+        u64::from_str_radix(item.trim_start_matches("0x"), 16)
+            .is_ok_and(|address| (1..=Self::PRECOMPILE_LAST_ADDRESS).contains(&address))
+    }
+
+    ///
+    /// Checks whether the hexadecimal stack item is zero.
+    ///
+    fn is_zero(item: &str) -> bool {
+        item.trim_start_matches("0x")
+            .trim_start_matches('0')
+            .is_empty()
+    }
+}
+
+impl std::fmt::Display for Event {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let or_unknown =
+            |value: &Option<String>| value.to_owned().unwrap_or_else(|| "?".to_owned());
+        match self {
+            Self::Call {
+                depth,
+                opcode,
+                target,
+                value,
+                input_size,
+            } => {
+                write!(f, "{opcode}")?;
+                if target.is_some() {
+                    write!(f, " to {}", or_unknown(target))?;
+                }
+                if value.is_some() {
+                    write!(f, " with value {}", or_unknown(value))?;
+                }
+                write!(
+                    f,
+                    " and input size {} at depth {depth}",
+                    or_unknown(input_size)
+                )
+            }
+            Self::Storage {
+                depth,
+                opcode,
+                key,
+                value,
+            } => write!(
+                f,
+                "{opcode} {} at key {} at depth {depth}",
+                or_unknown(value),
+                or_unknown(key)
+            ),
+            Self::Log {
+                depth,
+                topics,
+                size,
+            } => write!(
+                f,
+                "LOG{} with topics [{}] and data size {} at depth {depth}",
+                topics.len(),
+                topics
+                    .iter()
+                    .map(or_unknown)
+                    .collect::<Vec<String>>()
+                    .join(", "),
+                or_unknown(size)
+            ),
+            Self::Exit {
+                depth,
+                opcode,
+                size,
+            } => {
+                write!(f, "{opcode}")?;
+                if size.is_some() {
+                    write!(f, " with data size {}", or_unknown(size))?;
+                }
+                write!(f, " at depth {depth}")
+            }
+        }
+    }
+}
@@ -0,0 +1,417 @@
+//!
+//! The EIP-3155 trace differ.
+//!
+
+pub mod buffer;
+pub mod event;
+pub mod trace;
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashSet;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use colored::Colorize;
+
+use crate::compilers::Compiler;
+use crate::compilers::mode::Mode;
+use crate::compilers::solidity::SolidityCompiler;
+use crate::compilers::solidity::dwarf::LineTable;
+use crate::compilers::solidity::mode::Mode as SolidityMode;
+use crate::directories::Buildable;
+use crate::directories::matter_labs::test::MatterLabsTest;
+use crate::filters::Filters;
+use crate::summary::Summary;
+use crate::test::Test;
+use crate::toolchain::Toolchain;
+
+use self::buffer::Buffer;
+use self::event::Event;
+use self::trace::Step;
+use self::trace::Transaction;
+
+///
+/// The EIP-3155 trace differ.
+///
+/// Runs a failing case with `solx` and the reference `solc` under the EIP-3155 tracer, aligns
+/// the traces at calls, storage writes, logs, and frame exits, and prints the first divergence
+/// with the surrounding opcodes and, if `solx` emits debug information, the Solidity source line.
+///
+pub struct TraceDiff {
+    /// The `solx` compiler.
+    solx: Arc<SolidityCompiler>,
+    /// The `solx` compilation mode.
+    solx_mode: Mode,
+    /// The reference `solc` compiler.
+    reference: Arc<SolidityCompiler>,
+    /// The reference `solc` compilation mode.
+    reference_mode: Mode,
+    /// The number of opcodes to print around the divergence.
+    context: usize,
+}
+
+///
+/// The traced execution of a test case.
+///
+struct Execution {
+    /// The toolchain name.
+    name: &'static str,
+    /// The traced transactions.
+    transactions: Vec<Transaction>,
+    /// The DWARF line tables of the contracts, if debug information is available.
+    line_tables: Vec<LineTable>,
+    /// The test sources.
+    sources: Vec<(String, String)>,
+}
+
+impl TraceDiff {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        solidity_compiler: PathBuf,
+        reference_compiler: PathBuf,
+        via_ir: bool,
+        optimization: char,
+        context: usize,
+    ) -> anyhow::Result<Self> {
+        let solx = SolidityCompiler::new(
+            solidity_compiler.clone(),
+            solx_standard_json::InputLanguage::Solidity,
+        )?;
+        if solx.toolchain() != Toolchain::Solx {
+            anyhow::bail!("{solidity_compiler:?} is not a `solx` executable");
+        }
+        let reference = SolidityCompiler::new(
+            reference_compiler.clone(),
+            solx_standard_json::InputLanguage::Solidity,
+        )?;
+        if reference.toolchain() != Toolchain::Solc {
+            anyhow::bail!("{reference_compiler:?} is not a `solc` executable");
+        }
+
+        let solx_mode = SolidityMode::new_solx(
+            solx.version().to_owned(),
+            via_ir,
+            solx_codegen_evm::OptimizerSettings::try_from_cli(optimization)?,
+        )
+        .into();
+        let reference_mode =
+            SolidityMode::new_solc(reference.version().to_owned(), via_ir, true).into();
+
+        Ok(Self {
+            solx: Arc::new(solx),
+            solx_mode,
+            reference: Arc::new(reference),
+            reference_mode,
+            context,
+        })
+    }
+
+    ///
+    /// Diffs the traces of the case of the test at `path`, by default its first failing one.
+    ///
+    /// Returns whether a divergence has been found.
+    ///
+    pub fn run(&self, path: &Path, case: Option<String>) -> anyhow::Result<bool> {
+        let case = match case {
+            Some(case) => case,
+            None => {
+                let summary = Summary::new(false, true).wrap();
+                let filters = Filters::new(HashSet::new(), false, None, vec![]);
+                Self::build(
+                    path,
+                    self.solx.clone(),
+                    &self.solx_mode,
+                    summary.clone(),
+                    &filters,
+                )?
+                .run_revm(summary.clone(), false, None);
+                Summary::unwrap_arc(summary)
+                    .failed_cases()
+                    .into_iter()
+                    .next()
+                    .ok_or_else(|| {
+                        anyhow::anyhow!("No case of {path:?} fails in mode {}", self.solx_mode)
+                    })?
+            }
+        };
+
+        println!(
+            "     {} {:?}::{} ({} vs reference {})",
+            "Tracing".bright_green().bold(),
+            path,
+            case,
+            self.solx_mode,
+            self.reference_mode,
+        );
+        let solx = self.execute(
+            path,
+            case.as_str(),
+            self.solx.clone(),
+            &self.solx_mode,
+            "solx",
+        )?;
+        let reference = self.execute(
+            path,
+            case.as_str(),
+            self.reference.clone(),
+            &self.reference_mode,
+            "solc",
+        )?;
+
+        let transaction_count = solx.transactions.len().max(reference.transactions.len());
+        for index in 0..transaction_count {
+            let (Some(solx_transaction), Some(reference_transaction)) = (
+                solx.transactions.get(index),
+                reference.transactions.get(index),
+            ) else {
+                println!(
+                    "  {} transaction #{index} is only executed by {}",
+                    "Divergence".bright_red().bold(),
+                    if solx.transactions.len() > index {
+                        solx.name
+                    } else {
+                        reference.name
+                    },
+                );
+                return Ok(true);
+            };
+
+            let solx_events = Self::events(solx_transaction);
+            let reference_events = Self::events(reference_transaction);
+            let event_count = solx_events.len().max(reference_events.len());
+            for event_index in 0..event_count {
+                let solx_event = solx_events.get(event_index);
+                let reference_event = reference_events.get(event_index);
+                if solx_event.map(|(_, event)| event) == reference_event.map(|(_, event)| event) {
+                    continue;
+                }
+
+                println!(
+                    "  {} in transaction #{index} at event #{event_index}",
+                    "Divergence".bright_red().bold(),
+                );
+                for (execution, transaction, event) in [
+                    (&solx, solx_transaction, solx_event),
+                    (&reference, reference_transaction, reference_event),
+                ] {
+                    let step_index = event
+                        .map(|(step_index, _)| *step_index)
+                        .unwrap_or(transaction.steps.len().saturating_sub(1));
+                    println!(
+                        "{:>12}: {}",
+                        execution.name,
+                        event
+                            .map(|(_, event)| event.to_string())
+                            .unwrap_or_else(|| "no event".to_owned())
+                    );
+                    self.print_context(execution, transaction, step_index);
+                }
+                return Ok(true);
+            }
+
+            if let (Some(solx_summary), Some(reference_summary)) = (
+                solx_transaction.summary.as_ref(),
+                reference_transaction.summary.as_ref(),
+            ) && (solx_summary.pass != reference_summary.pass
+                || solx_summary.output != reference_summary.output)
+            {
+                println!(
+                    "  {} in transaction #{index} result",
+                    "Divergence".bright_red().bold(),
+                );
+                for (execution, transaction, summary) in [
+                    (&solx, solx_transaction, solx_summary),
+                    (&reference, reference_transaction, reference_summary),
+                ] {
+                    println!(
+                        "{:>12}: {} with output {}",
+                        execution.name,
+                        if summary.pass { "success" } else { "failure" },
+                        summary.output,
+                    );
+                    self.print_context(
+                        execution,
+                        transaction,
+                        transaction.steps.len().saturating_sub(1),
+                    );
+                }
+                return Ok(true);
+            }
+        }
+
+        println!(
+            "    {} the traces of {} transactions do not diverge",
+            "Finished".bright_green().bold(),
+            transaction_count,
+        );
+        Ok(false)
+    }
+
+    ///
+    /// Runs the case under the tracer, and collects the line tables of the contracts.
+    ///
+    fn execute(
+        &self,
+        path: &Path,
+        case: &str,
+        compiler: Arc<SolidityCompiler>,
+        mode: &Mode,
+        name: &'static str,
+    ) -> anyhow::Result<Execution> {
+        let summary = Summary::new(false, true).wrap();
+        let filters = Filters::new(HashSet::new(), false, None, vec![]);
+        let test = MatterLabsTest::new(path.to_owned(), summary.clone(), &filters)
+            .ok_or_else(|| anyhow::anyhow!("Failed to read the test {path:?}"))?;
+        let sources = test.sources().to_owned();
+
+        let buffer = Buffer::default();
+        Self::build(path, compiler.clone(), mode, summary.clone(), &filters)?.run_revm_traced(
+            summary,
+            case,
+            Box::new(buffer.clone()),
+        );
+        let transactions = Transaction::parse_trace(buffer.contents().as_str())?;
+
+        let line_tables = match compiler.toolchain() {
+            Toolchain::Solx => compiler
+                .line_tables(sources.as_slice(), mode)
+                .map(|line_tables| {
+                    line_tables
+                        .into_iter()
+                        .map(|(_contract, _code_segment, line_table)| line_table)
+                        .collect()
+                })
+                .unwrap_or_default(),
+            Toolchain::Solc => vec![],
+        };
+
+        Ok(Execution {
+            name,
+            transactions,
+            line_tables,
+            sources,
+        })
+    }
+
+    ///
+    /// Builds the test at `path`.
+    ///
+    fn build(
+        path: &Path,
+        compiler: Arc<SolidityCompiler>,
+        mode: &Mode,
+        summary: Arc<Mutex<Summary>>,
+        filters: &Filters,
+    ) -> anyhow::Result<Test> {
+        MatterLabsTest::new(path.to_owned(), summary.clone(), filters)
+            .and_then(|test| {
+                test.build_for_evm(
+                    mode.to_owned(),
+                    compiler as Arc<dyn Compiler>,
+                    summary,
+                    filters,
+                    None,
+                )
+            })
+            .ok_or_else(|| anyhow::anyhow!("Failed to build the test {path:?} in mode {mode}"))
+    }
+
+    ///
+    /// Returns the events of the transaction with the indexes of their steps.
+    ///
+    /// The storage writes between two other events are reported as the last write to each key
+    /// in key order, since compilers may reorder or merge independent writes within a frame.
+    ///
+    fn events(transaction: &Transaction) -> Vec<(usize, Event)> {
+        let mut events = Vec::with_capacity(transaction.steps.len());
+        let mut writes = BTreeMap::new();
+        for (index, step) in transaction.steps.iter().enumerate() {
+            let Some(event) = Event::from_step(step) else {
+                continue;
+            };
+            match &event {
+                Event::Storage { opcode, key, .. } => {
+                    writes.insert((opcode.to_owned(), key.to_owned()), (index, event));
+                }
+                _ => {
+                    events.extend(std::mem::take(&mut writes).into_values());
+                    events.push((index, event));
+                }
+            }
+        }
+        events.extend(writes.into_values());
+        events
+    }
+
+    ///
+    /// Prints the opcodes around the step, and its source line if it is known.
+    ///
+    fn print_context(&self, execution: &Execution, transaction: &Transaction, step_index: usize) {
+        let start = step_index.saturating_sub(self.context);
+        let end = (step_index + self.context + 1).min(transaction.steps.len());
+        for (index, step) in transaction.steps[start..end].iter().enumerate() {
+            let marker = if start + index == step_index {
+                "->"
+            } else {
+                "  "
+            };
+            let stack_top = step
+                .stack
+                .iter()
+                .rev()
+                .take(3)
+                .cloned()
+                .collect::<Vec<String>>()
+                .join(", ");
+            let line = format!(
+                "{:>14} {:>#6x} {:<14} [{}]",
+                marker, step.pc, step.op_name, stack_top,
+            );
+            if start + index == step_index {
+                println!("{}", line.bright_white());
+            } else {
+                println!("{}", line.bright_black());
+            }
+        }
+
+        if let Some(step) = transaction.steps.get(step_index)
+            && let Some((path, line)) = Self::source_line(execution, step)
+        {
+            let text = execution
+                .sources
+                .iter()
+                .find(|(source_path, _)| source_path.ends_with(path.as_str()))
+                .and_then(|(_, source_code)| {
+                    source_code.lines().nth((line as usize).checked_sub(1)?)
+                })
+                .map(str::trim)
+                .unwrap_or_default();
+            println!("{:>17} {}:{}: {}", "source", path, line, text);
+        }
+    }
+
+    ///
+    /// Returns the source line of the step, if the line tables map its address unambiguously.
+    ///
+    /// The trace does not say which contract code is executed, so the address is looked up in the
+    /// tables of all contracts.
+    ///
+    fn source_line(execution: &Execution, step: &Step) -> Option<(String, u64)> {
+        let lines = execution
+            .line_tables
+            .iter()
+            .filter_map(|line_table| line_table.range(..=step.pc).next_back())
+            .map(|(_address, line)| line.to_owned())
+            .collect::<BTreeSet<(String, u64)>>();
+        if lines.len() == 1 {
+            lines.into_iter().next()
+        } else {
+            None
+        }
+    }
+}
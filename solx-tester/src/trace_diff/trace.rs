@@ -0,0 +1,80 @@
+//!
+//! The parsed EIP-3155 trace.
+//!
+
+use serde::Deserialize;
+
+///
+/// The EIP-3155 trace step.
+///
+#[derive(Debug, Clone, Deserialize)]
+pub struct Step {
+    /// The program counter.
+    pub pc: u64,
+    /// The opcode name.
+    #[serde(rename = "opName")]
+    pub op_name: String,
+    /// The stack, from the bottom to the top.
+    pub stack: Vec<String>,
+    /// The call depth, starting from `1`.
+    pub depth: u64,
+}
+
+impl Step {
+    ///
+    /// Returns the stack element `index` positions below the top.
+    ///
+    pub fn stack_item(&self, index: usize) -> Option<&str> {
+        self.stack
+            .len()
+            .checked_sub(index + 1)
+            .map(|index| self.stack[index].as_str())
+    }
+}
+
+///
+/// The EIP-3155 transaction summary.
+///
+#[derive(Debug, Clone, Deserialize)]
+pub struct TransactionSummary {
+    /// The return or revert data.
+    pub output: String,
+    /// Whether the transaction has succeeded.
+    pub pass: bool,
+}
+
+///
+/// The traced transaction.
+///
+#[derive(Debug, Clone, Default)]
+pub struct Transaction {
+    /// The executed steps.
+    pub steps: Vec<Step>,
+    /// The summary, missing if the trace has been cut off.
+    pub summary: Option<TransactionSummary>,
+}
+
+impl Transaction {
+    ///
+    /// Splits the EIP-3155 trace into transactions at their summary lines.
+    ///
+    pub fn parse_trace(trace: &str) -> anyhow::Result<Vec<Self>> {
+        let mut transactions = Vec::new();
+        let mut transaction = Self::default();
+        for line in trace.lines().filter(|line| !line.trim().is_empty()) {
+            let value: serde_json::Value = serde_json::from_str(line).map_err(|error| {
+                anyhow::anyhow!("Invalid EIP-3155 trace line `{line}`: {error}")
+            })?;
+            if value.get("pc").is_some() {
+                transaction.steps.push(serde_json::from_value(value)?);
+            } else {
+                transaction.summary = Some(serde_json::from_value(value)?);
+                transactions.push(std::mem::take(&mut transaction));
+            }
+        }
+        if !transaction.steps.is_empty() {
+            transactions.push(transaction);
+        }
+        Ok(transactions)
+    }
+}
@@ -5,7 +5,7 @@
 ///
 /// CBOR payload.
 ///
-/// Used for encoding IPFS contract metadata hash.
+/// Used for encoding IPFS and Swarm contract metadata hashes.
 ///
 #[derive(Debug, Clone, PartialEq)]
 pub struct CBOR<'a, S>
@@ -50,8 +50,9 @@ where
         cbor.push(0xA0_u8 + (field_count as u8));
 
         if let Some((r#type, hash)) = self.hash.as_ref() {
-            cbor.push(0x64_u8);
-            cbor.extend(r#type.to_string().as_bytes());
+            let r#type = r#type.to_string();
+            cbor.push(0x60_u8 + (r#type.len() as u8));
+            cbor.extend(r#type.as_bytes());
             cbor.push(0x58_u8);
             cbor.push(hash.len() as u8);
             cbor.extend_from_slice(hash);
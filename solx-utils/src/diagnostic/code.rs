@@ -0,0 +1,79 @@
+//!
+//! The diagnostic code.
+//!
+
+use super::severity::Severity;
+
+///
+/// The diagnostic code.
+///
+/// The numeric values are stable and must never be reused for another diagnostic.
+/// Codes mimicking `solc` ones are kept for compatibility, and the solx-specific ones
+/// have five digits to avoid clashing with `solc` codes.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum Code {
+    /// The deploy code exceeds the EVM size limit.
+    DeployCodeSize,
+    /// The runtime code exceeds the EVM size limit.
+    RuntimeCodeSize,
+    /// The stack-too-deep error could not be resolved by spilling.
+    StackTooDeep,
+    /// LLVM reported a fatal error.
+    LLVMFatalError,
+    /// Memory-unsafe assembly prevents resolving the stack-too-deep error.
+    UnsafeMemoryAssembly,
+    /// The instruction is not supported by solx.
+    UnsupportedInstruction,
+    /// The instruction is not available on the selected EVM version.
+    UnavailableInstruction,
+    /// The bytecode could not be assembled or linked.
+    LinkerFailure,
+    /// The translation unit exceeded the wall-clock time limit.
+    TimeLimit,
+    /// The translation unit exceeded the memory limit.
+    MemoryLimit,
+}
+
+impl Code {
+    ///
+    /// Returns the stable numeric code.
+    ///
+    pub fn code(self) -> isize {
+        match self {
+            Self::DeployCodeSize => 3860,
+            Self::RuntimeCodeSize => 5574,
+            Self::StackTooDeep => 10001,
+            Self::LLVMFatalError => 10002,
+            Self::UnsafeMemoryAssembly => 10003,
+            Self::UnsupportedInstruction => 10004,
+            Self::UnavailableInstruction => 10005,
+            Self::LinkerFailure => 10006,
+            Self::TimeLimit => 10007,
+            Self::MemoryLimit => 10008,
+        }
+    }
+
+    ///
+    /// Returns the severity.
+    ///
+    pub fn severity(self) -> Severity {
+        match self {
+            Self::DeployCodeSize | Self::RuntimeCodeSize => Severity::Warning,
+            Self::StackTooDeep
+            | Self::LLVMFatalError
+            | Self::UnsafeMemoryAssembly
+            | Self::UnsupportedInstruction
+            | Self::UnavailableInstruction
+            | Self::LinkerFailure
+            | Self::TimeLimit
+            | Self::MemoryLimit => Severity::Error,
+        }
+    }
+}
+
+impl std::fmt::Display for Code {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
@@ -0,0 +1,35 @@
+//!
+//! The compiler component a diagnostic originates from.
+//!
+
+///
+/// The compiler component a diagnostic originates from.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum Component {
+    /// The Yul frontend.
+    Yul,
+    /// The EVM legacy assembly frontend.
+    EVMLA,
+    /// The LLVM IR generator and optimizer.
+    Codegen,
+    /// The LLVM backend.
+    LLVM,
+    /// The assembler and linker.
+    Linker,
+    /// The worker subprocess pool.
+    Worker,
+}
+
+impl std::fmt::Display for Component {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Yul => write!(f, "yul"),
+            Self::EVMLA => write!(f, "evmla"),
+            Self::Codegen => write!(f, "codegen"),
+            Self::LLVM => write!(f, "llvm"),
+            Self::Linker => write!(f, "linker"),
+            Self::Worker => write!(f, "worker"),
+        }
+    }
+}
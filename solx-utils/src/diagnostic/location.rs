@@ -0,0 +1,27 @@
+//!
+//! The diagnostic source code location.
+//!
+
+///
+/// The diagnostic source code location.
+///
+/// The offsets are absolute char offsets in the source code file, as in `solc` source maps.
+///
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Location {
+    /// Source file path.
+    pub path: String,
+    /// Start offset.
+    pub start: isize,
+    /// End offset.
+    pub end: isize,
+}
+
+impl Location {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(path: String, start: isize, end: isize) -> Self {
+        Self { path, start, end }
+    }
+}
@@ -0,0 +1,93 @@
+//!
+//! The compiler diagnostic.
+//!
+
+pub mod code;
+pub mod component;
+pub mod location;
+pub mod severity;
+
+use self::code::Code;
+use self::component::Component;
+use self::location::Location;
+use self::severity::Severity;
+
+///
+/// The compiler diagnostic.
+///
+/// Raised by solx components instead of free-form errors, so that the catalogued code,
+/// severity, and component survive until the standard JSON output.
+///
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Diagnostic {
+    /// The catalogued diagnostic code.
+    pub code: Code,
+    /// The component the diagnostic originates from.
+    pub component: Component,
+    /// The severity, which defaults to the catalogued one.
+    pub severity: Severity,
+    /// The human-readable message.
+    pub message: String,
+    /// The source code location, if known.
+    pub location: Option<Location>,
+}
+
+impl Diagnostic {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new<S>(code: Code, component: Component, message: S) -> Self
+    where
+        S: std::fmt::Display,
+    {
+        Self {
+            code,
+            component,
+            severity: code.severity(),
+            message: message.to_string(),
+            location: None,
+        }
+    }
+
+    ///
+    /// Sets the source code location.
+    ///
+    pub fn with_location(mut self, location: Option<Location>) -> Self {
+        self.location = location;
+        self
+    }
+
+    ///
+    /// Overrides the catalogued severity.
+    ///
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    ///
+    /// Prepends `context` to the message.
+    ///
+    pub fn with_context<S>(mut self, context: S) -> Self
+    where
+        S: std::fmt::Display,
+    {
+        self.message = format!("{context}: {}", self.message);
+        self
+    }
+
+    ///
+    /// Returns the severity.
+    ///
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+}
+
+impl std::fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for Diagnostic {}
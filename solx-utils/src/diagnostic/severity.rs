@@ -0,0 +1,26 @@
+//!
+//! The diagnostic severity.
+//!
+
+///
+/// The diagnostic severity.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum Severity {
+    /// The compilation cannot proceed.
+    Error,
+    /// The compilation proceeds, but the output may be unusable.
+    Warning,
+    /// The informational message.
+    Info,
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Error => write!(f, "Error"),
+            Self::Warning => write!(f, "Warning"),
+            Self::Info => write!(f, "Info"),
+        }
+    }
+}
@@ -0,0 +1,51 @@
+//!
+//! EVM opcode gated by a hard fork.
+//!
+
+///
+/// EVM opcode gated by a hard fork.
+///
+/// Only the opcodes introduced by the supported EVM versions are listed here. The older ones
+/// are available on every supported version and are never checked.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EVMOpcode {
+    /// The eponymous EVM instruction.
+    TLoad,
+    /// The eponymous EVM instruction.
+    TStore,
+    /// The eponymous EVM instruction.
+    MCopy,
+    /// The eponymous EVM instruction.
+    BlobHash,
+    /// The eponymous EVM instruction.
+    BlobBaseFee,
+    /// The eponymous EVM instruction.
+    Clz,
+}
+
+impl EVMOpcode {
+    ///
+    /// Returns the earliest EVM version the opcode is available on.
+    ///
+    pub fn introduced_in(self) -> crate::EVMVersion {
+        crate::EVMVersion::FORKS
+            .iter()
+            .find(|(_, opcodes)| opcodes.contains(&self))
+            .map(|(version, _)| *version)
+            .expect("Every opcode is enabled by a fork")
+    }
+}
+
+impl std::fmt::Display for EVMOpcode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TLoad => write!(f, "tload"),
+            Self::TStore => write!(f, "tstore"),
+            Self::MCopy => write!(f, "mcopy"),
+            Self::BlobHash => write!(f, "blobhash"),
+            Self::BlobBaseFee => write!(f, "blobbasefee"),
+            Self::Clz => write!(f, "clz"),
+        }
+    }
+}
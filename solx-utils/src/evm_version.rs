@@ -32,29 +32,86 @@ pub enum EVMVersion {
     #[serde(rename = "osaka")]
     #[default]
     Osaka,
+    /// The corresponding EVM version.
+    #[serde(rename = "amsterdam")]
+    Amsterdam,
 }
 
 impl FromStr for EVMVersion {
     type Err = anyhow::Error;
 
     fn from_str(value: &str) -> Result<Self, Self::Err> {
-        Ok(match value {
-            "cancun" => Self::Cancun,
-            "prague" => Self::Prague,
-            "osaka" => Self::Osaka,
-            _ => anyhow::bail!(
-                "Unsupported EVM version: {value}. Supported ones are: {}",
-                vec![Self::Cancun, Self::Prague, Self::Osaka,]
-                    .into_iter()
-                    .map(|target| target.to_string())
-                    .collect::<Vec<String>>()
-                    .join(", ")
-            ),
-        })
+        Self::FORKS
+            .iter()
+            .map(|(version, _)| *version)
+            .find(|version| version.to_string() == value)
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Unsupported EVM version: {value}. Supported ones are: {}",
+                    Self::FORKS
+                        .iter()
+                        .map(|(version, _)| version.to_string())
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                )
+            })
     }
 }
 
 impl EVMVersion {
+    ///
+    /// The supported hard forks in activation order, each with the opcodes it enables.
+    ///
+    /// A new fork is added by declaring it here, and every opcode check picks it up.
+    ///
+    pub const FORKS: [(Self, &'static [crate::EVMOpcode]); 4] = [
+        (
+            Self::Cancun,
+            &[
+                crate::EVMOpcode::TLoad,
+                crate::EVMOpcode::TStore,
+                crate::EVMOpcode::MCopy,
+                crate::EVMOpcode::BlobHash,
+                crate::EVMOpcode::BlobBaseFee,
+            ],
+        ),
+        (Self::Prague, &[]),
+        (Self::Osaka, &[crate::EVMOpcode::Clz]),
+        (Self::Amsterdam, &[]),
+    ];
+
+    ///
+    /// Whether the opcode is enabled by this or an earlier EVM version.
+    ///
+    pub fn is_opcode_available(self, opcode: crate::EVMOpcode) -> bool {
+        Self::FORKS
+            .iter()
+            .take_while(|(version, _)| *version <= self)
+            .any(|(_, opcodes)| opcodes.contains(&opcode))
+    }
+
+    ///
+    /// Returns a diagnostic raised by `component` if the opcode is not available on this
+    /// EVM version.
+    ///
+    pub fn check_opcode(
+        self,
+        opcode: crate::EVMOpcode,
+        component: crate::DiagnosticComponent,
+    ) -> Result<(), crate::Diagnostic> {
+        if !self.is_opcode_available(opcode) {
+            return Err(crate::Diagnostic::new(
+                crate::DiagnosticCode::UnavailableInstruction,
+                component,
+                format!(
+                    "The `{opcode}` instruction is not available on EVM version `{self}`. Use `{}` or newer.",
+                    opcode.introduced_in()
+                ),
+            ));
+        }
+        Ok(())
+    }
+
     ///
     /// Returns the LLVM subtarget feature string for the EVM version, or `None` for versions
     /// the EVM target does not model, which are its generic subtarget. Naming them anyway makes
@@ -66,7 +123,7 @@ impl EVMVersion {
     pub fn llvm_target_features(self) -> Option<&'static str> {
         match self {
             Self::Cancun | Self::Prague => None,
-            Self::Osaka => Some("+osaka"),
+            Self::Osaka | Self::Amsterdam => Some("+osaka"),
         }
     }
 }
@@ -79,6 +136,7 @@ impl EVMVersion {
             Self::Cancun => 11,
             Self::Prague => 12,
             Self::Osaka => 13,
+            Self::Amsterdam => 14,
         }
     }
 }
@@ -89,6 +147,35 @@ impl std::fmt::Display for EVMVersion {
             Self::Cancun => write!(f, "cancun"),
             Self::Prague => write!(f, "prague"),
             Self::Osaka => write!(f, "osaka"),
+            Self::Amsterdam => write!(f, "amsterdam"),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::EVMVersion;
+    use crate::EVMOpcode;
+
+    /// An opcode must be available on the fork that enables it and on every later one.
+    #[test]
+    fn opcode_availability_follows_fork_order() {
+        assert!(!EVMVersion::Cancun.is_opcode_available(EVMOpcode::Clz));
+        assert!(!EVMVersion::Prague.is_opcode_available(EVMOpcode::Clz));
+        assert!(EVMVersion::Osaka.is_opcode_available(EVMOpcode::Clz));
+        assert!(EVMVersion::Amsterdam.is_opcode_available(EVMOpcode::Clz));
+        assert!(EVMVersion::Cancun.is_opcode_available(EVMOpcode::TStore));
+    }
+
+    /// The error must name both the selected EVM version and the one enabling the opcode.
+    #[test]
+    fn unavailable_opcode_error() {
+        let error = EVMVersion::Prague
+            .check_opcode(EVMOpcode::Clz, crate::DiagnosticComponent::Codegen)
+            .expect_err("Always fails");
+        assert_eq!(
+            error.to_string(),
+            "The `clz` instruction is not available on EVM version `prague`. Use `osaka` or newer."
+        );
+    }
+}
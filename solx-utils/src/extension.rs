@@ -29,6 +29,9 @@ pub static EXTENSION_EVM_ASSEMBLY: &str = "asm";
 /// The EVM bytecode file extension.
 pub static EXTENSION_EVM_BINARY: &str = "bin";
 
+/// The relocatable EVM object file extension.
+pub static EXTENSION_EVM_OBJECT: &str = "o";
+
 /// The Solidity file extension.
 pub static EXTENSION_SOLIDITY: &str = "sol";
 
@@ -38,6 +41,9 @@ pub static EXTENSION_LLVM_SOURCE: &str = "ll";
 /// The LLVM bitcode file extension.
 pub static EXTENSION_LLVM_BINARY: &str = "bc";
 
+/// The Graphviz DOT file extension.
+pub static EXTENSION_DOT: &str = "dot";
+
 /// The MLIR source code file extension.
 #[cfg(feature = "mlir")]
 pub static EXTENSION_MLIR: &str = "mlir";
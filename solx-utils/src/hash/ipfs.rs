@@ -38,6 +38,13 @@ impl IPFS {
         }
     }
 
+    ///
+    /// Returns a reference to the base58 string representation, also known as CIDv0.
+    ///
+    pub fn as_base58(&self) -> &str {
+        self.string_base58.as_str()
+    }
+
     ///
     /// Extracts the binary representation.
     ///
@@ -4,3 +4,4 @@
 
 pub mod ipfs;
 pub mod keccak256;
+pub mod swarm;
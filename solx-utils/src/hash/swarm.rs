@@ -0,0 +1,178 @@
+//!
+//! Swarm hash utilities.
+//!
+
+use sha3::Digest;
+
+///
+/// Swarm hash utilities.
+///
+/// Implements the `bzzr1` binary Merkle tree chunk hash used by `solc`.
+///
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Swarm {
+    /// Binary representation.
+    bytes: [u8; crate::BYTE_LENGTH_FIELD],
+    /// Hexadecimal string representation.
+    string_hex: String,
+}
+
+impl Swarm {
+    /// The Swarm chunk size.
+    const CHUNK_SIZE: usize = 4096;
+
+    /// The number of child hashes fitting into a chunk.
+    const BRANCHES: usize = Self::CHUNK_SIZE / crate::BYTE_LENGTH_FIELD;
+
+    /// The binary Merkle tree leaf size.
+    const SEGMENT_PAIR_SIZE: usize = 2 * crate::BYTE_LENGTH_FIELD;
+
+    ///
+    /// Computes the `bzzr1` Swarm hash for `preimage`.
+    ///
+    pub fn from_slice(preimage: &[u8]) -> Self {
+        let bytes = Self::chunk_hash(preimage, false);
+        let string_hex = hex::encode(bytes);
+        Self { bytes, string_hex }
+    }
+
+    ///
+    /// Returns a reference to the 32-byte binary representation.
+    ///
+    pub fn as_bytes(&self) -> &[u8; crate::BYTE_LENGTH_FIELD] {
+        &self.bytes
+    }
+
+    ///
+    /// Extracts the binary representation.
+    ///
+    pub fn to_vec(&self) -> Vec<u8> {
+        self.bytes.to_vec()
+    }
+
+    ///
+    /// Hashes a chunk, or the tree of chunks if `data` does not fit into a single one.
+    ///
+    /// The span, that is the length of the data represented by the chunk, is prepended
+    /// to the binary Merkle tree root as a 64-bit little-endian number.
+    ///
+    fn chunk_hash(data: &[u8], force_higher_level: bool) -> [u8; crate::BYTE_LENGTH_FIELD] {
+        let mut chunk = if data.len() < Self::CHUNK_SIZE
+            || (data.len() == Self::CHUNK_SIZE && !force_higher_level)
+        {
+            data.to_vec()
+        } else {
+            let mut max_represented_size = Self::CHUNK_SIZE;
+            while max_represented_size * Self::BRANCHES < data.len() {
+                max_represented_size *= Self::BRANCHES;
+            }
+            let force_higher_level = max_represented_size > Self::CHUNK_SIZE;
+
+            data.chunks(max_represented_size)
+                .flat_map(|child| Self::chunk_hash(child, force_higher_level))
+                .collect()
+        };
+        chunk.resize(Self::CHUNK_SIZE, 0);
+
+        let mut hasher = sha3::Keccak256::new();
+        hasher.update((data.len() as u64).to_le_bytes());
+        hasher.update(Self::bmt_hash(chunk.as_slice()));
+        hasher.finalize().into()
+    }
+
+    ///
+    /// Computes the binary Merkle tree root of a chunk.
+    ///
+    fn bmt_hash(data: &[u8]) -> [u8; crate::BYTE_LENGTH_FIELD] {
+        if data.len() <= Self::SEGMENT_PAIR_SIZE {
+            return sha3::Keccak256::digest(data).into();
+        }
+
+        let (left, right) = data.split_at(data.len() / 2);
+        let mut hasher = sha3::Keccak256::new();
+        hasher.update(Self::bmt_hash(left));
+        hasher.update(Self::bmt_hash(right));
+        hasher.finalize().into()
+    }
+}
+
+impl std::fmt::Display for Swarm {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.string_hex)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Swarm;
+
+    /// The hash of empty data must match the `bzzr1` reference value produced by `solc`.
+    #[test]
+    fn empty() {
+        assert_eq!(
+            Swarm::from_slice(&[]).to_string(),
+            "b34ca8c22b9e982354f9c7f50b470d66db428d880c8a904d5fe4ec9713171526"
+        );
+    }
+
+    /// The hash of data within a single chunk must match the `bzzr1` reference value.
+    #[test]
+    fn single_chunk() {
+        assert_eq!(
+            Swarm::from_slice(b"hello world").to_string(),
+            "92672a471f4419b255d7cb0cf313474a6f5856fb347c5ece85fb706d644b630f"
+        );
+        assert_eq!(
+            Swarm::from_slice(vec![b'A'; 4096].as_slice()).to_string(),
+            "b8eff8d571a76806507afd44a0c58cb1bea2d8db5538d8e1c1eecee7ab12a66d"
+        );
+    }
+
+    /// The hash of data spanning several chunks, with a non-full final chunk, must match the
+    /// `bzzr1` reference values.
+    #[test]
+    fn multiple_chunks() {
+        for (length, expected) in [
+            (
+                4097,
+                "d5f9870c62a6056bf6e7014fdd9ea7831388a0290699e6bf15efe8ca73884898",
+            ),
+            (
+                8192,
+                "1fc5dcbca743ac3a95ea9090fc1fe291feef24279ba371df49412a059a9484c5",
+            ),
+            (
+                10000,
+                "e4160f3961447b601dc34ca1ad6ec1a2691c77596725290c3ab6f50365c47936",
+            ),
+        ] {
+            assert_eq!(
+                Swarm::from_slice(vec![b'A'; length].as_slice()).to_string(),
+                expected,
+                "length {length}"
+            );
+        }
+    }
+
+    /// The hash of data not fitting into a single level of chunk hashes must match the `bzzr1`
+    /// reference values, including the full chunks forced to the higher level.
+    #[test]
+    fn multiple_levels() {
+        for (length, expected) in [
+            (
+                4096 * 128,
+                "f874a213777770f5345aca9a6e213c4acb0b8a3cdc11bfb51c6eaa44d1515d5b",
+            ),
+            (
+                4096 * 128 + 1,
+                "cc13bc0f438f272b88de36d0e8c8c22fef12d4ed699a2898ad0babb50bea46db",
+            ),
+        ] {
+            assert_eq!(
+                Swarm::from_slice(vec![b'A'; length].as_slice()).to_string(),
+                expected,
+                "length {length}"
+            );
+        }
+    }
+}
@@ -12,7 +12,9 @@ pub(crate) mod contract_name;
 pub(crate) mod data_location;
 pub(crate) mod debug_info;
 pub(crate) mod deserializer;
+pub(crate) mod diagnostic;
 pub(crate) mod error;
+pub(crate) mod evm_opcode;
 pub(crate) mod evm_version;
 pub(crate) mod exit_code;
 pub(crate) mod extension;
@@ -42,12 +44,19 @@ pub use self::debug_info::mapped_location::MappedLocation as DebugInfoMappedLoca
 pub use self::debug_info::solc_location::SolcLocation as DebugInfoSolcLocation;
 pub use self::debug_info::solc_location::ordering::Ordering as DebugInfoSolcLocationOrdering;
 pub use self::deserializer::*;
+pub use self::diagnostic::Diagnostic;
+pub use self::diagnostic::code::Code as DiagnosticCode;
+pub use self::diagnostic::component::Component as DiagnosticComponent;
+pub use self::diagnostic::location::Location as DiagnosticLocation;
+pub use self::diagnostic::severity::Severity as DiagnosticSeverity;
 pub use self::error::*;
+pub use self::evm_opcode::EVMOpcode;
 pub use self::evm_version::EVMVersion;
 pub use self::exit_code::*;
 pub use self::extension::*;
 pub use self::hash::ipfs::IPFS as IPFSHash;
 pub use self::hash::keccak256::Keccak256 as Keccak256Hash;
+pub use self::hash::swarm::Swarm as SwarmHash;
 pub use self::libraries::Libraries;
 pub use self::llvm_ir::*;
 pub use self::logging::*;
@@ -15,6 +15,9 @@ pub enum MetadataHashType {
     /// The `ipfs` hash.
     #[serde(rename = "ipfs")]
     IPFS,
+    /// The `bzzr1` Swarm hash.
+    #[serde(rename = "bzzr1", alias = "swarm")]
+    Swarm,
 }
 
 impl FromStr for MetadataHashType {
@@ -24,6 +27,7 @@ impl FromStr for MetadataHashType {
         match string {
             "none" => Ok(Self::None),
             "ipfs" => Ok(Self::IPFS),
+            "bzzr1" | "swarm" => Ok(Self::Swarm),
             string => anyhow::bail!("unknown bytecode hash mode: `{string}`"),
         }
     }
@@ -34,6 +38,7 @@ impl std::fmt::Display for MetadataHashType {
         match self {
             Self::None => write!(f, "none"),
             Self::IPFS => write!(f, "ipfs"),
+            Self::Swarm => write!(f, "bzzr1"),
         }
     }
 }
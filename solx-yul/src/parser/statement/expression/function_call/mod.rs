@@ -154,8 +154,22 @@ impl FunctionCall {
 
         match name {
             Name::UserDefined(name) => self.user_defined(context, name.as_str()),
-            name @ Name::Clz if evm_version < solx_utils::EVMVersion::Osaka => {
-                self.user_defined(context, name.to_string().as_str())
+            name if name
+                .evm_opcode()
+                .is_some_and(|opcode| !evm_version.is_opcode_available(opcode)) =>
+            {
+                let opcode = name.evm_opcode().expect("Always exists");
+                let name = name.to_string();
+                if context.get_function(name.as_str()).is_none() {
+                    evm_version
+                        .check_opcode(opcode, solx_utils::DiagnosticComponent::Yul)
+                        .map_err(|diagnostic| {
+                            diagnostic
+                                .with_context(location)
+                                .with_location(context.diagnostic_location())
+                        })?;
+                }
+                self.user_defined(context, name.as_str())
             }
 
             Name::Add => {
@@ -850,7 +864,14 @@ impl FunctionCall {
                     && std::env::var(solx_utils::ENV_DISABLE_UNSAFE_MEMORY_ASM_STACK_TOO_DEEP_CHECK)
                         .is_err()
                 {
-                    anyhow::bail!(solx_utils::ERROR_UNSAFE_MEMORY_ASM_STACK_TOO_DEEP);
+                    anyhow::bail!(
+                        solx_utils::Diagnostic::new(
+                            solx_utils::DiagnosticCode::UnsafeMemoryAssembly,
+                            solx_utils::DiagnosticComponent::Yul,
+                            solx_utils::ERROR_UNSAFE_MEMORY_ASM_STACK_TOO_DEEP,
+                        )
+                        .with_location(context.diagnostic_location())
+                    );
                 }
 
                 Ok(None)
@@ -858,9 +879,23 @@ impl FunctionCall {
 
             Name::CallCode => {
                 let _arguments = self.pop_arguments_llvm::<7>(context)?;
-                anyhow::bail!("{location} The `CALLCODE` instruction is not supported")
-            }
-            Name::Pc => anyhow::bail!("{location} The `PC` instruction is not supported"),
+                anyhow::bail!(
+                    solx_utils::Diagnostic::new(
+                        solx_utils::DiagnosticCode::UnsupportedInstruction,
+                        solx_utils::DiagnosticComponent::Yul,
+                        format!("{location} The `CALLCODE` instruction is not supported"),
+                    )
+                    .with_location(context.diagnostic_location())
+                )
+            }
+            Name::Pc => anyhow::bail!(
+                solx_utils::Diagnostic::new(
+                    solx_utils::DiagnosticCode::UnsupportedInstruction,
+                    solx_utils::DiagnosticComponent::Yul,
+                    format!("{location} The `PC` instruction is not supported"),
+                )
+                .with_location(context.diagnostic_location())
+            ),
             Name::SelfDestruct => {
                 let arguments = self.pop_arguments_llvm::<1>(context)?;
                 solx_codegen_evm::r#return::self_destruct(context, arguments[0].into_int_value())
@@ -222,6 +222,23 @@ pub enum Name {
     SelfDestruct,
 }
 
+impl Name {
+    ///
+    /// Returns the EVM opcode if the builtin is only available since some hard fork.
+    ///
+    pub fn evm_opcode(&self) -> Option<solx_utils::EVMOpcode> {
+        match self {
+            Self::TLoad => Some(solx_utils::EVMOpcode::TLoad),
+            Self::TStore => Some(solx_utils::EVMOpcode::TStore),
+            Self::MCopy => Some(solx_utils::EVMOpcode::MCopy),
+            Self::BlobHash => Some(solx_utils::EVMOpcode::BlobHash),
+            Self::BlobBaseFee => Some(solx_utils::EVMOpcode::BlobBaseFee),
+            Self::Clz => Some(solx_utils::EVMOpcode::Clz),
+            _ => None,
+        }
+    }
+}
+
 impl From<&str> for Name {
     fn from(input: &str) -> Self {
         match input {
@@ -0,0 +1,156 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[cfg(feature = "solc")]
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT,
+        "--combined-json",
+        "abi,bin,bin-runtime,hashes,metadata,srcmap",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    let stdout = String::from_utf8(result.success().get_output().stdout.clone())?;
+    let output: serde_json::Value = serde_json::from_str(stdout.as_str())?;
+
+    let contracts = output
+        .get("contracts")
+        .and_then(|contracts| contracts.as_object())
+        .expect("Always exists");
+    assert!(!contracts.is_empty());
+    for contract in contracts.values() {
+        for key in ["abi", "bin", "bin-runtime", "hashes", "metadata", "srcmap"] {
+            assert!(contract.get(key).is_some(), "`{key}` is missing");
+        }
+    }
+    assert!(output.get("sourceList").is_some());
+    assert!(output.get("version").is_some());
+
+    Ok(())
+}
+
+#[cfg(feature = "solc")]
+#[test]
+fn ast() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT,
+        "--combined-json",
+        "ast",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"sources\""))
+        .stdout(predicate::str::contains("\"AST\""))
+        .stdout(predicate::str::contains("\"sourceList\""));
+
+    Ok(())
+}
+
+#[test]
+fn output_dir() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let output_directory = TempDir::with_prefix("solx_output")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT,
+        "--combined-json",
+        "bin",
+        "--output-dir",
+        output_directory.path().to_str().expect("Always valid"),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("Compiler run successful"));
+    assert!(output_directory.path().join("combined.json").exists());
+
+    Ok(())
+}
+
+#[test]
+fn invalid_selector() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT,
+        "--combined-json",
+        "bin,interface",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Invalid combined JSON selector `interface`.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn with_other_outputs() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT,
+        "--combined-json",
+        "bin",
+        "--bin-runtime",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Combined JSON cannot be emitted together with other outputs.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn yul() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_YUL_CONTRACT,
+        "--yul",
+        "--combined-json",
+        "bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Combined JSON can be only emitted for Solidity contracts.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON,
+        "--combined-json",
+        "bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Cannot output data outside of JSON in standard JSON mode.",
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,159 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+///
+/// Returns the names of the DOT files written to the output directory.
+///
+fn dot_file_names(output_directory: &TempDir) -> anyhow::Result<Vec<String>> {
+    let mut file_names = std::fs::read_dir(output_directory.path())?
+        .map(|entry| Ok(entry?.file_name().to_string_lossy().to_string()))
+        .collect::<anyhow::Result<Vec<String>>>()?;
+    file_names.retain(|name| name.ends_with(".dot"));
+    Ok(file_names)
+}
+
+#[test]
+fn llvm() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let output_directory = TempDir::with_prefix("solx_output")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT,
+        "--emit-cfg=llvm",
+        "--output-dir",
+        output_directory.path().to_str().expect("Always valid"),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("Compiler run successful"));
+
+    let file_names = dot_file_names(&output_directory)?;
+    assert!(!file_names.is_empty());
+    assert!(file_names.iter().all(|name| name.contains(".llvm.")));
+
+    let graph = std::fs::read_to_string(output_directory.path().join(&file_names[0]))?;
+    assert!(graph.starts_with("digraph "));
+
+    Ok(())
+}
+
+#[cfg(feature = "solc")]
+#[test]
+fn ethir() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let output_directory = TempDir::with_prefix("solx_output")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT,
+        "--emit-cfg=ethir",
+        "--output-dir",
+        output_directory.path().to_str().expect("Always valid"),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("Compiler run successful"));
+
+    let file_names = dot_file_names(&output_directory)?;
+    assert!(file_names.iter().any(|name| name.contains(".ethir.")));
+
+    let graphs = file_names
+        .iter()
+        .map(|name| std::fs::read_to_string(output_directory.path().join(name)))
+        .collect::<Result<Vec<String>, std::io::Error>>()?;
+    assert!(graphs.iter().any(|graph| graph.contains("block_dt_0/0:")));
+    assert!(graphs.iter().any(|graph| graph.contains("label=\"jump")));
+
+    Ok(())
+}
+
+#[cfg(feature = "solc")]
+#[test]
+fn multiple_types() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let output_directory = TempDir::with_prefix("solx_output")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT,
+        "--emit-cfg=ethir,llvm,asm",
+        "--output-dir",
+        output_directory.path().to_str().expect("Always valid"),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("Compiler run successful"));
+
+    let file_names = dot_file_names(&output_directory)?;
+    for cfg_type in ["ethir", "llvm", "asm"] {
+        assert!(
+            file_names
+                .iter()
+                .any(|name| name.contains(format!(".{cfg_type}.").as_str())),
+            "`{cfg_type}` graphs are missing"
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn yul_ethir() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let output_directory = TempDir::with_prefix("solx_output")?;
+
+    let args = &[
+        "--yul",
+        crate::common::TEST_YUL_CONTRACT,
+        "--emit-cfg=ethir",
+        "--output-dir",
+        output_directory.path().to_str().expect("Always valid"),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Ethereal IR and EVM assembly control-flow graphs are only available in Solidity mode.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn missing_output_dir() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[crate::common::TEST_SOLIDITY_CONTRACT, "--emit-cfg=llvm"];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Control-flow graphs can only be written to the output directory specified with `--output-dir`.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn invalid_type() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[crate::common::TEST_SOLIDITY_CONTRACT, "--emit-cfg=yul"];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "unknown control-flow graph type: `yul`",
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,137 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let output_directory = TempDir::with_prefix("solx_output")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT,
+        "--emit-obj",
+        "--output-dir",
+        output_directory.path().to_str().expect("Always valid"),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("Compiler run successful"));
+
+    let file_names = std::fs::read_dir(output_directory.path())?
+        .map(|entry| Ok(entry?.file_name().to_string_lossy().to_string()))
+        .collect::<anyhow::Result<Vec<String>>>()?;
+    let manifest_name = file_names
+        .iter()
+        .find(|name| name.ends_with(".o.json"))
+        .expect("Always exists");
+    let object_name = manifest_name.trim_end_matches(".json");
+    assert!(file_names.iter().any(|name| name == object_name));
+    assert!(
+        file_names
+            .iter()
+            .any(|name| *name == format!("{object_name}-runtime"))
+    );
+
+    let manifest: serde_json::Value = serde_json::from_slice(
+        std::fs::read(output_directory.path().join(manifest_name))?.as_slice(),
+    )?;
+    assert_eq!(
+        manifest.get("version").and_then(|version| version.as_str()),
+        Some(solx_core::Compiler::version())
+    );
+    for segment in ["deploy", "runtime"] {
+        assert!(manifest.get(segment).is_some(), "`{segment}` is missing");
+    }
+
+    let object = std::fs::read(output_directory.path().join(object_name))?;
+    assert!(object.starts_with(b"\x7fELF"));
+
+    Ok(())
+}
+
+#[test]
+fn yul() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let output_directory = TempDir::with_prefix("solx_output")?;
+
+    let args = &[
+        "--yul",
+        crate::common::TEST_YUL_CONTRACT,
+        "--emit-obj",
+        "--output-dir",
+        output_directory.path().to_str().expect("Always valid"),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("Compiler run successful"));
+    assert!(
+        std::fs::read_dir(output_directory.path())?
+            .filter_map(Result::ok)
+            .any(|entry| entry.file_name().to_string_lossy().ends_with(".o.json"))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn missing_output_dir() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[crate::common::TEST_SOLIDITY_CONTRACT, "--emit-obj"];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Relocatable objects can only be written to the output directory specified with `--output-dir`.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn with_other_outputs() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let output_directory = TempDir::with_prefix("solx_output")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT,
+        "--emit-obj",
+        "--bin",
+        "--output-dir",
+        output_directory.path().to_str().expect("Always valid"),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Relocatable objects cannot be emitted together with other outputs.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON,
+        "--emit-obj",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Cannot output data outside of JSON in standard JSON mode.",
+    ));
+
+    Ok(())
+}
@@ -8,6 +8,7 @@ use test_case::test_case;
 #[test_case(solx_utils::EVMVersion::Cancun)]
 #[test_case(solx_utils::EVMVersion::Prague)]
 #[test_case(solx_utils::EVMVersion::Osaka)]
+#[test_case(solx_utils::EVMVersion::Amsterdam)]
 fn default(evm_version: solx_utils::EVMVersion) -> anyhow::Result<()> {
     crate::common::setup()?;
 
@@ -31,6 +32,7 @@ fn default(evm_version: solx_utils::EVMVersion) -> anyhow::Result<()> {
 #[test_case(solx_utils::EVMVersion::Cancun)]
 #[test_case(solx_utils::EVMVersion::Prague)]
 #[test_case(solx_utils::EVMVersion::Osaka)]
+#[test_case(solx_utils::EVMVersion::Amsterdam)]
 fn yul(evm_version: solx_utils::EVMVersion) -> anyhow::Result<()> {
     crate::common::setup()?;
 
@@ -54,6 +56,7 @@ fn yul(evm_version: solx_utils::EVMVersion) -> anyhow::Result<()> {
 #[test_case(solx_utils::EVMVersion::Cancun)]
 #[test_case(solx_utils::EVMVersion::Prague)]
 #[test_case(solx_utils::EVMVersion::Osaka)]
+#[test_case(solx_utils::EVMVersion::Amsterdam)]
 fn llvm_ir(evm_version: solx_utils::EVMVersion) -> anyhow::Result<()> {
     crate::common::setup()?;
 
@@ -94,6 +97,26 @@ fn standard_json() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn standard_json_unavailable_opcode() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::standard_json!("yul_clz_prague.json"),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("clz"))
+        .stdout(predicate::str::contains("prague"))
+        .stdout(predicate::str::contains("\"severity\":\"error\""))
+        .stdout(predicate::str::contains("\"object\":\"").not());
+
+    Ok(())
+}
+
 #[test]
 fn too_old() -> anyhow::Result<()> {
     crate::common::setup()?;
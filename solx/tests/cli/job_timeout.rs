@@ -0,0 +1,101 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT,
+        "--bin",
+        "--job-timeout",
+        "3600",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains("Binary"));
+
+    Ok(())
+}
+
+#[test]
+fn invalid() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT,
+        "--bin",
+        "--job-timeout",
+        "unlimited",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("invalid value"));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::standard_json!("solidity_job_limits.json"),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"object\""))
+        .stdout(predicate::str::contains("\"errors\"").not());
+
+    Ok(())
+}
+
+#[test]
+fn standard_json_cli_excess_arg() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON,
+        "--job-timeout",
+        "3600",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Job limits must be specified in standard JSON input settings.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn exceeded() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::contract!("solidity/Test.sol"),
+        "--bin",
+        "--job-timeout",
+        "0",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(
+        predicate::str::contains("Contract `")
+            .and(predicate::str::contains("Test.sol:Test` ("))
+            .and(predicate::str::contains(
+                "exceeded the time limit of 0s at stage `",
+            )),
+    );
+
+    Ok(())
+}
@@ -0,0 +1,171 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use std::path::Path;
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+/// Library linking argument for the `UpperLibrary` library of the `LinkedMixedDeps` contract.
+const LIBRARY_UPPER: &str = "tests/data/contracts/solidity/LinkedMixedDeps.sol:UpperLibrary=0xF9702469Dfb84A9aC171E284F71615bd3D3f1EdC";
+
+/// The `UpperLibrary` library placeholder left in the bytecode if the library is not linked.
+const PLACEHOLDER_UPPER: &str = "__$65ec92bf84627f42eab2cb5e40b5cc19ff$__";
+
+///
+/// Emits the relocatable objects of `contract` to `output_directory`, and returns their manifest paths.
+///
+fn emit_objects(contract: &str, output_directory: &Path) -> anyhow::Result<Vec<String>> {
+    let args = &[
+        contract,
+        "--emit-obj",
+        "--output-dir",
+        output_directory.to_str().expect("Always valid"),
+    ];
+    crate::cli::execute_solx(args)?.success();
+
+    let mut manifests = Vec::new();
+    for entry in std::fs::read_dir(output_directory)? {
+        let path = entry?.path().to_string_lossy().to_string();
+        if path.ends_with(".o.json") {
+            manifests.push(path);
+        }
+    }
+    manifests.sort();
+    Ok(manifests)
+}
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let output_directory = TempDir::with_prefix("solx_output")?;
+    let manifests = emit_objects(
+        crate::common::TEST_SOLIDITY_CONTRACT,
+        output_directory.path(),
+    )?;
+
+    let mut args = vec!["--link-objects", "--bin", "--bin-runtime"];
+    args.extend(manifests.iter().map(String::as_str));
+
+    let result = crate::cli::execute_solx(args.as_slice())?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Binary:\n"))
+        .stdout(predicate::str::contains("Binary of the runtime part:\n"));
+
+    Ok(())
+}
+
+#[test]
+fn matches_compilation() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let output_directory = TempDir::with_prefix("solx_output")?;
+    let manifests = emit_objects(
+        crate::common::TEST_SOLIDITY_CONTRACT,
+        output_directory.path(),
+    )?;
+
+    let mut args = vec!["--link-objects", "--bin"];
+    args.extend(manifests.iter().map(String::as_str));
+    let linked = crate::cli::execute_solx(args.as_slice())?;
+    let linked = String::from_utf8(linked.success().get_output().stdout.clone())?;
+
+    let compiled = crate::cli::execute_solx(&[crate::common::TEST_SOLIDITY_CONTRACT, "--bin"])?;
+    let compiled = String::from_utf8(compiled.success().get_output().stdout.clone())?;
+
+    let binary = |output: &str| {
+        output
+            .split("Binary:\n")
+            .nth(1)
+            .and_then(|binary| binary.lines().next())
+            .map(str::to_owned)
+    };
+    assert!(binary(linked.as_str()).is_some());
+    assert_eq!(binary(linked.as_str()), binary(compiled.as_str()));
+
+    Ok(())
+}
+
+#[cfg(feature = "solc")]
+#[test]
+fn libraries() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let output_directory = TempDir::with_prefix("solx_output")?;
+    let manifests = emit_objects(
+        crate::common::contract!("solidity/LinkedMixedDeps.sol"),
+        output_directory.path(),
+    )?;
+
+    let mut args = vec!["--link-objects", "--bin"];
+    args.extend(manifests.iter().map(String::as_str));
+    let result = crate::cli::execute_solx(args.as_slice())?;
+    result
+        .success()
+        .stdout(predicate::str::contains(PLACEHOLDER_UPPER));
+
+    args.extend(["--libraries", LIBRARY_UPPER]);
+    let result = crate::cli::execute_solx(args.as_slice())?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Binary:\n"))
+        .stdout(predicate::str::contains(PLACEHOLDER_UPPER).not());
+
+    Ok(())
+}
+
+#[cfg(feature = "solc")]
+#[test]
+fn missing_dependency() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let output_directory = TempDir::with_prefix("solx_output")?;
+    let manifests = emit_objects(
+        crate::common::contract!("solidity/LinkedMixedDeps.sol"),
+        output_directory.path(),
+    )?;
+    let library_manifest = manifests
+        .iter()
+        .find(|path| path.ends_with("_UpperLibrary.o.json"))
+        .expect("Always exists");
+
+    let args = &["--link-objects", "--bin", library_manifest.as_str()];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "which is not among the linked objects.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn with_other_outputs() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["--link-objects", "Test.o.json", "--abi"];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Only bytecode can be emitted in object linking mode.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn with_yul() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["--link-objects", "--yul", crate::common::TEST_YUL_CONTRACT];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Only one mode is allowed at the same time",
+    ));
+
+    Ok(())
+}
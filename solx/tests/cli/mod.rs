@@ -21,6 +21,7 @@ mod base_path;
 mod benchmarks;
 mod bin;
 mod bin_runtime;
+mod combined_json;
 #[cfg(feature = "solc")]
 mod debug_info;
 #[cfg(feature = "solc")]
@@ -29,9 +30,11 @@ mod debug_info_runtime;
 mod devdoc;
 #[cfg(feature = "solc")]
 mod docs_examples;
+mod emit_cfg;
 mod emit_llvm_ir;
 #[cfg(feature = "mlir")]
 mod emit_mlir;
+mod emit_obj;
 mod empty;
 #[cfg(feature = "solc")]
 mod ethir;
@@ -45,8 +48,11 @@ mod help;
 mod include_path;
 #[cfg(feature = "solc")]
 mod ir;
+mod job_memory_limit;
+mod job_timeout;
 #[cfg(feature = "solc")]
 mod libraries;
+mod link_objects;
 mod llvm_ir;
 mod llvm_options;
 #[cfg(feature = "solc")]
@@ -63,21 +69,32 @@ mod output_dir;
 mod overwrite;
 mod recursive_process;
 mod remappings;
+mod remote_worker;
+mod replay;
+#[cfg(feature = "solc")]
+mod source_archive;
 mod stack_too_deep;
 mod standard_json;
 mod standard_json_optimizer;
 mod standard_json_output;
+mod standard_json_stream;
 #[cfg(feature = "solc")]
 mod storage_layout;
+#[cfg(feature = "solc")]
+mod suppress_warning;
 mod threads;
 #[cfg(feature = "solc")]
 mod transient_storage_layout;
 #[cfg(feature = "solc")]
 mod userdoc;
+#[cfg(feature = "solc")]
+mod verify;
 mod version;
 #[cfg(feature = "solc")]
 mod via_ir;
 #[cfg(feature = "solc")]
+mod warning_as_error;
+#[cfg(feature = "solc")]
 mod yul;
 #[cfg(feature = "solc")]
 mod yul_parser;
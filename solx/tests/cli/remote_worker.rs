@@ -0,0 +1,161 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use std::process::Child;
+use std::process::Command;
+use std::process::Stdio;
+use std::time::Duration;
+use std::time::Instant;
+
+use predicates::prelude::*;
+
+/// The time to wait for the worker server to start listening.
+const SERVER_STARTUP_TIMEOUT: Duration = Duration::from_secs(30);
+
+///
+/// A worker server process killed on drop.
+///
+struct Server(Child);
+
+impl Server {
+    ///
+    /// Starts a worker server at `address` and waits until it is listening.
+    ///
+    fn start(address: &str, is_listening: impl Fn() -> bool) -> anyhow::Result<Self> {
+        let child = Command::new(assert_cmd::cargo::cargo_bin!(env!("CARGO_PKG_NAME")))
+            .args(["--recursive-process", "--listen", address])
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()?;
+        let server = Self(child);
+
+        let start_time = Instant::now();
+        while !is_listening() {
+            if start_time.elapsed() > SERVER_STARTUP_TIMEOUT {
+                anyhow::bail!("The worker server at `{address}` has not started listening");
+            }
+            std::thread::sleep(Duration::from_millis(50));
+        }
+        Ok(server)
+    }
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        let _ = self.0.kill();
+        let _ = self.0.wait();
+    }
+}
+
+///
+/// Returns a TCP port that is free at the moment of the call.
+///
+fn free_tcp_port() -> anyhow::Result<u16> {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+    Ok(listener.local_addr()?.port())
+}
+
+#[test]
+fn tcp() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let socket_address = format!("127.0.0.1:{}", free_tcp_port()?);
+    let address = format!("tcp://{socket_address}");
+    let _server = Server::start(address.as_str(), || {
+        std::net::TcpStream::connect(socket_address.as_str()).is_ok()
+    })?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT,
+        "--bin",
+        "--remote-worker",
+        address.as_str(),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains("Binary"));
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn unix() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let directory = tempfile::tempdir()?;
+    let path = directory.path().join("worker.sock");
+    let address = format!("unix://{}", path.display());
+    let _server = Server::start(address.as_str(), || path.exists())?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT,
+        "--bin",
+        "--remote-worker",
+        address.as_str(),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains("Binary"));
+
+    Ok(())
+}
+
+#[test]
+fn unreachable() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let address = format!("tcp://127.0.0.1:{}", free_tcp_port()?);
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT,
+        "--bin",
+        "--remote-worker",
+        address.as_str(),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("connecting error"));
+
+    Ok(())
+}
+
+#[test]
+fn invalid_address() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT,
+        "--bin",
+        "--remote-worker",
+        "ftp://127.0.0.1:21",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("Invalid worker address"));
+
+    Ok(())
+}
+
+#[test]
+fn listen_without_recursive_process() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT,
+        "--listen",
+        "tcp://127.0.0.1:0",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Listening for worker connections is only allowed in the recursive process mode.",
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+
+#[test]
+fn missing_bundle() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["--replay", "tests/data/non_existent"];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("Reproducer").and(predicate::str::contains("reading")));
+
+    Ok(())
+}
+
+#[test]
+fn excess_args() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--replay",
+        "tests/data/non_existent",
+        crate::common::TEST_SOLIDITY_CONTRACT,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "No other options are allowed while replaying a crash reproducer.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn round_trip() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let crash_directory = tempfile::tempdir()?;
+    let args = &[
+        "--llvm-ir",
+        "--bin",
+        crate::common::contract!("llvm_ir/LinkerError.ll"),
+    ];
+
+    let result = crate::cli::execute_solx_with_env_vars(
+        args,
+        vec![(
+            solx_core::EVMProcessReproducer::CRASH_DIRECTORY_ENV,
+            crash_directory.path().to_string_lossy().to_string(),
+        )],
+    )?;
+    result
+        .failure()
+        .stderr(predicate::str::contains(
+            "LLVM error: cannot evaluate undefined symbol 'foo'",
+        ))
+        .stderr(predicate::str::contains(
+            "A crash reproducer has been written to",
+        ));
+
+    let bundles = std::fs::read_dir(crash_directory.path())?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+    assert!(!bundles.is_empty(), "No crash reproducer has been written");
+    for bundle in bundles.iter() {
+        let bundle = bundle.to_string_lossy();
+        let args = &["--replay", bundle.as_ref()];
+
+        let result = crate::cli::execute_solx(args)?;
+        result.failure().stderr(predicate::str::contains(
+            "cannot evaluate undefined symbol 'foo'",
+        ));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+
+const SOURCE_ARCHIVE: &str = "tests/data/source_archive";
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::standard_json!("yul_urls_source_archive.json"),
+        "--source-archive",
+        SOURCE_ARCHIVE,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Fallback.yul"))
+        .stdout(predicate::str::contains("\"object\""))
+        .stdout(predicate::str::contains("\"errors\"").not());
+
+    Ok(())
+}
+
+#[test]
+fn missing() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::standard_json!("yul_urls_source_archive.json"),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains(
+        "No resolver is configured for URL `ipfs://QmZ18DEt4w1mHq2WmnjodyUgd781gBrejt1DVU7kzpj8aG`.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn keccak256_mismatch() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::standard_json!("yul_urls_keccak256_mismatch.json"),
+        "--source-archive",
+        SOURCE_ARCHIVE,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains(
+        "does not match the `keccak256` hash",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn not_standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_YUL_CONTRACT,
+        "--yul",
+        "--bin",
+        "--source-archive",
+        SOURCE_ARCHIVE,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Source archive can only be used in standard JSON mode.",
+    ));
+
+    Ok(())
+}
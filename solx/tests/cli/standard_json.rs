@@ -260,6 +260,102 @@ fn missing_sources() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[cfg(feature = "solc")]
+#[test]
+fn unsupported_keys() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::standard_json!("solidity_unsupported_keys.json"),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains(
+            "Standard JSON input `/settings/optimizer/details`: not supported by solx, ignored.",
+        ))
+        .stdout(predicate::str::contains(
+            "Standard JSON input `/settings/modelChecker`: not supported by solx, ignored.",
+        ))
+        .stdout(predicate::str::contains(
+            "Standard JSON input `/settings/outputSelections`: unknown key, ignored.",
+        ))
+        .stdout(predicate::str::contains("\"object\""));
+
+    Ok(())
+}
+
+#[test]
+fn type_mismatches() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::standard_json!("solidity_type_mismatches.json"),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains(
+            "Standard JSON input `/settings/optimizer/runs`: invalid type",
+        ))
+        .stdout(predicate::str::contains(
+            "Standard JSON input `/settings/outputSelection/*/*`: expected an array, found a string.",
+        ))
+        .stdout(predicate::str::contains(
+            "Standard JSON input `/settings/viaIR`: invalid type",
+        ))
+        .stdout(predicate::str::contains("\"object\"").not());
+
+    Ok(())
+}
+
+#[test]
+fn via_ir_for_llvm_ir() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::standard_json!("llvm_ir_via_ir.json"),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains(
+            "Standard JSON input `/settings/viaIR`: only supported for Solidity input, ignored for LLVM IR input.",
+        ))
+        .stdout(predicate::str::contains("\"object\""));
+
+    Ok(())
+}
+
+#[test]
+fn validation_warnings_kept_on_errors() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::standard_json!("llvm_ir_via_ir_missing_file.json"),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains(
+            "Standard JSON input `/settings/viaIR`: only supported for Solidity input, ignored for LLVM IR input.",
+        ))
+        .stdout(predicate::str::contains(
+            "Error: File \\\"tests/data/contracts/llvm_ir/Missing.ll\\\" reading:",
+        ))
+        .stdout(predicate::str::contains("\"object\"").not());
+
+    Ok(())
+}
+
 #[cfg(feature = "solc")]
 #[test]
 fn metadata_hash_ipfs_and_metadata() -> anyhow::Result<()> {
@@ -298,6 +394,25 @@ fn metadata_hash_ipfs_no_metadata() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[cfg(feature = "solc")]
+#[test]
+fn metadata_hash_bzzr1_and_metadata() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::standard_json!("metadata_hash_bzzr1_and_metadata.json"),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("a265627a7a72315820"))
+        .stdout(predicate::str::contains("\"metadata\""));
+
+    Ok(())
+}
+
 #[cfg(feature = "solc")]
 #[test]
 fn metadata_hash_none_and_metadata() -> anyhow::Result<()> {
@@ -116,6 +116,76 @@ fn size_fallback_true_produces_bytecode() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn runs_trades_size_for_gas() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let mut bytecodes = Vec::with_capacity(2);
+    for (path, expected_warning) in [
+        (
+            crate::common::standard_json!("optimizer_runs_low.json"),
+            Some("switched from `3` to `z`"),
+        ),
+        (
+            crate::common::standard_json!("optimizer_runs_high.json"),
+            None,
+        ),
+    ] {
+        let args = &["--standard-json", path];
+
+        let result = crate::cli::execute_solx(args)?;
+        let stdout = String::from_utf8(result.success().get_output().stdout.clone())?;
+        let output: serde_json::Value = serde_json::from_str(stdout.as_str())?;
+        match expected_warning {
+            Some(expected_warning) => assert!(
+                output["errors"].as_array().is_some_and(|errors| errors
+                    .iter()
+                    .all(|error| error["severity"] == "warning")
+                    && errors.iter().any(|error| error["message"]
+                        .as_str()
+                        .is_some_and(|message| message.contains(expected_warning)))),
+                "Missing the mode switch warning: {}",
+                output["errors"]
+            ),
+            None => assert!(
+                output.get("errors").is_none(),
+                "Unexpected errors: {}",
+                output["errors"]
+            ),
+        }
+        let bytecode = output["contracts"]["A"]["C"]["evm"]["deployedBytecode"]["object"]
+            .as_str()
+            .expect("Always exists")
+            .to_owned();
+        assert!(!bytecode.is_empty(), "No bytecode emitted for {path}");
+        bytecodes.push(bytecode);
+    }
+    assert_ne!(
+        bytecodes[0], bytecodes[1],
+        "Low and high `runs` must not produce the same bytecode"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn runs_keep_mode_without_size_for_low_runs() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::standard_json!("optimizer_runs_low_without_size.json"),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"object\""))
+        .stdout(predicate::str::contains("\"errors\"").not());
+
+    Ok(())
+}
+
 #[cfg(feature = "solc")]
 #[test_case('1')]
 #[test_case('2')]
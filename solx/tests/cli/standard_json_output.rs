@@ -213,6 +213,28 @@ fn error_output_has_source_location() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[cfg(feature = "solc")]
+#[test]
+fn error_output_has_diagnostic_code() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::standard_json!("yul_unsupported_callcode.json"),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"errorCode\":\"10004\""))
+        .stdout(predicate::str::contains("\"component\":\"yul\""))
+        .stdout(predicate::str::contains(
+            "The `CALLCODE` instruction is not supported",
+        ));
+
+    Ok(())
+}
+
 #[test]
 fn error_output_component_is_general() -> anyhow::Result<()> {
     crate::common::setup()?;
@@ -0,0 +1,80 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON,
+        "--standard-json-stream",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    let stdout = String::from_utf8(result.success().get_output().stdout.clone())?;
+    let output: serde_json::Value = serde_json::from_str(stdout.as_str())?;
+    assert!(output.get("contracts").is_some());
+
+    Ok(())
+}
+
+#[test]
+fn ndjson() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON,
+        "--standard-json-stream=ndjson",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    let stdout = String::from_utf8(result.success().get_output().stdout.clone())?;
+    let lines = stdout.lines().collect::<Vec<&str>>();
+    assert!(lines.len() > 1);
+    for line in lines.into_iter() {
+        serde_json::from_str::<serde_json::Value>(line)?;
+    }
+
+    Ok(())
+}
+
+#[test]
+fn invalid_format() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON,
+        "--standard-json-stream=yaml",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("Invalid streaming output format"));
+
+    Ok(())
+}
+
+#[test]
+fn without_standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT,
+        "--bin",
+        "--standard-json-stream",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Streaming output can only be used in standard JSON mode.",
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,122 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+
+const RUNTIME_CODE_SIZE_CONTRACT: &str = crate::common::contract!("yul/RuntimeCodeSize.yul");
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[RUNTIME_CODE_SIZE_CONTRACT, "--yul", "--bin"];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("exceeds the EVM limit"));
+
+    Ok(())
+}
+
+#[test]
+fn code() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        RUNTIME_CODE_SIZE_CONTRACT,
+        "--yul",
+        "--bin",
+        "--suppress-warning",
+        "5574",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("exceeds the EVM limit").not());
+
+    Ok(())
+}
+
+#[test]
+fn path_glob_not_matching() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        RUNTIME_CODE_SIZE_CONTRACT,
+        "--yul",
+        "--bin",
+        "--suppress-warning",
+        "*@*/Other.yul",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("exceeds the EVM limit"));
+
+    Ok(())
+}
+
+#[test]
+fn precedence_over_promotion() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        RUNTIME_CODE_SIZE_CONTRACT,
+        "--yul",
+        "--bin",
+        "--suppress-warning",
+        "*@*RuntimeCodeSize.yul",
+        "--warning-as-error",
+        "*",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("exceeds the EVM limit").not());
+
+    Ok(())
+}
+
+#[test]
+fn invalid_rule() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        RUNTIME_CODE_SIZE_CONTRACT,
+        "--yul",
+        "--bin",
+        "--suppress-warning",
+        "size",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("Invalid warning code `size`"));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json_cli_excess_arg() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON,
+        "--suppress-warning",
+        "5574",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Warning rules must be specified in standard JSON input settings.",
+    ));
+
+    Ok(())
+}
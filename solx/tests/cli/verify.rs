@@ -0,0 +1,187 @@
+//!
+//! CLI tests for the eponymous subcommand.
+//!
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+///
+/// Compiles the test contract and returns its metadata and runtime bytecode.
+///
+fn metadata_and_runtime_bytecode(optimization: &str) -> anyhow::Result<(String, String)> {
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT,
+        "-O",
+        optimization,
+        "--metadata",
+        "--bin-runtime",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    let stdout = String::from_utf8(result.success().get_output().stdout.clone())?;
+    let value_after = |header: &str| -> anyhow::Result<String> {
+        let mut lines = stdout.lines().skip_while(|line| *line != header);
+        lines.next();
+        lines
+            .next()
+            .map(str::to_owned)
+            .ok_or_else(|| anyhow::anyhow!("`{header}` is missing from the output"))
+    };
+    Ok((
+        value_after("Metadata:")?,
+        value_after("Binary of the runtime part:")?,
+    ))
+}
+
+#[test]
+fn full_match() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let (metadata, bytecode) = metadata_and_runtime_bytecode("3")?;
+    let directory = TempDir::with_prefix("solx_verify_full_match")?;
+    let metadata_path = directory.path().join("metadata.json");
+    std::fs::write(metadata_path.as_path(), metadata)?;
+
+    let metadata_path = metadata_path.to_string_lossy();
+    let args = &[
+        "verify",
+        "--metadata",
+        metadata_path.as_ref(),
+        "--bytecode",
+        bytecode.as_str(),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("full match"));
+
+    Ok(())
+}
+
+#[test]
+fn partial_match() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let (metadata, mut bytecode) = metadata_and_runtime_bytecode("3")?;
+    let directory = TempDir::with_prefix("solx_verify_partial_match")?;
+    let metadata_path = directory.path().join("metadata.json");
+    std::fs::write(metadata_path.as_path(), metadata)?;
+
+    // Corrupt the first byte of the IPFS hash, which follows the `ipfs` key and its length prefix.
+    let hash_offset = bytecode
+        .rfind("697066735822")
+        .ok_or_else(|| anyhow::anyhow!("IPFS hash is missing from the bytecode"))?
+        + 12;
+    let corrupted = if &bytecode[hash_offset..hash_offset + 2] == "00" {
+        "01"
+    } else {
+        "00"
+    };
+    bytecode.replace_range(hash_offset..hash_offset + 2, corrupted);
+
+    let metadata_path = metadata_path.to_string_lossy();
+    let args = &[
+        "verify",
+        "--metadata",
+        metadata_path.as_ref(),
+        "--bytecode",
+        bytecode.as_str(),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("partial match"));
+
+    Ok(())
+}
+
+#[test]
+fn mismatch() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let (metadata, mut bytecode) = metadata_and_runtime_bytecode("3")?;
+    let directory = TempDir::with_prefix("solx_verify_mismatch")?;
+    let metadata_path = directory.path().join("metadata.json");
+    std::fs::write(metadata_path.as_path(), metadata)?;
+
+    bytecode.insert_str(0, "00");
+
+    let metadata_path = metadata_path.to_string_lossy();
+    let args = &[
+        "verify",
+        "--metadata",
+        metadata_path.as_ref(),
+        "--bytecode",
+        bytecode.as_str(),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stdout(predicate::str::contains("mismatch"))
+        .stderr(predicate::str::contains(
+            "The deployed bytecode does not match the bytecode compiled from the metadata.",
+        ));
+
+    Ok(())
+}
+
+#[test]
+fn settings_from_metadata() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let (metadata, bytecode) = metadata_and_runtime_bytecode("z")?;
+    let mut metadata: serde_json::Value = serde_json::from_str(metadata.as_str())?;
+    metadata["solx"] = serde_json::json!({
+        "solx_version": env!("CARGO_PKG_VERSION"),
+        "optimizer_settings": solx_codegen_evm::OptimizerSettings::size(),
+        "llvm_options": [],
+    });
+    let directory = TempDir::with_prefix("solx_verify_settings_from_metadata")?;
+    let metadata_path = directory.path().join("metadata.json");
+    std::fs::write(metadata_path.as_path(), serde_json::to_string(&metadata)?)?;
+
+    let metadata_path = metadata_path.to_string_lossy();
+    let args = &[
+        "verify",
+        "--metadata",
+        metadata_path.as_ref(),
+        "--bytecode",
+        bytecode.as_str(),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains("match"));
+
+    Ok(())
+}
+
+#[test]
+fn missing_bytecode() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["verify", "--metadata", "metadata.json"];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("--bytecode <BYTECODE>"));
+
+    Ok(())
+}
+
+#[test]
+fn bytecode_without_verify() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[crate::common::TEST_SOLIDITY_CONTRACT, "--bytecode", "0x00"];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("unexpected argument '--bytecode'"));
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+
+const RUNTIME_CODE_SIZE_CONTRACT: &str = crate::common::contract!("yul/RuntimeCodeSize.yul");
+
+#[test]
+fn code() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        RUNTIME_CODE_SIZE_CONTRACT,
+        "--yul",
+        "--bin",
+        "--warning-as-error",
+        "5574",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("Error: runtime bytecode size is"));
+
+    Ok(())
+}
+
+#[test]
+fn other_code() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        RUNTIME_CODE_SIZE_CONTRACT,
+        "--yul",
+        "--bin",
+        "--warning-as-error",
+        "3860",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stderr(predicate::str::contains(
+        "Warning: runtime bytecode size is",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::standard_json!("yul_warning_as_error.json"),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains(r#""errorCode":"5574""#))
+        .stdout(predicate::str::contains(r#""severity":"error""#))
+        .stdout(predicate::str::contains("deployedBytecode").not());
+
+    Ok(())
+}
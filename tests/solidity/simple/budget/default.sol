@@ -0,0 +1,50 @@
+//! { "budgets": {
+//!     "Test": { "max_size": 2048, "max_runtime_size": 1024 }
+//! }, "cases": [ {
+//!     "name": "answer",
+//!     "inputs": [
+//!         {
+//!             "method": "answer",
+//!             "calldata": [
+//!             ],
+//!             "expected_gas": [
+//!                 { "value": 150, "tolerance": 100, "modes": ["M3"] },
+//!                 { "value": 150, "tolerance": 100, "modes": ["Mz"] }
+//!             ],
+//!             "max_gas": 10000
+//!         }
+//!     ],
+//!     "expected": [
+//!         "42"
+//!     ]
+//! }, {
+//!     "name": "sum",
+//!     "inputs": [
+//!         {
+//!             "method": "sum",
+//!             "calldata": [
+//!                 "10"
+//!             ],
+//!             "max_gas": { "value": 20000, "tolerance": 10 }
+//!         }
+//!     ],
+//!     "expected": [
+//!         "55"
+//!     ]
+//! } ] }
+
+// SPDX-License-Identifier: MIT
+
+pragma solidity >=0.4.16;
+
+contract Test {
+    function answer() public pure returns(uint256) {
+        return 42;
+    }
+
+    function sum(uint256 n) public pure returns(uint256 result) {
+        for (uint256 i = 1; i <= n; i++) {
+            result += i;
+        }
+    }
+}
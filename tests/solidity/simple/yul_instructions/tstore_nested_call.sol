@@ -0,0 +1,55 @@
+//! { "cases": [ {
+//!     "name": "nested",
+//!     "inputs": [
+//!         {
+//!             "method": "main",
+//!             "calldata": [
+//!                 "42"
+//!             ],
+//!             "expected_transient_storage": {
+//!                 "Test.address": {
+//!                     "1": "43"
+//!                 },
+//!                 "0xbd09fdcdee6261108bca2a7d5814f09731911566": [
+//!                     "42"
+//!                 ]
+//!             },
+//!             "expected_code": {
+//!                 "0xbd09fdcdee6261108bca2a7d5814f09731911566": { "bytecode": "0x60003560005d00" }
+//!             }
+//!         }
+//!     ],
+//!     "expected": [
+//!         "42"
+//!     ]
+//! } ] }
+
+// SPDX-License-Identifier: MIT
+
+pragma solidity >=0.8.24;
+
+// The transient storage written in nested calls must be visible at the end of the transaction:
+// the child created below at `CREATE(Test.address, 1)` runs `tstore(0, calldataload(0))`, and
+// the external call to `store` writes the slot 1 of the test contract.
+
+contract Test {
+    function main(uint256 value) external returns (uint256) {
+        // Init code returning the runtime code `60003560005d00`.
+        bytes memory initCode = hex"6660003560005d0060005260076019f3";
+        address child;
+        assembly {
+            child := create(0, add(initCode, 32), mload(initCode))
+        }
+        (bool success, ) = child.call(abi.encode(value));
+        require(success);
+
+        this.store(value + 1);
+        return value;
+    }
+
+    function store(uint256 value) external {
+        assembly {
+            tstore(1, value)
+        }
+    }
+}